@@ -6,7 +6,7 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
-    types::{days_ms, NativeType},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 /// Compare the values at two arbitrary indices in two arrays.
@@ -95,6 +95,120 @@ fn compare_string<'a, O: Offset>(left: &'a dyn Array, right: &'a dyn Array) -> D
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
 
+fn compare_binary<'a, O: Offset>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
+    let left = left.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+fn compare_fixed_size_binary<'a>(left: &'a dyn Array, right: &'a dyn Array) -> DynComparator<'a> {
+    let left = left
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+/// Returns a comparator that, contrarily to [`build_compare`], takes the validity of the
+/// arrays into account, ordering nulls before any value.
+/// This is used to compare the (possibly null) children of nested arrays.
+fn build_compare_with_nulls<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+) -> Result<DynComparator<'a>> {
+    let comparator = build_compare(left, right)?;
+    if left.null_count() == 0 && right.null_count() == 0 {
+        return Ok(comparator);
+    }
+    Ok(Box::new(move |i, j| {
+        match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => (comparator)(i, j),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }))
+}
+
+/// Compares the items in `left[left_range]` and `right[right_range]` lexicographically; a
+/// list that is a prefix of another list is smaller than it.
+#[inline]
+fn compare_items(
+    comparator: &DynComparator,
+    left_range: std::ops::Range<usize>,
+    right_range: std::ops::Range<usize>,
+) -> Ordering {
+    let left_length = left_range.len();
+    let right_length = right_range.len();
+    for (i, j) in left_range.zip(right_range) {
+        match (comparator)(i, j) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    left_length.cmp(&right_length)
+}
+
+fn compare_list<'a, O: Offset>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let comparator = build_compare_with_nulls(left.values().as_ref(), right.values().as_ref())?;
+    let left_offsets = left.offsets();
+    let right_offsets = right.offsets();
+
+    Ok(Box::new(move |i, j| {
+        let left_range = left_offsets[i].to_usize()..left_offsets[i + 1].to_usize();
+        let right_range = right_offsets[j].to_usize()..right_offsets[j + 1].to_usize();
+        compare_items(&comparator, left_range, right_range)
+    }))
+}
+
+fn compare_fixed_size_list<'a>(
+    left: &'a dyn Array,
+    right: &'a dyn Array,
+) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = *FixedSizeListArray::get_child_and_size(left.data_type()).1 as usize;
+    let comparator = build_compare_with_nulls(left.values().as_ref(), right.values().as_ref())?;
+
+    Ok(Box::new(move |i, j| {
+        compare_items(
+            &comparator,
+            i * size..(i + 1) * size,
+            j * size..(j + 1) * size,
+        )
+    }))
+}
+
+fn compare_struct<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(left, right)| build_compare_with_nulls(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        for comparator in comparators.iter() {
+            match (comparator)(i, j) {
+                Ordering::Equal => continue,
+                other => return other,
+            }
+        }
+        Ordering::Equal
+    }))
+}
+
 fn compare_dict<'a, K>(
     left: &'a DictionaryArray<K>,
     right: &'a DictionaryArray<K>,
@@ -105,7 +219,7 @@ where
     let left_keys = left.keys().values();
     let right_keys = right.keys().values();
 
-    let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
+    let comparator = build_compare_with_nulls(left.values().as_ref(), right.values().as_ref())?;
 
     Ok(Box::new(move |i: usize, j: usize| {
         let key_left = left_keys[i].to_usize().unwrap();
@@ -142,6 +256,11 @@ macro_rules! dyn_dict {
 /// ```
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
+/// # Nested types
+/// The validity of `left` and `right` is not taken into account, but the validity of their
+/// children is: lists and structs compare their (possibly null) children lexicographically,
+/// with nulls ordered before values; dictionaries compare their values without materializing
+/// them.
 // This is a factory of comparisons.
 // The lifetime 'a enforces that we cannot use the closure beyond any of the array's lifetime.
 pub fn build_compare<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<DynComparator<'a>> {
@@ -181,8 +300,19 @@ pub fn build_compare<'a>(left: &'a dyn Array, right: &'a dyn Array) -> Result<Dy
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Interval(DayTime), Interval(DayTime)) => compare_primitives::<days_ms>(left, right),
+        (Interval(MonthDayNano), Interval(MonthDayNano)) => {
+            compare_primitives::<months_days_ns>(left, right)
+        }
+        (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
+        (Binary, Binary) => compare_binary::<i32>(left, right),
+        (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => compare_fixed_size_binary(left, right),
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
         (Dictionary(key_type_lhs, _), Dictionary(key_type_rhs, _)) => {
             match (key_type_lhs.as_ref(), key_type_rhs.as_ref()) {
                 (UInt8, UInt8) => dyn_dict!(u8, left, right),
@@ -215,6 +345,7 @@ pub mod tests {
     use super::*;
     use crate::error::Result;
     use std::cmp::Ordering;
+    use std::sync::Arc;
 
     #[test]
     fn test_i32() -> Result<()> {
@@ -313,4 +444,59 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(2, 3));
         Ok(())
     }
+
+    #[test]
+    fn test_binary() -> Result<()> {
+        let array = BinaryArray::<i32>::from_slice(&[b"a".as_ref(), b"ab", b"b"]);
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(2, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list() -> Result<()> {
+        let data = vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            Some(vec![None, Some(3)]),
+            Some(vec![Some(1), Some(2)]),
+        ];
+        let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data)?;
+        let array: ListArray<i32> = array.into();
+
+        let cmp = build_compare(&array, &array)?;
+
+        // a prefix is smaller
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        // nulls are smaller than values
+        assert_eq!(Ordering::Less, (cmp)(2, 1));
+        assert_eq!(Ordering::Equal, (cmp)(0, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct() -> Result<()> {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let array = StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(&[Some(1), Some(1), None])),
+                Arc::new(Utf8Array::<i32>::from_slice(&["b", "a", "c"])),
+            ],
+            None,
+        );
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Less, (cmp)(2, 1));
+        Ok(())
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{datatypes::*, temporal_conversions, types::days_ms};
+use crate::{
+    datatypes::*,
+    temporal_conversions,
+    types::{days_ms, months_days_ns},
+};
 
 use super::super::{display_fmt, Array};
 use super::PrimitiveArray;
@@ -159,6 +163,15 @@ impl std::fmt::Display for PrimitiveArray<days_ms> {
     }
 }
 
+impl std::fmt::Display for PrimitiveArray<months_days_ns> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let new_lines = false;
+        let head = &format!("{}", self.data_type());
+        let iter = self.iter().map(|x| x.map(|x| x.to_string()));
+        display_fmt(iter, head, f, new_lines)
+    }
+}
+
 macro_rules! display {
     ($ty:ty) => {
         impl std::fmt::Display for PrimitiveArray<$ty> {
@@ -181,7 +194,7 @@ display!(f64);
 
 #[cfg(test)]
 mod tests {
-    use super::super::{DaysMsArray, Int128Array, Int32Array, Int64Array};
+    use super::super::{DaysMsArray, Int128Array, Int32Array, Int64Array, MonthsDaysNsArray};
     use super::*;
 
     #[test]
@@ -339,4 +352,17 @@ mod tests {
         let array = DaysMsArray::from(&[Some(days_ms::new(1, 1)), None, Some(days_ms::new(2, 2))]);
         assert_eq!(format!("{}", array), "Interval(DayTime)[1d1ms, , 2d2ms]");
     }
+
+    #[test]
+    fn display_interval_months_days_ns() {
+        let array = MonthsDaysNsArray::from(&[
+            Some(months_days_ns::new(1, 1, 2)),
+            None,
+            Some(months_days_ns::new(-2, 3, 4)),
+        ]);
+        assert_eq!(
+            format!("{}", array),
+            "Interval(MonthDayNano)[1m1d2ns, , -2m3d4ns]"
+        );
+    }
 }
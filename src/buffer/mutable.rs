@@ -21,7 +21,7 @@ fn capacity_multiple_of_64<T: NativeType>(capacity: usize) -> usize {
 /// A [`MutableBuffer`] is this crates' interface to store types that are byte-like, such as `i32`.
 /// It behaves like a [`Vec`], with the following differences:
 /// * memory is allocated along cache lines and in multiple of 64 bytes.
-/// * it can only hold types supported by the arrow format (`u8-u64`, `i8-i128`, `f32,f64` and [`crate::types::days_ms`])
+/// * it can only hold types supported by the arrow format (`u8-u64`, `i8-i128`, `f32,f64`, [`crate::types::days_ms`] and [`crate::types::months_days_ns`])
 /// A [`MutableBuffer`] can be converted to a [`Buffer`] via `.into`.
 /// # Example
 /// ```
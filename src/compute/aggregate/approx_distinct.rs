@@ -0,0 +1,265 @@
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::hash::{hash_columns_with_options, HashMode, HashOptions};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::Sketch;
+
+const DEFAULT_PRECISION: u8 = 14;
+const MIN_PRECISION: u8 = 4;
+const MAX_PRECISION: u8 = 18;
+
+/// A [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog) sketch used to estimate the
+/// number of distinct values of a set of hashes.
+///
+/// With precision `p`, the sketch uses `2^p` one-byte registers and has a relative standard
+/// error of about `1.04 / sqrt(2^p)` (~0.8% for the default `p = 14`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::try_new(DEFAULT_PRECISION).unwrap()
+    }
+}
+
+impl HyperLogLog {
+    /// Creates a new empty [`HyperLogLog`] with `2^precision` registers.
+    /// # Errors
+    /// This function errors iff `precision` is not between 4 and 18.
+    pub fn try_new(precision: u8) -> Result<Self> {
+        if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog precision must be between {} and {} (got {})",
+                MIN_PRECISION, MAX_PRECISION, precision
+            )));
+        }
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// The precision of this sketch.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Adds a (64-bit) hash to the sketch.
+    #[inline]
+    pub fn add_hash(&mut self, hash: u64) {
+        let p = self.precision as u32;
+        let index = (hash >> (64 - p)) as usize;
+        // the remaining `64 - p` bits, left-aligned; the sentinel bit bounds the rank
+        let rest = (hash << p) | (1 << (p - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Adds all non-null hashes of `hashes` to the sketch.
+    pub fn add_hashes(&mut self, hashes: &PrimitiveArray<u64>) {
+        hashes.iter().flatten().for_each(|x| self.add_hash(*x))
+    }
+
+    /// Returns the estimated number of distinct hashes added to this sketch.
+    pub fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let (sum, zeros) =
+            self.registers
+                .iter()
+                .fold((0.0f64, 0usize), |(sum, zeros), register| {
+                    (
+                        sum + 2f64.powi(-(*register as i32)),
+                        zeros + (*register == 0) as usize,
+                    )
+                });
+        let estimate = alpha * m * m / sum;
+
+        // small range correction via linear counting
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+impl Sketch for HyperLogLog {
+    fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.precision != other.precision {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot merge HyperLogLog sketches of different precisions ({} != {})",
+                self.precision, other.precision
+            )));
+        }
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(lhs, rhs)| *lhs = std::cmp::max(*lhs, *rhs));
+        Ok(())
+    }
+
+    /// The layout is one byte with the precision followed by the registers.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.registers.len());
+        bytes.push(self.precision);
+        bytes.extend_from_slice(&self.registers);
+        bytes
+    }
+
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (precision, registers) = bytes.split_first().ok_or_else(|| {
+            ArrowError::InvalidArgumentError("Empty HyperLogLog sketch".to_string())
+        })?;
+        let mut sketch = Self::try_new(*precision)?;
+        if registers.len() != sketch.registers.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A HyperLogLog sketch of precision {} must have {} registers (got {})",
+                precision,
+                sketch.registers.len(),
+                registers.len()
+            )));
+        }
+        sketch.registers.copy_from_slice(registers);
+        Ok(sketch)
+    }
+}
+
+/// Returns a [`HyperLogLog`] sketch of the non-null values of `array`.
+/// Values are hashed with [`HashMode::Stable`], so that sketches can be persisted and merged
+/// with sketches computed by other versions of this crate or on other platforms.
+/// Supported types are those supported by [`crate::compute::hash::hash_columns`].
+/// # Errors
+/// This function errors iff the array's type is not supported.
+pub fn approx_count_distinct_sketch(array: &dyn Array) -> Result<HyperLogLog> {
+    let options = HashOptions {
+        seed: 0,
+        mode: HashMode::Stable,
+    };
+    let hashes = hash_columns_with_options(&[array], &options)?;
+    let mut sketch = HyperLogLog::default();
+    if array.data_type() == &DataType::Null {
+        // all values of a `NullArray` are null, but it has no validity
+        return Ok(sketch);
+    }
+    hashes
+        .values()
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| array.is_valid(*row))
+        .for_each(|(_, hash)| sketch.add_hash(*hash));
+    Ok(sketch)
+}
+
+/// Returns an estimate of the number of distinct non-null values of `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::approx_count_distinct;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(1)]);
+/// assert_eq!(approx_count_distinct(&array).unwrap(), 2);
+/// ```
+/// # Errors
+/// This function errors iff the array's type is not supported.
+pub fn approx_count_distinct(array: &dyn Array) -> Result<u64> {
+    Ok(approx_count_distinct_sketch(array)?.count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::compute::aggregate::{merge_sketches, sketches_to_binary};
+    use crate::datatypes::DataType;
+
+    fn assert_close(estimate: u64, expected: u64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(error < 0.03, "{} is not close to {}", estimate, expected);
+    }
+
+    #[test]
+    fn small() {
+        let array = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None, Some("a")]);
+        assert_eq!(approx_count_distinct(&array).unwrap(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let array = Int64Array::from_slice(&[]);
+        assert_eq!(approx_count_distinct(&array).unwrap(), 0);
+    }
+
+    #[test]
+    fn large() {
+        let array = Int64Array::from_values((0..100_000).map(|x| x % 50_000));
+        assert_close(approx_count_distinct(&array).unwrap(), 50_000);
+    }
+
+    #[test]
+    fn floats() {
+        let array = Float64Array::from_values((0..10_000).map(|x| x as f64 / 3.0));
+        assert_close(approx_count_distinct(&array).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn merge_via_binary() -> Result<()> {
+        let lhs = Int32Array::from_values(0..20_000);
+        let rhs = Int32Array::from_values(10_000..30_000);
+        let sketches = vec![
+            approx_count_distinct_sketch(&lhs)?,
+            approx_count_distinct_sketch(&rhs)?,
+        ];
+        let binary = sketches_to_binary::<_, i32>(&sketches);
+        assert_eq!(binary.len(), 2);
+
+        let merged = merge_sketches::<HyperLogLog, _>(&binary)?.unwrap();
+        assert_close(merged.count(), 30_000);
+        Ok(())
+    }
+
+    #[test]
+    fn incompatible() {
+        let mut lhs = HyperLogLog::try_new(10).unwrap();
+        let rhs = HyperLogLog::try_new(12).unwrap();
+        assert!(lhs.merge(&rhs).is_err());
+        assert!(HyperLogLog::try_new(30).is_err());
+        assert!(HyperLogLog::try_from_bytes(&[10, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn nested_and_null() {
+        let array = new_null_array(DataType::Null, 2);
+        assert_eq!(approx_count_distinct(array.as_ref()).unwrap(), 0);
+
+        let values = Utf8Array::<i32>::from_slice(&["a", "b", "a"]);
+        let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        dictionary.try_extend(values.iter()).unwrap();
+        let dictionary: DictionaryArray<i32> = dictionary.into();
+        assert_eq!(approx_count_distinct(&dictionary).unwrap(), 2);
+    }
+
+    #[test]
+    fn stable() {
+        // the registers of a sketch must not change between versions nor platforms
+        let array = Int32Array::from_values(0..1000);
+        let sketch = approx_count_distinct_sketch(&array).unwrap();
+        let bytes = sketch.to_bytes();
+        let checksum = bytes.iter().enumerate().fold(0u64, |acc, (i, x)| {
+            acc.wrapping_add((i as u64 + 1) * *x as u64)
+        });
+        assert_eq!(checksum, 15955832);
+    }
+}
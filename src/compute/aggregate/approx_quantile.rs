@@ -0,0 +1,418 @@
+use std::convert::TryInto;
+
+use num::ToPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::Sketch;
+
+const DEFAULT_MAX_SIZE: usize = 100;
+const MAX_MAX_SIZE: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    #[inline]
+    fn add(&mut self, other: &Centroid) {
+        let weight = self.weight + other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / weight;
+        self.weight = weight;
+    }
+}
+
+/// Inverse of the `k_1` scale function of the t-digest, normalized to `[0, max_size]`.
+#[inline]
+fn k_to_q(k: f64, max_size: f64) -> f64 {
+    let k_div_d = k / max_size;
+    if k_div_d >= 0.5 {
+        let base = 1.0 - k_div_d;
+        1.0 - 2.0 * base * base
+    } else {
+        2.0 * k_div_d * k_div_d
+    }
+}
+
+/// A [t-digest](https://github.com/tdunning/t-digest) sketch used to estimate quantiles of a
+/// set of values.
+///
+/// The digest keeps at most `max_size` centroids, with more resolution on the tails of the
+/// distribution. Larger `max_size` yields more accurate quantiles at the cost of memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    max_size: usize,
+    centroids: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::try_new(DEFAULT_MAX_SIZE).unwrap()
+    }
+}
+
+impl TDigest {
+    /// Creates a new empty [`TDigest`] with at most `max_size` centroids.
+    /// # Errors
+    /// This function errors iff `max_size` is not between 1 and 100000.
+    pub fn try_new(max_size: usize) -> Result<Self> {
+        if !(1..=MAX_MAX_SIZE).contains(&max_size) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "TDigest maximum size must be between 1 and {} (got {})",
+                MAX_MAX_SIZE, max_size
+            )));
+        }
+        Ok(Self {
+            max_size,
+            centroids: vec![],
+            count: 0.0,
+            min: f64::NAN,
+            max: f64::NAN,
+        })
+    }
+
+    /// The maximum number of centroids of this digest.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// The number of values summarized by this digest.
+    pub fn count(&self) -> u64 {
+        self.count as u64
+    }
+
+    /// Adds `values` to this digest. Non-finite values (`NaN`s and infinities) are ignored.
+    pub fn add_values<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        let new = values
+            .into_iter()
+            .filter(|x| x.is_finite())
+            .map(|mean| Centroid { mean, weight: 1.0 });
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend(new);
+        self.compress(centroids);
+    }
+
+    fn compress(&mut self, mut centroids: Vec<Centroid>) {
+        if centroids.is_empty() {
+            return;
+        }
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        self.count = centroids.iter().map(|x| x.weight).sum();
+        self.min = self.min.min(centroids[0].mean);
+        self.max = self.max.max(centroids[centroids.len() - 1].mean);
+
+        let max_size = self.max_size as f64;
+        let mut k_limit = 1.0;
+        let mut q_limit_times_count = k_to_q(k_limit, max_size) * self.count;
+
+        let mut result = Vec::with_capacity(self.max_size);
+        let mut iter = centroids.into_iter();
+        let mut current = iter.next().unwrap();
+        let mut weight_so_far = current.weight;
+        for next in iter {
+            weight_so_far += next.weight;
+            if weight_so_far <= q_limit_times_count {
+                current.add(&next);
+            } else {
+                result.push(current);
+                k_limit += 1.0;
+                q_limit_times_count = k_to_q(k_limit, max_size) * self.count;
+                current = next;
+            }
+        }
+        result.push(current);
+        self.centroids = result;
+    }
+
+    /// Returns the estimated `q`-quantile of the values in this digest, or `None` if the
+    /// digest is empty.
+    /// # Errors
+    /// This function errors iff `q` is not between 0 and 1.
+    pub fn quantile(&self, q: f64) -> Result<Option<f64>> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The quantile must be between 0 and 1 (got {})",
+                q
+            )));
+        }
+        Ok(self.estimate(q))
+    }
+
+    fn estimate(&self, q: f64) -> Option<f64> {
+        let centroids = &self.centroids;
+        if centroids.is_empty() {
+            return None;
+        }
+        if q == 0.0 {
+            return Some(self.min);
+        }
+        if q == 1.0 {
+            return Some(self.max);
+        }
+
+        let rank = q * self.count;
+        let mut cumulative = 0.0;
+        for (i, centroid) in centroids.iter().enumerate() {
+            let center = cumulative + centroid.weight / 2.0;
+            if rank < center {
+                // interpolate between the previous center (or the minimum) and this one
+                let (previous_mean, previous_center) = if i == 0 {
+                    (self.min, 0.0)
+                } else {
+                    let previous = &centroids[i - 1];
+                    (previous.mean, cumulative - previous.weight / 2.0)
+                };
+                let t = (rank - previous_center) / (center - previous_center);
+                return Some(previous_mean + (centroid.mean - previous_mean) * t);
+            }
+            cumulative += centroid.weight;
+        }
+
+        // between the last center and the maximum
+        let last = &centroids[centroids.len() - 1];
+        let last_center = self.count - last.weight / 2.0;
+        let t = (rank - last_center) / (self.count - last_center);
+        Some(last.mean + (self.max - last.mean) * t)
+    }
+}
+
+fn read_f64(bytes: &[u8], index: usize) -> f64 {
+    f64::from_le_bytes(bytes[index * 8..(index + 1) * 8].try_into().unwrap())
+}
+
+impl Sketch for TDigest {
+    fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.max_size != other.max_size {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot merge TDigest sketches of different maximum sizes ({} != {})",
+                self.max_size, other.max_size
+            )));
+        }
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend_from_slice(&other.centroids);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.compress(centroids);
+        Ok(())
+    }
+
+    /// The layout is a sequence of little-endian 8-byte words: the maximum size, the minimum,
+    /// the maximum, followed by the mean and weight of each centroid.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 * (3 + 2 * self.centroids.len()));
+        bytes.extend_from_slice(&(self.max_size as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.min.to_le_bytes());
+        bytes.extend_from_slice(&self.max.to_le_bytes());
+        for centroid in &self.centroids {
+            bytes.extend_from_slice(&centroid.mean.to_le_bytes());
+            bytes.extend_from_slice(&centroid.weight.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 3 * 8 || bytes.len() % 16 != 8 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A TDigest sketch cannot be {} bytes long",
+                bytes.len()
+            )));
+        }
+        let max_size = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let mut sketch = Self::try_new(max_size.try_into().unwrap_or(usize::MAX))?;
+        let length = (bytes.len() - 3 * 8) / 16;
+        if length > sketch.max_size {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A TDigest sketch of maximum size {} cannot have {} centroids",
+                sketch.max_size, length
+            )));
+        }
+        let centroids = (0..length)
+            .map(|i| Centroid {
+                mean: read_f64(bytes, 3 + 2 * i),
+                weight: read_f64(bytes, 4 + 2 * i),
+            })
+            .collect::<Vec<_>>();
+        let is_valid = |x: &Centroid| x.mean.is_finite() && x.weight.is_finite() && x.weight > 0.0;
+        if !centroids.iter().all(is_valid) {
+            return Err(ArrowError::InvalidArgumentError(
+                "A TDigest sketch must have finite means and positive finite weights".to_string(),
+            ));
+        }
+        let (min, max) = (read_f64(bytes, 1), read_f64(bytes, 2));
+        let is_bounded = min.is_finite() && max.is_finite() && min <= max;
+        if !centroids.is_empty() && !is_bounded {
+            return Err(ArrowError::InvalidArgumentError(
+                "A non-empty TDigest sketch must have a finite minimum and maximum".to_string(),
+            ));
+        }
+        sketch.count = centroids.iter().map(|x| x.weight).sum();
+        sketch.centroids = centroids;
+        sketch.min = min;
+        sketch.max = max;
+        Ok(sketch)
+    }
+}
+
+fn primitive_values<T: NativeType + ToPrimitive>(
+    array: &dyn Array,
+) -> impl Iterator<Item = f64> + '_ {
+    array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .iter()
+        .flatten()
+        .map(|x| x.to_f64().unwrap())
+}
+
+/// Returns a [`TDigest`] sketch of the non-null values of `array`.
+/// # Errors
+/// This function errors iff the array is not of a numeric type.
+pub fn approx_quantile_sketch(array: &dyn Array) -> Result<TDigest> {
+    let mut sketch = TDigest::default();
+    match array.data_type() {
+        DataType::Int8 => sketch.add_values(primitive_values::<i8>(array)),
+        DataType::Int16 => sketch.add_values(primitive_values::<i16>(array)),
+        DataType::Int32 => sketch.add_values(primitive_values::<i32>(array)),
+        DataType::Int64 => sketch.add_values(primitive_values::<i64>(array)),
+        DataType::UInt8 => sketch.add_values(primitive_values::<u8>(array)),
+        DataType::UInt16 => sketch.add_values(primitive_values::<u16>(array)),
+        DataType::UInt32 => sketch.add_values(primitive_values::<u32>(array)),
+        DataType::UInt64 => sketch.add_values(primitive_values::<u64>(array)),
+        DataType::Float32 => sketch.add_values(primitive_values::<f32>(array)),
+        DataType::Float64 => sketch.add_values(primitive_values::<f64>(array)),
+        dt => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "\"approx_quantile\" does not support type {:?}",
+                dt
+            )))
+        }
+    };
+    Ok(sketch)
+}
+
+/// Returns an estimate of the `q`-quantile of the non-null values of `array`, or `None`
+/// if there are no such values.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::approx_quantile;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(2)]);
+/// assert_eq!(approx_quantile(&array, 0.5).unwrap(), Some(2.0));
+/// ```
+/// # Errors
+/// This function errors iff the array is not of a numeric type or `q` is not between 0 and 1.
+pub fn approx_quantile(array: &dyn Array, q: f64) -> Result<Option<f64>> {
+    approx_quantile_sketch(array)?.quantile(q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::compute::aggregate::{merge_sketches, sketches_to_binary};
+
+    #[test]
+    fn exact_when_small() -> Result<()> {
+        let array =
+            Float64Array::from(&[Some(5.0), Some(1.0), None, Some(3.0), Some(2.0), Some(4.0)]);
+        assert_eq!(approx_quantile(&array, 0.0)?, Some(1.0));
+        assert_eq!(approx_quantile(&array, 0.5)?, Some(3.0));
+        assert_eq!(approx_quantile(&array, 1.0)?, Some(5.0));
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> Result<()> {
+        let array = Int32Array::from(&[None, None]);
+        assert_eq!(approx_quantile(&array, 0.5)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn large() -> Result<()> {
+        let array = Int64Array::from_values((0..100_000).rev());
+        let sketch = approx_quantile_sketch(&array)?;
+        assert!(sketch.centroids.len() <= sketch.max_size());
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let estimate = sketch.quantile(q)?.unwrap();
+            assert!(
+                (estimate - q * 100_000.0).abs() < 500.0,
+                "{} {}",
+                q,
+                estimate
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn merge_via_binary() -> Result<()> {
+        let lhs = UInt32Array::from_values(0..50_000);
+        let rhs = UInt32Array::from_values(50_000..100_000);
+        let sketches = vec![approx_quantile_sketch(&lhs)?, approx_quantile_sketch(&rhs)?];
+        let binary = sketches_to_binary::<_, i64>(&sketches);
+
+        let merged = merge_sketches::<TDigest, _>(&binary)?.unwrap();
+        assert_eq!(merged.count(), 100_000);
+        assert_eq!(merged.quantile(0.0)?, Some(0.0));
+        assert_eq!(merged.quantile(1.0)?, Some(99_999.0));
+        let median = merged.quantile(0.5)?.unwrap();
+        assert!((median - 50_000.0).abs() < 500.0);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let array = Int32Array::from_slice(&[1]);
+        assert!(approx_quantile(&array, 1.5).is_err());
+        let array = Utf8Array::<i32>::from_slice(&["a"]);
+        assert!(approx_quantile(&array, 0.5).is_err());
+        assert!(TDigest::try_from_bytes(&[0; 9]).is_err());
+        assert!(TDigest::default().quantile(f64::NAN).is_err());
+
+        let mut lhs = TDigest::try_new(10).unwrap();
+        assert!(lhs.merge(&TDigest::try_new(20).unwrap()).is_err());
+        assert!(TDigest::try_new(0).is_err());
+        assert!(TDigest::try_new(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let mut sketch = TDigest::try_new(10).unwrap();
+        sketch.add_values([1.0, 2.0, f64::INFINITY, f64::NEG_INFINITY]);
+        assert_eq!(sketch.count(), 2);
+        let bytes = sketch.to_bytes();
+        assert_eq!(TDigest::try_from_bytes(&bytes).unwrap(), sketch);
+
+        let mut large = bytes.clone();
+        large[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(TDigest::try_from_bytes(&large).is_err());
+        let mut small = bytes.clone();
+        small[..8].copy_from_slice(&1u64.to_le_bytes());
+        assert!(TDigest::try_from_bytes(&small).is_err());
+
+        // the mean and weight of the first centroid are at words 3 and 4
+        for (word, value) in [
+            (3, f64::NAN),
+            (3, f64::INFINITY),
+            (4, f64::NAN),
+            (4, -1.0),
+            (4, 0.0),
+            (1, f64::NAN),
+        ] {
+            let mut bytes = bytes.clone();
+            bytes[word * 8..(word + 1) * 8].copy_from_slice(&value.to_le_bytes());
+            assert!(TDigest::try_from_bytes(&bytes).is_err());
+        }
+    }
+}
@@ -7,3 +7,12 @@ pub use min_max::*;
 mod memory;
 pub use memory::*;
 mod simd;
+
+mod sketch;
+pub use sketch::*;
+
+mod approx_distinct;
+pub use approx_distinct::*;
+
+mod approx_quantile;
+pub use approx_quantile::*;
@@ -0,0 +1,55 @@
+use crate::array::{BinaryArray, Offset};
+use crate::error::Result;
+
+/// A mergeable summary of a set of values whose state can be stored as bytes, e.g. on a
+/// [`BinaryArray`] written to Parquet, and merged later.
+pub trait Sketch: Sized {
+    /// Merges `other` into `self`.
+    /// # Errors
+    /// This function errors when both sketches were created with incompatible parameters.
+    fn merge(&mut self, other: &Self) -> Result<()>;
+
+    /// Serializes this sketch into bytes.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Deserializes a sketch from bytes produced by [`Sketch::to_bytes`].
+    /// # Errors
+    /// This function errors when `bytes` is not a valid serialized sketch.
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self>;
+}
+
+/// Serializes `sketches` into a [`BinaryArray`], one sketch per slot.
+pub fn sketches_to_binary<S: Sketch, O: Offset>(sketches: &[S]) -> BinaryArray<O> {
+    sketches.iter().map(|x| Some(x.to_bytes())).collect()
+}
+
+/// Deserializes and merges all non-null sketches of a [`BinaryArray`] into a single sketch.
+/// Returns `None` when the array has no non-null slots.
+/// # Errors
+/// This function errors when a slot is not a valid sketch or when sketches are incompatible.
+pub fn merge_sketches<S: Sketch, O: Offset>(array: &BinaryArray<O>) -> Result<Option<S>> {
+    let mut result: Option<S> = None;
+    for bytes in array.iter().flatten() {
+        let sketch = S::try_from_bytes(bytes)?;
+        match result.as_mut() {
+            Some(result) => result.merge(&sketch)?,
+            None => result = Some(sketch),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Array;
+    use crate::compute::aggregate::HyperLogLog;
+
+    #[test]
+    fn nulls_are_skipped() -> Result<()> {
+        let array = BinaryArray::<i32>::from(&[None::<&[u8]>, None]);
+        assert!(merge_sketches::<HyperLogLog, _>(&array)?.is_none());
+        assert_eq!(array.null_count(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+//! Definition of bitwise and shift operations with integer primitive arrays.
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use num::{
+    cast::AsPrimitive,
+    traits::{CheckedShl, CheckedShr, PrimInt, WrappingShl, WrappingShr},
+    ToPrimitive, Zero,
+};
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+#[inline]
+fn check_same_type<T: NativeType>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<()> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same logical type".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Performs `AND` on the bits of two arrays of integers.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::bitwise::bitwise_and;
+/// use arrow2::array::UInt8Array;
+///
+/// let a = UInt8Array::from(&[Some(0b1100), None, Some(0b1111)]);
+/// let b = UInt8Array::from(&[Some(0b1010), Some(1), Some(0)]);
+/// let result = bitwise_and(&a, &b).unwrap();
+/// assert_eq!(result, UInt8Array::from(&[Some(0b1000), None, Some(0)]));
+/// ```
+pub fn bitwise_and<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitAnd<Output = T>,
+{
+    check_same_type(lhs, rhs)?;
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a & b)
+}
+
+/// Performs `OR` on the bits of two arrays of integers.
+pub fn bitwise_or<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitOr<Output = T>,
+{
+    check_same_type(lhs, rhs)?;
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a | b)
+}
+
+/// Performs `XOR` on the bits of two arrays of integers.
+pub fn bitwise_xor<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitXor<Output = T>,
+{
+    check_same_type(lhs, rhs)?;
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a ^ b)
+}
+
+/// Performs `AND` on the bits of every value of an array of integers and a scalar.
+pub fn bitwise_and_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + BitAnd<Output = T>,
+{
+    let rhs = *rhs;
+    unary(lhs, |a| a & rhs, lhs.data_type().clone())
+}
+
+/// Performs `OR` on the bits of every value of an array of integers and a scalar.
+pub fn bitwise_or_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + BitOr<Output = T>,
+{
+    let rhs = *rhs;
+    unary(lhs, |a| a | rhs, lhs.data_type().clone())
+}
+
+/// Performs `XOR` on the bits of every value of an array of integers and a scalar.
+pub fn bitwise_xor_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + BitXor<Output = T>,
+{
+    let rhs = *rhs;
+    unary(lhs, |a| a ^ rhs, lhs.data_type().clone())
+}
+
+/// Performs `NOT` on the bits of every value of an array of integers.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::bitwise::bitwise_not;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(0), None, Some(-1)]);
+/// assert_eq!(bitwise_not(&a), Int8Array::from(&[Some(-1), None, Some(0)]));
+/// ```
+pub fn bitwise_not<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Not<Output = T>,
+{
+    unary(array, |a| !a, array.data_type().clone())
+}
+
+/// Returns the number of ones in the binary representation of every value of an array of
+/// integers, as a `UInt32` array.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::bitwise::count_ones;
+/// use arrow2::array::{Int16Array, UInt32Array};
+///
+/// let a = Int16Array::from(&[Some(0b1011), None, Some(-1)]);
+/// assert_eq!(count_ones(&a), UInt32Array::from(&[Some(3), None, Some(16)]));
+/// ```
+pub fn count_ones<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<u32>
+where
+    T: NativeType + PrimInt,
+{
+    unary(array, |a| a.count_ones(), DataType::UInt32)
+}
+
+/// Shifts the bits of every value of `lhs` to the left by the value of `rhs` in the same slot.
+/// Shift amounts are taken modulo the number of bits of the type, as in
+/// [`u32::wrapping_shl`]; use [`checked_shift_left`] to reject them instead.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::bitwise::shift_left;
+/// use arrow2::array::UInt8Array;
+///
+/// let a = UInt8Array::from(&[Some(1), None, Some(1)]);
+/// let b = UInt8Array::from(&[Some(3), Some(1), Some(9)]);
+/// let result = shift_left(&a, &b).unwrap();
+/// assert_eq!(result, UInt8Array::from(&[Some(8), None, Some(2)]));
+/// ```
+pub fn shift_left<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    check_same_type(lhs, rhs)?;
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shl(b.as_())
+    })
+}
+
+/// Shifts the bits of every value of `lhs` to the left by the value of `rhs` in the same slot.
+/// Slots whose shift amount is negative or not smaller than the number of bits of the type
+/// are null.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::bitwise::checked_shift_left;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(1), Some(1), Some(1)]);
+/// let b = Int8Array::from(&[Some(7), Some(8), Some(-1)]);
+/// let result = checked_shift_left(&a, &b).unwrap();
+/// assert_eq!(result, Int8Array::from(&[Some(i8::MIN), None, None]));
+/// ```
+pub fn checked_shift_left<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedShl + ToPrimitive + Zero,
+{
+    check_same_type(lhs, rhs)?;
+    let op = |a: T, b: T| b.to_u32().and_then(|b| a.checked_shl(b));
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Shifts the bits of every value of `lhs` to the right by the value of `rhs` in the same
+/// slot. Signed integers are shifted arithmetically, i.e. their sign is preserved.
+/// Shift amounts are taken modulo the number of bits of the type, as in
+/// [`u32::wrapping_shr`]; use [`checked_shift_right`] to reject them instead.
+pub fn shift_right<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    check_same_type(lhs, rhs)?;
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shr(b.as_())
+    })
+}
+
+/// Shifts the bits of every value of `lhs` to the right by the value of `rhs` in the same
+/// slot. Slots whose shift amount is negative or not smaller than the number of bits of the
+/// type are null.
+pub fn checked_shift_right<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedShr + ToPrimitive + Zero,
+{
+    check_same_type(lhs, rhs)?;
+    let op = |a: T, b: T| b.to_u32().and_then(|b| a.checked_shr(b));
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Shifts the bits of every value of `lhs` to the left by `rhs`, modulo the number of bits
+/// of the type.
+pub fn shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl,
+{
+    unary(lhs, |a| a.wrapping_shl(rhs), lhs.data_type().clone())
+}
+
+/// Shifts the bits of every value of `lhs` to the left by `rhs`. All values are null when
+/// `rhs` is not smaller than the number of bits of the type.
+pub fn checked_shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShl + Zero,
+{
+    unary_checked(lhs, |a| a.checked_shl(rhs), lhs.data_type().clone())
+}
+
+/// Shifts the bits of every value of `lhs` to the right by `rhs`, modulo the number of bits
+/// of the type. Signed integers are shifted arithmetically.
+pub fn shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr,
+{
+    unary(lhs, |a| a.wrapping_shr(rhs), lhs.data_type().clone())
+}
+
+/// Shifts the bits of every value of `lhs` to the right by `rhs`. All values are null when
+/// `rhs` is not smaller than the number of bits of the type.
+pub fn checked_shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedShr + Zero,
+{
+    unary_checked(lhs, |a| a.checked_shr(rhs), lhs.data_type().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    #[test]
+    fn test_bitwise() {
+        let a = Int32Array::from(&[Some(0b0110), None, Some(-1)]);
+        let b = Int32Array::from(&[Some(0b0011), Some(1), Some(0b0101)]);
+        assert_eq!(
+            bitwise_or(&a, &b).unwrap(),
+            Int32Array::from(&[Some(0b0111), None, Some(-1)])
+        );
+        assert_eq!(
+            bitwise_xor(&a, &b).unwrap(),
+            Int32Array::from(&[Some(0b0101), None, Some(!0b0101)])
+        );
+        assert_eq!(
+            bitwise_and_scalar(&a, &0b0100),
+            Int32Array::from(&[Some(0b0100), None, Some(0b0100)])
+        );
+
+        let b = Int32Array::from_slice(&[1]);
+        assert!(bitwise_and(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_shift() {
+        let a = Int16Array::from(&[Some(-16), None, Some(3), Some(1)]);
+        let b = Int16Array::from(&[Some(2), Some(1), Some(16), Some(-1)]);
+        assert_eq!(
+            shift_right(&a, &b).unwrap(),
+            Int16Array::from(&[Some(-4), None, Some(3), Some(0)])
+        );
+        assert_eq!(
+            checked_shift_right(&a, &b).unwrap(),
+            Int16Array::from(&[Some(-4), None, None, None])
+        );
+        assert_eq!(
+            shift_left_scalar(&a, 17),
+            Int16Array::from(&[Some(-32), None, Some(6), Some(2)])
+        );
+        assert_eq!(checked_shift_left_scalar(&a, 16).null_count(), 4);
+        assert_eq!(
+            checked_shift_right_scalar(&a, 1),
+            Int16Array::from(&[Some(-8), None, Some(1), Some(0)])
+        );
+    }
+}
@@ -0,0 +1,700 @@
+//! Definition of elementwise math functions of primitive arrays, such as `abs`, `sqrt` or `sin`.
+//!
+//! Functions whose result is undefined for some values (e.g. `sqrt` of negative numbers)
+//! return `NaN` for those values; their `checked_` variant returns a null instead.
+//! Functions only defined for floats, such as `sqrt` or `floor`, are generic over float
+//! arrays; [`math`] applies them to arrays of any numeric type. Integer arrays keep their type
+//! for functions with integer results, such as `abs` or `floor`, and are cast to `Float64` for
+//! the others.
+use num::{
+    traits::{CheckedNeg, Float},
+    NumCast, PrimInt, Signed,
+};
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    compute::{
+        arity::{unary, unary_checked},
+        cast::cast,
+    },
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// Returns the absolute value of every value of the array. The absolute value of the minimum
+/// value of a signed integer type overflows: it panics in debug builds and wraps around
+/// (i.e. remains the minimum value) in release builds; use [`checked_abs`] to get a null instead.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::abs;
+/// use arrow2::array::Int32Array;
+///
+/// let a = Int32Array::from(&[Some(-1), None, Some(2)]);
+/// let result = abs(&a);
+/// assert_eq!(result, Int32Array::from(&[Some(1), None, Some(2)]));
+/// ```
+pub fn abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed,
+{
+    unary(array, |x| x.abs(), array.data_type().clone())
+}
+
+/// Returns the absolute value of every value of the array. Values whose absolute value
+/// overflows, i.e. the minimum value of a signed integer type, are null.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::checked_abs;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-1), None, Some(i8::MIN)]);
+/// let result = checked_abs(&a);
+/// assert_eq!(result, Int8Array::from(&[Some(1), None, None]));
+/// ```
+pub fn checked_abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed + CheckedNeg,
+{
+    let op = |x: T| {
+        if x.is_negative() {
+            x.checked_neg()
+        } else {
+            Some(x)
+        }
+    };
+    unary_checked(array, op, array.data_type().clone())
+}
+
+/// Returns the sign of every value of the array: `-1` for negative values, `1` for positive
+/// values and `0` for zeros. `NaN`s remain `NaN`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::sign;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(-2.5), Some(0.0), None, Some(3.0)]);
+/// let result = sign(&a);
+/// assert_eq!(result, Float64Array::from(&[Some(-1.0), Some(0.0), None, Some(1.0)]));
+/// ```
+pub fn sign<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Signed + PartialOrd,
+{
+    let op = |x: T| {
+        if x > T::zero() {
+            T::one()
+        } else if x < T::zero() {
+            -T::one()
+        } else {
+            x
+        }
+    };
+    unary(array, op, array.data_type().clone())
+}
+
+/// Limits every value of the array to the interval `[min, max]`. `NaN`s remain `NaN`.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::clip;
+/// use arrow2::array::UInt8Array;
+///
+/// let a = UInt8Array::from(&[Some(1), None, Some(5), Some(9)]);
+/// let result = clip(&a, 2, 6);
+/// assert_eq!(result, UInt8Array::from(&[Some(2), None, Some(5), Some(6)]));
+/// ```
+/// # Panics
+/// This function panics iff `min > max` or a bound is `NaN`; use [`try_clip`] to get an error
+/// instead.
+pub fn clip<T>(array: &PrimitiveArray<T>, min: T, max: T) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    try_clip(array, min, max).unwrap()
+}
+
+/// Limits every value of the array to the interval `[min, max]`. `NaN`s remain `NaN`.
+/// # Errors
+/// This function errors iff `min > max` or a bound is `NaN`.
+pub fn try_clip<T>(array: &PrimitiveArray<T>, min: T, max: T) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    // `NaN` bounds are not ordered
+    let is_ordered = matches!(
+        min.partial_cmp(&max),
+        Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+    );
+    if !is_ordered {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The bounds of clip must be ordered (got {:?} and {:?})",
+            min, max
+        )));
+    }
+    let op = |x: T| {
+        if x < min {
+            min
+        } else if x > max {
+            max
+        } else {
+            x
+        }
+    };
+    Ok(unary(array, op, array.data_type().clone()))
+}
+
+macro_rules! float_unary {
+    ($(#[$doc:meta])* $name:ident, $method:ident) => {
+        $(#[$doc])*
+        pub fn $name<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+        where
+            T: NativeType + Float,
+        {
+            unary(array, |x| x.$method(), array.data_type().clone())
+        }
+    };
+}
+
+macro_rules! float_checked_unary {
+    ($(#[$doc:meta])* $name:ident, $method:ident, $domain:expr) => {
+        $(#[$doc])*
+        pub fn $name<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+        where
+            T: NativeType + Float,
+        {
+            let op = |x: T| if $domain(x) { Some(x.$method()) } else { None };
+            unary_checked(array, op, array.data_type().clone())
+        }
+    };
+}
+
+float_unary!(
+    /// Returns the square root of every value of the array, `NaN` for negative values.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow2::compute::arithmetics::basic::math::sqrt;
+    /// use arrow2::array::Float32Array;
+    ///
+    /// let a = Float32Array::from(&[Some(4.0), None, Some(-1.0)]);
+    /// let result = sqrt(&a);
+    /// assert_eq!(result.value(0), 2.0);
+    /// assert!(result.value(2).is_nan());
+    /// ```
+    sqrt,
+    sqrt
+);
+float_checked_unary!(
+    /// Returns the square root of every value of the array. Negative values are null.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow2::compute::arithmetics::basic::math::checked_sqrt;
+    /// use arrow2::array::Float32Array;
+    ///
+    /// let a = Float32Array::from(&[Some(4.0), None, Some(-1.0)]);
+    /// let result = checked_sqrt(&a);
+    /// assert_eq!(result, Float32Array::from(&[Some(2.0), None, None]));
+    /// ```
+    checked_sqrt,
+    sqrt,
+    |x: T| x >= T::zero()
+);
+float_unary!(
+    /// Returns the cube root of every value of the array.
+    cbrt,
+    cbrt
+);
+float_unary!(
+    /// Returns `e` to the power of every value of the array.
+    exp,
+    exp
+);
+float_unary!(
+    /// Returns the natural logarithm of every value of the array, `NaN` for negative values
+    /// and `-inf` for zeros.
+    ln,
+    ln
+);
+float_checked_unary!(
+    /// Returns the natural logarithm of every value of the array. Zeros and negative values
+    /// are null.
+    checked_ln,
+    ln,
+    |x: T| x > T::zero()
+);
+float_unary!(
+    /// Returns the base 2 logarithm of every value of the array, `NaN` for negative values
+    /// and `-inf` for zeros.
+    log2,
+    log2
+);
+float_checked_unary!(
+    /// Returns the base 2 logarithm of every value of the array. Zeros and negative values
+    /// are null.
+    checked_log2,
+    log2,
+    |x: T| x > T::zero()
+);
+float_unary!(
+    /// Returns the base 10 logarithm of every value of the array, `NaN` for negative values
+    /// and `-inf` for zeros.
+    log10,
+    log10
+);
+float_checked_unary!(
+    /// Returns the base 10 logarithm of every value of the array. Zeros and negative values
+    /// are null.
+    checked_log10,
+    log10,
+    |x: T| x > T::zero()
+);
+float_unary!(
+    /// Returns the sine of every value of the array, in radians.
+    sin,
+    sin
+);
+float_unary!(
+    /// Returns the cosine of every value of the array, in radians.
+    cos,
+    cos
+);
+float_unary!(
+    /// Returns the tangent of every value of the array, in radians.
+    tan,
+    tan
+);
+float_unary!(
+    /// Returns the arcsine of every value of the array in radians, `NaN` for values outside
+    /// `[-1, 1]`.
+    asin,
+    asin
+);
+float_checked_unary!(
+    /// Returns the arcsine of every value of the array in radians. Values outside `[-1, 1]`
+    /// are null.
+    checked_asin,
+    asin,
+    |x: T| x.abs() <= T::one()
+);
+float_unary!(
+    /// Returns the arccosine of every value of the array in radians, `NaN` for values outside
+    /// `[-1, 1]`.
+    acos,
+    acos
+);
+float_checked_unary!(
+    /// Returns the arccosine of every value of the array in radians. Values outside `[-1, 1]`
+    /// are null.
+    checked_acos,
+    acos,
+    |x: T| x.abs() <= T::one()
+);
+float_unary!(
+    /// Returns the arctangent of every value of the array, in radians.
+    atan,
+    atan
+);
+float_unary!(
+    /// Returns the largest integer smaller than or equal to every value of the array.
+    floor,
+    floor
+);
+float_unary!(
+    /// Returns the smallest integer larger than or equal to every value of the array.
+    ceil,
+    ceil
+);
+float_unary!(
+    /// Returns the integer part of every value of the array.
+    trunc,
+    trunc
+);
+
+/// Returns the logarithm of every value of the array in base `base`, `NaN` for negative
+/// values and `-inf` for zeros.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::log;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(9.0), None]);
+/// let result = log(&a, 3.0);
+/// assert_eq!(result, Float64Array::from(&[Some(2.0), None]));
+/// ```
+pub fn log<T>(array: &PrimitiveArray<T>, base: T) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    unary(array, |x| x.log(base), array.data_type().clone())
+}
+
+/// Returns the logarithm of every value of the array in base `base`. Zeros and negative values
+/// are null.
+pub fn checked_log<T>(array: &PrimitiveArray<T>, base: T) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    let op = |x: T| {
+        if x > T::zero() {
+            Some(x.log(base))
+        } else {
+            None
+        }
+    };
+    unary_checked(array, op, array.data_type().clone())
+}
+
+/// Rounds every value of the array to `digits` decimal digits, half away from zero.
+/// Negative `digits` round to the left of the decimal point, e.g. `-1` rounds to tens.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::round;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(1.25), None, Some(-15.0)]);
+/// assert_eq!(round(&a, 1), Float64Array::from(&[Some(1.3), None, Some(-15.0)]));
+/// assert_eq!(round(&a, -1), Float64Array::from(&[Some(0.0), None, Some(-20.0)]));
+/// ```
+pub fn round<T>(array: &PrimitiveArray<T>, digits: i32) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    let factor = T::from(10).unwrap().powi(digits);
+    let op = |x: T| {
+        // the factor underflows for large negative `digits`, to which every value rounds to 0
+        if factor.is_zero() && x.is_finite() {
+            return T::zero();
+        }
+        let rounded = (x * factor).round() / factor;
+        // values too large to be scaled have no fractional digits
+        if rounded.is_finite() {
+            rounded
+        } else {
+            x
+        }
+    };
+    unary(array, op, array.data_type().clone())
+}
+
+/// A function applied to the values of an array by [`math`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MathFunction {
+    /// See [`abs`]
+    Abs,
+    /// See [`sign`]
+    Sign,
+    /// See [`sqrt`]
+    Sqrt,
+    /// See [`cbrt`]
+    Cbrt,
+    /// See [`exp`]
+    Exp,
+    /// See [`ln`]
+    Ln,
+    /// See [`log2`]
+    Log2,
+    /// See [`log10`]
+    Log10,
+    /// See [`log`]
+    Log(f64),
+    /// See [`sin`]
+    Sin,
+    /// See [`cos`]
+    Cos,
+    /// See [`tan`]
+    Tan,
+    /// See [`asin`]
+    Asin,
+    /// See [`acos`]
+    Acos,
+    /// See [`atan`]
+    Atan,
+    /// See [`floor`]
+    Floor,
+    /// See [`ceil`]
+    Ceil,
+    /// See [`trunc`]
+    Trunc,
+    /// See [`round`]
+    Round(i32),
+}
+
+fn apply<T: NativeType + Float + Signed>(
+    array: &PrimitiveArray<T>,
+    function: MathFunction,
+) -> PrimitiveArray<T> {
+    match function {
+        MathFunction::Abs => abs(array),
+        MathFunction::Sign => sign(array),
+        MathFunction::Sqrt => sqrt(array),
+        MathFunction::Cbrt => cbrt(array),
+        MathFunction::Exp => exp(array),
+        MathFunction::Ln => ln(array),
+        MathFunction::Log2 => log2(array),
+        MathFunction::Log10 => log10(array),
+        MathFunction::Log(base) => log(array, T::from(base).unwrap()),
+        MathFunction::Sin => sin(array),
+        MathFunction::Cos => cos(array),
+        MathFunction::Tan => tan(array),
+        MathFunction::Asin => asin(array),
+        MathFunction::Acos => acos(array),
+        MathFunction::Atan => atan(array),
+        MathFunction::Floor => floor(array),
+        MathFunction::Ceil => ceil(array),
+        MathFunction::Trunc => trunc(array),
+        MathFunction::Round(digits) => round(array, digits),
+    }
+}
+
+/// Rounds the integer `x` to `-digits` decimal digits to the left of the decimal point, half
+/// away from zero, or returns `None` when the result overflows `T`.
+fn round_integer<T: PrimInt>(x: T, digits: i32) -> Option<T> {
+    if digits >= 0 {
+        return Some(x);
+    }
+    // every integer type fits in `i128`, where the factor is at most `10^38`
+    let x = x.to_i128().unwrap();
+    let rounded = match 10i128.checked_pow(digits.unsigned_abs()) {
+        Some(factor) => {
+            let remainder = x % factor;
+            let truncated = x - remainder;
+            if remainder.abs() >= factor - remainder.abs() {
+                truncated + remainder.signum() * factor
+            } else {
+                truncated
+            }
+        }
+        None => 0,
+    };
+    NumCast::from(rounded)
+}
+
+/// Applies `function` to the integer `array`, keeping its type for functions with integer
+/// results: the absolute value of the minimum of a signed type and rounded values that do not
+/// fit the type are null. Other functions are applied to the array cast to `Float64`.
+fn apply_integer<T: NativeType + PrimInt>(
+    array: &PrimitiveArray<T>,
+    function: MathFunction,
+) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+    Ok(match function {
+        MathFunction::Abs => Box::new(unary_checked(
+            array,
+            |x| {
+                if x < T::zero() {
+                    T::zero().checked_sub(&x)
+                } else {
+                    Some(x)
+                }
+            },
+            data_type,
+        )),
+        MathFunction::Sign => Box::new(unary(
+            array,
+            |x| match x.cmp(&T::zero()) {
+                std::cmp::Ordering::Greater => T::one(),
+                // only reached by signed types
+                std::cmp::Ordering::Less => T::zero() - T::one(),
+                std::cmp::Ordering::Equal => x,
+            },
+            data_type,
+        )),
+        MathFunction::Floor | MathFunction::Ceil | MathFunction::Trunc => Box::new(array.clone()),
+        MathFunction::Round(digits) => Box::new(unary_checked(
+            array,
+            |x| round_integer(x, digits),
+            data_type,
+        )),
+        function => {
+            let array = cast(array, &DataType::Float64)?;
+            return math(array.as_ref(), function);
+        }
+    })
+}
+
+/// Applies `function` to every value of `array`. `Float32` and `Float64` arrays keep their
+/// type. Integer arrays keep their type for [`MathFunction::Abs`], [`MathFunction::Sign`],
+/// [`MathFunction::Floor`], [`MathFunction::Ceil`], [`MathFunction::Trunc`] and
+/// [`MathFunction::Round`] (where values that overflow, such as the absolute value of
+/// `i64::MIN`, are null), and are cast to `Float64` for the other functions.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::math::{math, MathFunction};
+/// use arrow2::array::{Float64Array, Int32Array};
+///
+/// let a = Int32Array::from(&[Some(4), None, Some(9)]);
+/// let result = math(&a, MathFunction::Sqrt).unwrap();
+/// assert_eq!(Float64Array::from(&[Some(2.0), None, Some(3.0)]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff the array is not of a numeric type (see [`can_math`]).
+pub fn math(array: &dyn Array, function: MathFunction) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Float32 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(apply::<f32>(array, function)))
+        }
+        DataType::Float64 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(apply::<f64>(array, function)))
+        }
+        DataType::Int8 => apply_integer::<i8>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::Int16 => apply_integer::<i16>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::Int32 => apply_integer::<i32>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::Int64 => apply_integer::<i64>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::UInt8 => apply_integer::<u8>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::UInt16 => apply_integer::<u16>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::UInt32 => apply_integer::<u32>(array.as_any().downcast_ref().unwrap(), function),
+        DataType::UInt64 => apply_integer::<u64>(array.as_any().downcast_ref().unwrap(), function),
+        data_type => Err(ArrowError::NotYetImplemented(format!(
+            "Math functions are not supported for type {:?}",
+            data_type
+        ))),
+    }
+}
+
+/// Returns whether [`math`] supports arrays of type `data_type`.
+pub fn can_math(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type,
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{
+        Float32Array, Float64Array, Int16Array, Int64Array, UInt64Array, Utf8Array,
+    };
+
+    #[test]
+    fn integers() {
+        let a = Int16Array::from(&[Some(-3), None, Some(0), Some(i16::MIN)]);
+        assert_eq!(
+            sign(&a),
+            Int16Array::from(&[Some(-1), None, Some(0), Some(-1)])
+        );
+        assert_eq!(
+            checked_abs(&a),
+            Int16Array::from(&[Some(3), None, Some(0), None])
+        );
+        assert_eq!(
+            clip(&a, -1, 1),
+            Int16Array::from(&[Some(-1), None, Some(0), Some(-1)])
+        );
+        assert!(try_clip(&a, 1, -1).is_err());
+        let a = Float64Array::from_slice(&[1.0]);
+        assert!(try_clip(&a, f64::NAN, 1.0).is_err());
+        assert!(try_clip(&a, 0.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn floats() {
+        let a = Float64Array::from(&[Some(-0.5), None, Some(0.0), Some(f64::NAN)]);
+        let result = sign(&a);
+        assert_eq!(result.value(0), -1.0);
+        assert_eq!(result.value(2), 0.0);
+        assert!(result.value(3).is_nan());
+
+        let result = checked_ln(&a);
+        assert_eq!(result.null_count(), 4);
+        let result = checked_asin(&a);
+        assert_eq!(result.null_count(), 2);
+        assert!((result.value(0) + std::f64::consts::FRAC_PI_6).abs() < 1e-12);
+
+        let a = Float32Array::from(&[Some(-1.5), Some(2.5), Some(f32::MAX)]);
+        assert_eq!(
+            round(&a, 0),
+            Float32Array::from(&[Some(-2.0), Some(3.0), Some(f32::MAX)])
+        );
+        assert_eq!(
+            round(&a, 2),
+            Float32Array::from(&[Some(-1.5), Some(2.5), Some(f32::MAX)])
+        );
+        assert_eq!(
+            round(&a, -400),
+            Float32Array::from(&[Some(0.0), Some(0.0), Some(0.0)])
+        );
+        let infinite = Float64Array::from_slice(&[f64::INFINITY]);
+        assert_eq!(round(&infinite, -400), infinite);
+        assert_eq!(
+            trunc(&a),
+            Float32Array::from(&[Some(-1.0), Some(2.0), Some(f32::MAX)])
+        );
+        assert_eq!(
+            floor(&a),
+            Float32Array::from(&[Some(-2.0), Some(2.0), Some(f32::MAX)])
+        );
+        assert_eq!(
+            ceil(&a),
+            Float32Array::from(&[Some(-1.0), Some(3.0), Some(f32::MAX)])
+        );
+    }
+
+    #[test]
+    fn logarithms() {
+        let a = Float64Array::from(&[Some(8.0), Some(-1.0), Some(1.0)]);
+        assert_eq!(log2(&a).value(0), 3.0);
+        assert!(log2(&a).value(1).is_nan());
+        assert_eq!(
+            checked_log(&a, 2.0),
+            Float64Array::from(&[Some(3.0), None, Some(0.0)])
+        );
+        assert_eq!(checked_log10(&a).null_count(), 1);
+        assert!((exp(&ln(&a)).value(0) - 8.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dyn_math() {
+        let a = UInt64Array::from(&[Some(8), None, Some(1)]);
+        let result = math(&a, MathFunction::Log(2.0)).unwrap();
+        assert_eq!(
+            Float64Array::from(&[Some(3.0), None, Some(0.0)]),
+            result.as_ref()
+        );
+
+        let a = Float32Array::from_slice(&[1.26]);
+        let result = math(&a, MathFunction::Round(1)).unwrap();
+        assert_eq!(Float32Array::from_slice(&[1.3]), result.as_ref());
+
+        let a = Int64Array::from(&[Some(i64::MAX), None, Some(i64::MIN), Some(-15)]);
+        let result = math(&a, MathFunction::Floor).unwrap();
+        assert_eq!(a, result.as_ref());
+        let result = math(&a, MathFunction::Abs).unwrap();
+        let expected = Int64Array::from(&[Some(i64::MAX), None, None, Some(15)]);
+        assert_eq!(expected, result.as_ref());
+        let result = math(&a, MathFunction::Sign).unwrap();
+        let expected = Int64Array::from(&[Some(1), None, Some(-1), Some(-1)]);
+        assert_eq!(expected, result.as_ref());
+        let result = math(&a, MathFunction::Round(-1)).unwrap();
+        let expected = Int64Array::from(&[None, None, None, Some(-20)]);
+        assert_eq!(expected, result.as_ref());
+        let result = math(&a, MathFunction::Round(-40)).unwrap();
+        let expected = Int64Array::from(&[Some(0), None, Some(0), Some(0)]);
+        assert_eq!(expected, result.as_ref());
+
+        let a = UInt64Array::from_slice(&[u64::MAX, 14]);
+        let result = math(&a, MathFunction::Round(-1)).unwrap();
+        assert_eq!(UInt64Array::from(&[None, Some(10)]), result.as_ref());
+        let result = math(&a, MathFunction::Abs).unwrap();
+        assert_eq!(a, result.as_ref());
+
+        let a = Float64Array::from_slice(&[-1.5]);
+        let result = math(&a, MathFunction::Abs).unwrap();
+        assert_eq!(Float64Array::from_slice(&[1.5]), result.as_ref());
+
+        let a = Utf8Array::<i32>::from_slice(&["a"]);
+        assert!(!can_math(a.data_type()));
+        assert!(math(&a, MathFunction::Floor).is_err());
+    }
+}
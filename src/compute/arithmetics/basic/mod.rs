@@ -1,6 +1,8 @@
 //! Defines the arithmetic kernels for `PrimitiveArrays`.
 pub mod add;
+pub mod bitwise;
 pub mod div;
+pub mod math;
 pub mod mul;
 pub mod pow;
 pub mod rem;
@@ -6,6 +6,7 @@
 pub mod add;
 pub mod div;
 pub mod mul;
+pub mod round;
 pub mod sub;
 
 /// Maximum value that can exist with a selected precision
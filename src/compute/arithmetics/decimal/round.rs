@@ -0,0 +1,141 @@
+//! Defines the rounding of decimal primitive arrays.
+use crate::{
+    array::{Array, PrimitiveArray},
+    compute::arity::{unary, unary_checked},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::max_value;
+
+/// Returns the precision, scale and the factor whose multiples are the values of
+/// `data_type` rounded to `digits` digits, or `None` if rounding does not change the values.
+fn get_parameters(data_type: &DataType, digits: i32) -> Result<(usize, Option<u32>)> {
+    if let DataType::Decimal(precision, scale) = data_type {
+        let exponent = *scale as i64 - digits as i64;
+        let exponent = (exponent > 0).then(|| exponent.min(u32::MAX as i64) as u32);
+        Ok((*precision, exponent))
+    } else {
+        Err(ArrowError::InvalidArgumentError(
+            "Incorrect data type for the array".to_string(),
+        ))
+    }
+}
+
+/// Rounds `value` half away from zero to a multiple of `10^exponent`, or returns `None` if the
+/// result overflows.
+#[inline]
+fn round_value(value: i128, exponent: u32) -> Option<i128> {
+    let factor = match 10i128.checked_pow(exponent) {
+        Some(factor) => factor,
+        // the factor is larger than any value
+        None => return Some(0),
+    };
+    let quotient = value / factor;
+    let remainder = (value % factor).abs();
+    let quotient = if remainder >= factor - remainder {
+        quotient + value.signum()
+    } else {
+        quotient
+    };
+    quotient.checked_mul(factor)
+}
+
+/// Rounds the values of a decimal array half away from zero to `digits` digits after the
+/// decimal point, keeping its precision and scale. Negative `digits` round to the left of
+/// the decimal point. Panics if a rounded value does not fit the precision of the array.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::round::round;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from(&vec![Some(12345i128), None, Some(-12355i128)]).to(DataType::Decimal(5, 2));
+///
+/// let result = round(&a, 1).unwrap();
+/// let expected = PrimitiveArray::from(&vec![Some(12350i128), None, Some(-12360i128)]).to(DataType::Decimal(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// This function errors iff the array is not a decimal array.
+pub fn round(array: &PrimitiveArray<i128>, digits: i32) -> Result<PrimitiveArray<i128>> {
+    let (precision, exponent) = get_parameters(array.data_type(), digits)?;
+    let exponent = match exponent {
+        Some(exponent) => exponent,
+        None => return Ok(array.clone()),
+    };
+    let max = max_value(precision);
+
+    let op = move |a| match round_value(a, exponent) {
+        Some(res) if res.abs() <= max => res,
+        _ => panic!("Overflow in round presented for precision {}", precision),
+    };
+
+    Ok(unary(array, op, array.data_type().clone()))
+}
+
+/// Checked rounding of the values of a decimal array half away from zero to `digits` digits
+/// after the decimal point, keeping its precision and scale. Negative `digits` round to the
+/// left of the decimal point. If a rounded value does not fit the precision of the array,
+/// the validity for that index is changed to None.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::round::checked_round;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from(&vec![Some(99999i128), None, Some(-12355i128)]).to(DataType::Decimal(5, 2));
+///
+/// let result = checked_round(&a, 0).unwrap();
+/// let expected = PrimitiveArray::from(&vec![None, None, Some(-12400i128)]).to(DataType::Decimal(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// This function errors iff the array is not a decimal array.
+pub fn checked_round(array: &PrimitiveArray<i128>, digits: i32) -> Result<PrimitiveArray<i128>> {
+    let (precision, exponent) = get_parameters(array.data_type(), digits)?;
+    let exponent = match exponent {
+        Some(exponent) => exponent,
+        None => return Ok(array.clone()),
+    };
+    let max = max_value(precision);
+
+    let op = move |a| round_value(a, exponent).filter(|res: &i128| res.abs() <= max);
+
+    Ok(unary_checked(array, op, array.data_type().clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round() {
+        let a = PrimitiveArray::from(&vec![Some(12345i128), Some(-15000i128), None])
+            .to(DataType::Decimal(6, 2));
+
+        let result = round(&a, 3).unwrap();
+        assert_eq!(result, a);
+
+        let result = round(&a, -2).unwrap();
+        let expected = PrimitiveArray::from(&vec![Some(10000i128), Some(-20000i128), None])
+            .to(DataType::Decimal(6, 2));
+        assert_eq!(result, expected);
+
+        let result = round(&a, -4).unwrap();
+        let expected =
+            PrimitiveArray::from(&vec![Some(0i128), Some(0i128), None]).to(DataType::Decimal(6, 2));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_panic() {
+        let a = PrimitiveArray::from(&vec![Some(99999i128)]).to(DataType::Decimal(5, 2));
+        let _ = round(&a, 0);
+    }
+}
@@ -59,16 +59,21 @@ pub mod basic;
 pub mod decimal;
 pub mod time;
 
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::any::{Any, TypeId};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Sub};
 
-use num::Zero;
+use num::{
+    cast::AsPrimitive,
+    traits::{WrappingShl, WrappingShr},
+    Zero,
+};
 
 use crate::datatypes::{DataType, TimeUnit};
 use crate::error::{ArrowError, Result};
 use crate::types::NativeType;
 use crate::{array::*, bitmap::Bitmap};
 
-use super::arity::unary;
+use super::{arity::unary, cast};
 
 // Macro to evaluate match branch in arithmetic function.
 // The macro is used to downcast both arrays to a primitive_array_type. If there
@@ -87,11 +92,39 @@ macro_rules! primitive {
 
 /// Execute an arithmetic operation with two arrays. It uses the enum Operator
 /// to select the type of operation that is going to be performed with the two
-/// arrays
+/// arrays.
+///
+/// Numeric arrays of different types are first cast to a common type, following the
+/// rules of [`arithmetic_result_type`].
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::{arithmetic, Operator};
+/// use arrow2::array::{Array, Float64Array, Int32Array};
+///
+/// let a = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let b = Float64Array::from(&[Some(0.5), Some(1.0), Some(1.5)]);
+/// let result = arithmetic(&a, Operator::Add, &b).unwrap();
+/// let expected = Float64Array::from(&[Some(1.5), None, Some(4.5)]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
 pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<dyn Array>> {
     use DataType::*;
     use Operator::*;
+    if let Some(data_type) = coerced_type(lhs.data_type(), op, rhs.data_type())? {
+        // the common type holds both sides, so a failing cast is an error rather than a null
+        let options = cast::CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let lhs = cast::cast_with_options(lhs, &data_type, options)?;
+        let rhs = cast::cast_with_options(rhs, &data_type, options)?;
+        return arithmetic(lhs.as_ref(), op, rhs.as_ref());
+    }
     match (lhs.data_type(), op, rhs.data_type()) {
+        (_, BitwiseAnd | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight, _) => {
+            bitwise(lhs, op, rhs)
+        }
         (Int8, _, Int8) => primitive!(lhs, rhs, op, i8),
         (Int16, _, Int16) => primitive!(lhs, rhs, op, i16),
         (Int32, _, Int32) => primitive!(lhs, rhs, op, i32),
@@ -113,7 +146,7 @@ pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<
                 Subtract => decimal::sub::sub(lhs, rhs),
                 Multiply => decimal::mul::mul(lhs, rhs),
                 Divide => decimal::div::div(lhs, rhs),
-                Remainder => {
+                Remainder | BitwiseAnd | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight => {
                     return Err(ArrowError::NotYetImplemented(format!(
                         "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
                         lhs, op, rhs
@@ -158,6 +191,12 @@ pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<
             let rhs = rhs.as_any().downcast_ref().unwrap();
             time::subtract_timestamps(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
         }
+        (Date32, Add, Interval(_))
+        | (Date64, Add, Interval(_))
+        | (Timestamp(_, _), Add, Interval(_)) => time::add_interval(lhs, rhs),
+        (Date32, Subtract, Interval(_))
+        | (Date64, Subtract, Interval(_))
+        | (Timestamp(_, _), Subtract, Interval(_)) => time::sub_interval(lhs, rhs),
         (lhs, op, rhs) => Err(ArrowError::NotYetImplemented(format!(
             "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
             lhs, op, rhs
@@ -180,42 +219,188 @@ pub fn arithmetic(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<
 /// assert_eq!(can_arithmetic(&data_type, Operator::Add, &data_type), false)
 /// ```
 pub fn can_arithmetic(lhs: &DataType, op: Operator, rhs: &DataType) -> bool {
+    arithmetic_result_type(lhs, op, rhs).is_ok()
+}
+
+/// Returns the [`DataType`] of the result of [`arithmetic`] with arrays of types `lhs` and `rhs`,
+/// without executing it.
+///
+/// Numeric types that differ are first coerced to a common type:
+/// * integers of the same signedness are widened to the largest of the two;
+/// * a signed and an unsigned integer are widened to a signed integer that holds both; there is
+///   none when one of them is `UInt64`, which is an error;
+/// * `Float32` with an integer of up to 16 bits is `Float32`; any other float combination is
+///   `Float64`;
+/// * an integer is widened to a `Decimal` with scale 0 and enough precision for the integer type;
+/// * two `Decimal`s are widened to the largest number of digits before and after the point of the
+///   two, which errors when that needs a precision above 38;
+/// * a `Decimal` with a float is `Float64`.
+///
+/// Bitwise operators are not coerced and require both sides to have the same integer type.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::{arithmetic_result_type, Operator};
+/// use arrow2::datatypes::DataType;
+///
+/// let result = arithmetic_result_type(&DataType::Int8, Operator::Add, &DataType::Int64);
+/// assert_eq!(result.unwrap(), DataType::Int64);
+///
+/// let result =
+///     arithmetic_result_type(&DataType::Decimal(5, 2), Operator::Multiply, &DataType::Int16);
+/// assert_eq!(result.unwrap(), DataType::Decimal(7, 2));
+/// ```
+/// # Errors
+/// This function errors iff the operation is not supported for `lhs` and `rhs`.
+pub fn arithmetic_result_type(lhs: &DataType, op: Operator, rhs: &DataType) -> Result<DataType> {
     use DataType::*;
     use Operator::*;
-    if let (Decimal(_, _), Remainder, Decimal(_, _)) = (lhs, op, rhs) {
-        return false;
-    };
+    if let Some(data_type) = coerced_type(lhs, op, rhs)? {
+        return arithmetic_result_type(&data_type, op, &data_type);
+    }
+    if op.is_bitwise() {
+        return if lhs == rhs && is_integer(lhs) {
+            Ok(lhs.clone())
+        } else {
+            Err(ArrowError::NotYetImplemented(format!(
+                "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
+                lhs, op, rhs
+            )))
+        };
+    }
 
-    matches!(
-        (lhs, op, rhs),
+    match (lhs, op, rhs) {
         (Int8, _, Int8)
-            | (Int16, _, Int16)
-            | (Int32, _, Int32)
-            | (Int64, _, Int64)
-            | (UInt8, _, UInt8)
-            | (UInt16, _, UInt16)
-            | (UInt32, _, UInt32)
-            | (UInt64, _, UInt64)
-            | (Float64, _, Float64)
-            | (Float32, _, Float32)
-            | (Duration(_), _, Duration(_))
-            | (Decimal(_, _), _, Decimal(_, _))
-            | (Date32, Subtract, Duration(_))
-            | (Date32, Add, Duration(_))
-            | (Date64, Subtract, Duration(_))
-            | (Date64, Add, Duration(_))
-            | (Time32(TimeUnit::Millisecond), Subtract, Duration(_))
-            | (Time32(TimeUnit::Second), Subtract, Duration(_))
-            | (Time32(TimeUnit::Millisecond), Add, Duration(_))
-            | (Time32(TimeUnit::Second), Add, Duration(_))
-            | (Time64(TimeUnit::Microsecond), Subtract, Duration(_))
-            | (Time64(TimeUnit::Nanosecond), Subtract, Duration(_))
-            | (Time64(TimeUnit::Microsecond), Add, Duration(_))
-            | (Time64(TimeUnit::Nanosecond), Add, Duration(_))
-            | (Timestamp(_, _), Subtract, Duration(_))
-            | (Timestamp(_, _), Add, Duration(_))
-            | (Timestamp(_, None), Subtract, Timestamp(_, None))
-    )
+        | (Int16, _, Int16)
+        | (Int32, _, Int32)
+        | (Int64, _, Int64)
+        | (UInt8, _, UInt8)
+        | (UInt16, _, UInt16)
+        | (UInt32, _, UInt32)
+        | (UInt64, _, UInt64)
+        | (Float64, _, Float64)
+        | (Float32, _, Float32)
+        | (Duration(_), _, Duration(_))
+        | (Date32, Subtract | Add, Duration(_))
+        | (Date64, Subtract | Add, Duration(_))
+        | (Time32(TimeUnit::Millisecond), Subtract | Add, Duration(_))
+        | (Time32(TimeUnit::Second), Subtract | Add, Duration(_))
+        | (Time64(TimeUnit::Microsecond), Subtract | Add, Duration(_))
+        | (Time64(TimeUnit::Nanosecond), Subtract | Add, Duration(_))
+        | (Timestamp(_, _), Subtract | Add, Duration(_))
+        | (Date32, Subtract | Add, Interval(_))
+        | (Date64, Subtract | Add, Interval(_))
+        | (Timestamp(_, _), Subtract | Add, Interval(_)) => Ok(lhs.clone()),
+        (Decimal(_, _), Add | Subtract | Multiply | Divide, Decimal(_, _)) => Ok(lhs.clone()),
+        (Timestamp(unit, None), Subtract, Timestamp(_, None)) => Ok(Duration(unit.clone())),
+        (lhs, op, rhs) => Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
+            lhs, op, rhs
+        ))),
+    }
+}
+
+/// Returns the type both sides of `op` are cast to before executing it, or `None` when the
+/// types are used as they are.
+/// # Errors
+/// This function errors when no type holds all values of both `lhs` and `rhs`.
+fn coerced_type(lhs: &DataType, op: Operator, rhs: &DataType) -> Result<Option<DataType>> {
+    use DataType::*;
+    if lhs == rhs || op.is_bitwise() {
+        return Ok(None);
+    }
+    let data_type = match (lhs, rhs) {
+        (Decimal(lhs_p, lhs_s), Decimal(rhs_p, rhs_s)) => {
+            common_decimal(*lhs_p, *lhs_s, *rhs_p, *rhs_s)
+        }
+        (Decimal(p, s), other) | (other, Decimal(p, s)) if is_integer(other) => {
+            common_decimal(*p, *s, integer_digits(other), 0)
+        }
+        (Decimal(_, _), Float32 | Float64) | (Float32 | Float64, Decimal(_, _)) => Ok(Float64),
+        (Float32, Float64) | (Float64, Float32) => Ok(Float64),
+        (Float32, other) | (other, Float32) if is_integer(other) => {
+            let (_, bits) = integer_width(other);
+            Ok(if bits <= 16 { Float32 } else { Float64 })
+        }
+        (Float64, other) | (other, Float64) if is_integer(other) => Ok(Float64),
+        (lhs, rhs) if is_integer(lhs) && is_integer(rhs) => {
+            let (lhs_signed, lhs_bits) = integer_width(lhs);
+            let (rhs_signed, rhs_bits) = integer_width(rhs);
+            let (signed, bits) = match (lhs_signed, rhs_signed) {
+                (true, true) | (false, false) => (lhs_signed, lhs_bits.max(rhs_bits)),
+                (true, false) if lhs_bits > rhs_bits => (true, lhs_bits),
+                (false, true) if rhs_bits > lhs_bits => (true, rhs_bits),
+                // the signed type must be larger than the unsigned one
+                _ if lhs_bits.max(rhs_bits) < 64 => (true, lhs_bits.max(rhs_bits) * 2),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "There is no integer type that holds all values of {:?} and {:?}",
+                        lhs, rhs
+                    )))
+                }
+            };
+            Ok(integer_type(signed, bits))
+        }
+        _ => return Ok(None),
+    }?;
+    Ok(Some(data_type))
+}
+
+/// Returns the decimal type with the digits before and after the point of both
+/// `Decimal(lhs_p, lhs_s)` and `Decimal(rhs_p, rhs_s)`.
+/// # Errors
+/// This function errors when that type needs a precision above 38.
+fn common_decimal(lhs_p: usize, lhs_s: usize, rhs_p: usize, rhs_s: usize) -> Result<DataType> {
+    let before = (lhs_p - lhs_s).max(rhs_p - rhs_s);
+    let scale = lhs_s.max(rhs_s);
+    if before + scale > 38 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "There is no decimal type that holds all values of Decimal({}, {}) and Decimal({}, {})",
+            lhs_p, lhs_s, rhs_p, rhs_s
+        )));
+    }
+    Ok(DataType::Decimal(before + scale, scale))
+}
+
+/// Returns whether the integer `data_type` is signed and its number of bits.
+fn integer_width(data_type: &DataType) -> (bool, usize) {
+    use DataType::*;
+    match data_type {
+        Int8 => (true, 8),
+        Int16 => (true, 16),
+        Int32 => (true, 32),
+        Int64 => (true, 64),
+        UInt8 => (false, 8),
+        UInt16 => (false, 16),
+        UInt32 => (false, 32),
+        UInt64 => (false, 64),
+        _ => unreachable!(),
+    }
+}
+
+fn integer_type(signed: bool, bits: usize) -> DataType {
+    use DataType::*;
+    match (signed, bits) {
+        (true, 8) => Int8,
+        (true, 16) => Int16,
+        (true, 32) => Int32,
+        (true, _) => Int64,
+        (false, 8) => UInt8,
+        (false, 16) => UInt16,
+        (false, 32) => UInt32,
+        (false, _) => UInt64,
+    }
+}
+
+/// Returns the number of decimal digits of the largest value of the integer `data_type`.
+fn integer_digits(data_type: &DataType) -> usize {
+    match integer_width(data_type) {
+        (_, 8) => 3,
+        (_, 16) => 5,
+        (_, 32) => 10,
+        (true, _) => 19,
+        (false, _) => 20,
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -225,6 +410,145 @@ pub enum Operator {
     Multiply,
     Divide,
     Remainder,
+    /// Bitwise `AND` of integers
+    BitwiseAnd,
+    /// Bitwise `OR` of integers
+    BitwiseOr,
+    /// Bitwise `XOR` of integers
+    BitwiseXor,
+    /// Shift of the bits of integers to the left, modulo their number of bits
+    ShiftLeft,
+    /// Shift of the bits of integers to the right, modulo their number of bits
+    ShiftRight,
+}
+
+impl Operator {
+    /// Whether this operator is only defined for integers.
+    fn is_bitwise(&self) -> bool {
+        use Operator::*;
+        matches!(
+            self,
+            BitwiseAnd | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight
+        )
+    }
+}
+
+#[inline]
+fn is_integer(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type,
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+    )
+}
+
+// Macro to evaluate the bitwise operation `$op` on two arrays downcasted to
+// `PrimitiveArray<$type>`.
+macro_rules! integer {
+    ($lhs: expr, $rhs: expr, $op: expr, $type: ty) => {{
+        let lhs = $lhs.as_any().downcast_ref().unwrap();
+        let rhs = $rhs.as_any().downcast_ref().unwrap();
+        bitwise_primitive::<$type>(lhs, $op, rhs).map(|x| Box::new(x) as Box<dyn Array>)
+    }};
+}
+
+/// Executes a bitwise operation with two arrays of the same integer type.
+fn bitwise(lhs: &dyn Array, op: Operator, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    match (lhs.data_type(), rhs.data_type()) {
+        (Int8, Int8) => integer!(lhs, rhs, op, i8),
+        (Int16, Int16) => integer!(lhs, rhs, op, i16),
+        (Int32, Int32) => integer!(lhs, rhs, op, i32),
+        (Int64, Int64) => integer!(lhs, rhs, op, i64),
+        (UInt8, UInt8) => integer!(lhs, rhs, op, u8),
+        (UInt16, UInt16) => integer!(lhs, rhs, op, u16),
+        (UInt32, UInt32) => integer!(lhs, rhs, op, u32),
+        (UInt64, UInt64) => integer!(lhs, rhs, op, u64),
+        (lhs, rhs) => Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics of ({:?}, {:?}, {:?}) is not supported",
+            lhs, op, rhs
+        ))),
+    }
+}
+
+/// Perform bitwise operations on two integer arrays based on the Operator enum
+fn bitwise_primitive<T>(
+    lhs: &PrimitiveArray<T>,
+    op: Operator,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType
+        + BitAnd<Output = T>
+        + BitOr<Output = T>
+        + BitXor<Output = T>
+        + WrappingShl
+        + WrappingShr
+        + AsPrimitive<u32>,
+{
+    match op {
+        Operator::BitwiseAnd => basic::bitwise::bitwise_and(lhs, rhs),
+        Operator::BitwiseOr => basic::bitwise::bitwise_or(lhs, rhs),
+        Operator::BitwiseXor => basic::bitwise::bitwise_xor(lhs, rhs),
+        Operator::ShiftLeft => basic::bitwise::shift_left(lhs, rhs),
+        Operator::ShiftRight => basic::bitwise::shift_right(lhs, rhs),
+        _ => unreachable!(),
+    }
+}
+
+// Macro to evaluate the bitwise operation `$op` on an array and a scalar of the
+// generic type `$T` when `$T` is the integer `$type`.
+macro_rules! integer_scalar {
+    ($lhs: expr, $rhs: expr, $op: expr, $T: ty, $type: ty) => {{
+        if TypeId::of::<$T>() == TypeId::of::<$type>() {
+            let lhs = $lhs.as_any().downcast_ref().unwrap();
+            let rhs = ($rhs as &dyn Any).downcast_ref().unwrap();
+            let result: Box<dyn Any> = Box::new(bitwise_primitive_scalar::<$type>(lhs, $op, rhs));
+            return Ok(*result.downcast::<PrimitiveArray<$T>>().unwrap());
+        }
+    }};
+}
+
+/// Executes a bitwise operation with an array and a scalar of the same integer type.
+fn bitwise_scalar<T: NativeType>(
+    lhs: &PrimitiveArray<T>,
+    op: Operator,
+    rhs: &T,
+) -> Result<PrimitiveArray<T>> {
+    integer_scalar!(lhs, rhs, op, T, i8);
+    integer_scalar!(lhs, rhs, op, T, i16);
+    integer_scalar!(lhs, rhs, op, T, i32);
+    integer_scalar!(lhs, rhs, op, T, i64);
+    integer_scalar!(lhs, rhs, op, T, u8);
+    integer_scalar!(lhs, rhs, op, T, u16);
+    integer_scalar!(lhs, rhs, op, T, u32);
+    integer_scalar!(lhs, rhs, op, T, u64);
+    Err(ArrowError::NotYetImplemented(format!(
+        "Arithmetics of {:?} is not supported for {:?}",
+        op,
+        lhs.data_type()
+    )))
+}
+
+/// Perform bitwise operations on an integer array and a scalar based on the Operator enum
+fn bitwise_primitive_scalar<T>(lhs: &PrimitiveArray<T>, op: Operator, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType
+        + BitAnd<Output = T>
+        + BitOr<Output = T>
+        + BitXor<Output = T>
+        + WrappingShl
+        + WrappingShr
+        + AsPrimitive<u32>,
+{
+    match op {
+        Operator::BitwiseAnd => basic::bitwise::bitwise_and_scalar(lhs, rhs),
+        Operator::BitwiseOr => basic::bitwise::bitwise_or_scalar(lhs, rhs),
+        Operator::BitwiseXor => basic::bitwise::bitwise_xor_scalar(lhs, rhs),
+        Operator::ShiftLeft => basic::bitwise::shift_left_scalar(lhs, rhs.as_()),
+        Operator::ShiftRight => basic::bitwise::shift_right_scalar(lhs, rhs.as_()),
+        _ => unreachable!(),
+    }
 }
 
 /// Perform arithmetic operations on two primitive arrays based on the Operator enum
@@ -248,6 +572,11 @@ where
         Operator::Multiply => basic::mul::mul(lhs, rhs),
         Operator::Divide => basic::div::div(lhs, rhs),
         Operator::Remainder => basic::rem::rem(lhs, rhs),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics of {:?} is not supported for {:?}",
+            op,
+            lhs.data_type()
+        ))),
     }
 }
 
@@ -272,6 +601,11 @@ where
         Operator::Multiply => Ok(basic::mul::mul_scalar(lhs, rhs)),
         Operator::Divide => Ok(basic::div::div_scalar(lhs, rhs)),
         Operator::Remainder => Ok(basic::rem::rem_scalar(lhs, rhs)),
+        Operator::BitwiseAnd
+        | Operator::BitwiseOr
+        | Operator::BitwiseXor
+        | Operator::ShiftLeft
+        | Operator::ShiftRight => bitwise_scalar(lhs, op, rhs),
     }
 }
 
@@ -486,4 +820,85 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn bitwise_dispatch() {
+        use crate::array::Int32Array;
+        use crate::datatypes::DataType::*;
+
+        assert!(can_arithmetic(&Int32, Operator::ShiftLeft, &Int32));
+        assert!(!can_arithmetic(&Int32, Operator::BitwiseAnd, &Int64));
+        assert!(!can_arithmetic(&Float64, Operator::BitwiseOr, &Float64));
+
+        let a = Int32Array::from(&[Some(0b0110), None, Some(1)]);
+        let b = Int32Array::from(&[Some(0b0011), Some(1), Some(4)]);
+        let result = arithmetic(&a, Operator::BitwiseAnd, &b).unwrap();
+        let expected = Int32Array::from(&[Some(0b0010), None, Some(0)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        let result = arithmetic(&a, Operator::ShiftLeft, &b).unwrap();
+        let expected = Int32Array::from(&[Some(48), None, Some(16)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let a = new_empty_array(Float64);
+        assert!(arithmetic(a.as_ref(), Operator::BitwiseOr, a.as_ref()).is_err());
+
+        let a = Int32Array::from(&[Some(0b0110), None, Some(-8)]);
+        let result = arithmetic_primitive_scalar(&a, Operator::BitwiseXor, &0b0011).unwrap();
+        assert_eq!(result, Int32Array::from(&[Some(0b0101), None, Some(-5)]));
+        let result = arithmetic_primitive_scalar(&a, Operator::ShiftRight, &1).unwrap();
+        assert_eq!(result, Int32Array::from(&[Some(0b0011), None, Some(-4)]));
+
+        let a = PrimitiveArray::<f64>::from(&[Some(1.0)]);
+        assert!(arithmetic_primitive_scalar(&a, Operator::BitwiseAnd, &1.0).is_err());
+    }
+
+    #[test]
+    fn coercion() {
+        use crate::array::{Int16Array, Int64Array, Int8Array, PrimitiveArray, UInt8Array};
+        use crate::datatypes::DataType::*;
+
+        let cases = vec![
+            (Int8, Int64, Int64),
+            (UInt8, Int8, Int16),
+            (Int32, UInt16, Int32),
+            (UInt32, Int32, Int64),
+            (UInt16, UInt64, UInt64),
+            (Float32, Int16, Float32),
+            (Float32, UInt32, Float64),
+            (Int64, Float64, Float64),
+            (Decimal(5, 2), Int32, Decimal(12, 2)),
+            (UInt64, Decimal(3, 1), Decimal(21, 1)),
+            (Decimal(5, 2), Decimal(4, 3), Decimal(6, 3)),
+            (Decimal(36, 0), Decimal(2, 2), Decimal(38, 2)),
+            (Decimal(5, 2), Float32, Float64),
+        ];
+        for (lhs, rhs, expected) in cases {
+            assert_eq!(
+                arithmetic_result_type(&lhs, Operator::Add, &rhs).unwrap(),
+                expected
+            );
+            assert_eq!(
+                arithmetic_result_type(&rhs, Operator::Add, &lhs).unwrap(),
+                expected
+            );
+        }
+        assert!(arithmetic_result_type(&Decimal(5, 2), Operator::Remainder, &Int8).is_err());
+        assert!(arithmetic_result_type(&Int8, Operator::BitwiseAnd, &Int16).is_err());
+        assert!(arithmetic_result_type(&Utf8, Operator::Add, &Int16).is_err());
+        assert!(arithmetic_result_type(&UInt64, Operator::Add, &Int8).is_err());
+        assert!(arithmetic_result_type(&Decimal(36, 0), Operator::Add, &Decimal(36, 36)).is_err());
+        assert!(arithmetic_result_type(&UInt64, Operator::Add, &Decimal(38, 20)).is_err());
+
+        let a = UInt8Array::from(&[Some(200), None, Some(1)]);
+        let b = Int8Array::from(&[Some(100), Some(1), Some(-2)]);
+        let result = arithmetic(&a, Operator::Add, &b).unwrap();
+        let expected = Int16Array::from(&[Some(300), None, Some(-1)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let a = PrimitiveArray::<i128>::from(&[Some(150), None]).to(Decimal(5, 2));
+        let b = Int64Array::from(&[Some(2), Some(1)]);
+        let result = arithmetic(&a, Operator::Multiply, &b).unwrap();
+        let expected = PrimitiveArray::<i128>::from(&[Some(300), None]).to(Decimal(21, 2));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
 }
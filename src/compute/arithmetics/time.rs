@@ -1,5 +1,6 @@
 //! Defines the arithmetic kernels for adding a Duration to a Timestamp,
-//! Time32, Time64, Date32 and Date64.
+//! Time32, Time64, Date32 and Date64, and for adding an Interval to a
+//! Timestamp, Date32 and Date64.
 //!
 //! For the purposes of Arrow Implementations, adding this value to a Timestamp
 //! ("t1") naively (i.e. simply summing the two number) is acceptable even
@@ -8,18 +9,24 @@
 //! representing the difference between two Unix timestamp is acceptable, but
 //! would yield a value that is possibly a few seconds off from the true
 //! elapsed time.
+//!
+//! Intervals are calendar-aware: their months and days are added to the
+//! local date of the time array, and their remaining time is added as an
+//! exact duration.
 
+use std::convert::TryFrom;
 use std::ops::{Add, Sub};
 
+use chrono::{Datelike, Duration, Months, NaiveDateTime, TimeZone};
 use num::cast::AsPrimitive;
 
 use crate::{
     array::{Array, PrimitiveArray},
-    compute::arity::binary,
-    datatypes::{DataType, TimeUnit},
+    compute::arity::{binary, unary},
+    datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
-    temporal_conversions::{timeunit_scale, SECONDS_IN_DAY},
-    types::NativeType,
+    temporal_conversions::*,
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 /// Creates the scale required to add or subtract a Duration to a time array
@@ -227,6 +234,270 @@ pub fn subtract_timestamps(
     }
 }
 
+#[inline]
+fn as_primitive<T: NativeType>(array: &dyn Array) -> &PrimitiveArray<T> {
+    array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap()
+}
+
+/// Returns `interval` as an array of [`months_days_ns`], which can represent
+/// every [`IntervalUnit`].
+fn to_months_days_ns(interval: &dyn Array) -> Result<PrimitiveArray<months_days_ns>> {
+    let data_type = DataType::Interval(IntervalUnit::MonthDayNano);
+    match interval.data_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => Ok(unary(
+            as_primitive::<i32>(interval),
+            |x| months_days_ns::new(x, 0, 0),
+            data_type,
+        )),
+        DataType::Interval(IntervalUnit::DayTime) => Ok(unary(
+            as_primitive::<days_ms>(interval),
+            |x| months_days_ns::new(0, x.days(), x.milliseconds() as i64 * 1_000_000),
+            data_type,
+        )),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            Ok(as_primitive::<months_days_ns>(interval).clone())
+        }
+        dt => Err(ArrowError::InvalidArgumentError(format!(
+            "Expected an Interval array, got {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Adds `months` to `datetime`, clamping its day to the last day of the
+/// resulting month (e.g. `2021-01-31 + 1 month = 2021-02-28`).
+#[inline]
+fn add_months(datetime: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let abs_months = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months >= 0 {
+        datetime.checked_add_months(abs_months)
+    } else {
+        datetime.checked_sub_months(abs_months)
+    }
+}
+
+/// Adds the months and days of `interval`, times `sign`, to `datetime`.
+#[inline]
+fn add_calendar(
+    datetime: NaiveDateTime,
+    interval: months_days_ns,
+    sign: i64,
+) -> Option<NaiveDateTime> {
+    let datetime = add_months(datetime, interval.months() as i64 * sign)?;
+    datetime.checked_add_signed(Duration::days(interval.days() as i64 * sign))
+}
+
+/// Adds the nanoseconds of `interval`, times `sign`, to `datetime`.
+#[inline]
+fn add_exact(
+    datetime: NaiveDateTime,
+    interval: months_days_ns,
+    sign: i64,
+) -> Option<NaiveDateTime> {
+    datetime.checked_add_signed(Duration::nanoseconds(interval.ns().checked_mul(sign)?))
+}
+
+fn apply_interval(time: &dyn Array, interval: &dyn Array, sign: i64) -> Result<Box<dyn Array>> {
+    if time.len() != interval.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    let interval = to_months_days_ns(interval)?;
+    let shift =
+        |datetime, interval| add_exact(add_calendar(datetime, interval, sign)?, interval, sign);
+
+    let data_type = time.data_type().clone();
+    match time.data_type() {
+        DataType::Date32 => {
+            let iter =
+                as_primitive::<i32>(time)
+                    .iter()
+                    .zip(interval.iter())
+                    .map(|(x, interval)| {
+                        let datetime = shift(date32_to_datetime(*x?), *interval?)?;
+                        Some(datetime.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+                    });
+            Ok(Box::new(
+                PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+            ))
+        }
+        DataType::Date64 => {
+            let iter =
+                as_primitive::<i64>(time)
+                    .iter()
+                    .zip(interval.iter())
+                    .map(|(x, interval)| {
+                        let datetime = shift(date64_to_datetime(*x?), *interval?)?;
+                        naive_datetime_to_timestamp(&datetime, &TimeUnit::Millisecond)
+                    });
+            Ok(Box::new(
+                PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+            ))
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let iter =
+                as_primitive::<i64>(time)
+                    .iter()
+                    .zip(interval.iter())
+                    .map(|(x, interval)| {
+                        let datetime =
+                            shift(timestamp_to_naive_datetime(*x?, time_unit), *interval?)?;
+                        naive_datetime_to_timestamp(&datetime, time_unit)
+                    });
+            Ok(Box::new(
+                PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+            ))
+        }
+        DataType::Timestamp(time_unit, Some(timezone)) => {
+            let time = as_primitive::<i64>(time);
+            with_timezone!(timezone, |tz| {
+                let iter = time.iter().zip(interval.iter()).map(|(x, interval)| {
+                    let interval = *interval?;
+                    let local = timestamp_to_datetime(*x?, time_unit, &tz).naive_local();
+                    let local = add_calendar(local, interval, sign)?;
+                    let datetime = tz.from_local_datetime(&local).earliest()?.naive_utc();
+                    let datetime = add_exact(datetime, interval, sign)?;
+                    naive_datetime_to_timestamp(&datetime, time_unit)
+                });
+                Ok(Box::new(
+                    PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+                ))
+            })
+        }
+        dt => Err(ArrowError::InvalidArgumentError(format!(
+            "Intervals can only be added to Date32, Date64 and Timestamp arrays, got {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Adds an interval (`Interval(YearMonth)`, `Interval(DayTime)` or
+/// `Interval(MonthDayNano)`) to a `Date32`, `Date64` or `Timestamp` array.
+///
+/// The months of the interval are added first, clamping the day to the last
+/// day of the resulting month, followed by its days and by its remaining time.
+/// Timestamps with a timezone are shifted by months and days in their local
+/// time, so that e.g. adding one day keeps the wall-clock time across daylight
+/// saving time transitions. `Date32` results are truncated to the day.
+///
+/// Slots where either value is null, the result overflows, or the local
+/// result does not exist in the timezone are null.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::add_interval;
+/// use arrow2::array::{Array, PrimitiveArray};
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+///
+/// // 2021-01-31 and 2020-01-31
+/// let dates = PrimitiveArray::from(&[Some(18658), Some(18292), None]).to(DataType::Date32);
+/// let months = PrimitiveArray::from(&[Some(1), Some(1), Some(1)])
+///     .to(DataType::Interval(IntervalUnit::YearMonth));
+///
+/// let result = add_interval(&dates, &months).unwrap();
+/// // 2021-02-28 and 2020-02-29
+/// let expected = PrimitiveArray::from(&[Some(18686), Some(18321), None]).to(DataType::Date32);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors when the arrays have different lengths, the types are
+/// not supported or the timezone is invalid.
+pub fn add_interval(time: &dyn Array, interval: &dyn Array) -> Result<Box<dyn Array>> {
+    apply_interval(time, interval, 1)
+}
+
+/// Subtracts an interval (`Interval(YearMonth)`, `Interval(DayTime)` or
+/// `Interval(MonthDayNano)`) from a `Date32`, `Date64` or `Timestamp` array.
+///
+/// This is equivalent to adding the negated interval with [`add_interval`].
+/// # Errors
+/// This function errors when the arrays have different lengths, the types are
+/// not supported or the timezone is invalid.
+pub fn sub_interval(time: &dyn Array, interval: &dyn Array) -> Result<Box<dyn Array>> {
+    apply_interval(time, interval, -1)
+}
+
+/// Returns the local datetimes of a `Date32`, `Date64` or `Timestamp` array.
+fn to_local_datetimes(array: &dyn Array) -> Result<Vec<Option<NaiveDateTime>>> {
+    Ok(match array.data_type() {
+        DataType::Date32 => as_primitive::<i32>(array)
+            .iter()
+            .map(|x| x.map(|x| date32_to_datetime(*x)))
+            .collect(),
+        DataType::Date64 => as_primitive::<i64>(array)
+            .iter()
+            .map(|x| x.map(|x| date64_to_datetime(*x)))
+            .collect(),
+        DataType::Timestamp(time_unit, None) => as_primitive::<i64>(array)
+            .iter()
+            .map(|x| x.map(|x| timestamp_to_naive_datetime(*x, time_unit)))
+            .collect(),
+        DataType::Timestamp(time_unit, Some(timezone)) => {
+            let array = as_primitive::<i64>(array);
+            with_timezone!(timezone, |tz| array
+                .iter()
+                .map(|x| x.map(|x| timestamp_to_datetime(*x, time_unit, &tz).naive_local()))
+                .collect())
+        }
+        dt => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "\"months_between\" does not support type {:?}",
+                dt
+            )))
+        }
+    })
+}
+
+/// The number of whole months from `start` to `end`, consistent with
+/// [`add_months`]: `start` plus the result is never past `end`.
+#[inline]
+fn whole_months(start: NaiveDateTime, end: NaiveDateTime) -> i32 {
+    let months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    match add_months(start, months as i64) {
+        Some(shifted) if months > 0 && shifted > end => months - 1,
+        Some(shifted) if months < 0 && shifted < end => months + 1,
+        _ => months,
+    }
+}
+
+/// Returns the number of whole months between the values of `lhs` and `rhs`
+/// (`Date32`, `Date64` or `Timestamp` arrays), positive when `lhs` is after
+/// `rhs`. Timestamps with a timezone are compared in their local time.
+///
+/// A month is complete when adding it to `rhs` with [`add_interval`] does not
+/// overshoot `lhs`; e.g. there is one month between `2021-01-31` and `2021-02-28`.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::months_between;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::DataType;
+///
+/// // 2021-03-15 and 2021-03-14
+/// let lhs = PrimitiveArray::from(&[Some(18701), Some(18700), None]).to(DataType::Date32);
+/// // 2021-01-15
+/// let rhs = PrimitiveArray::from(&[Some(18642), Some(18642), Some(18642)]).to(DataType::Date32);
+///
+/// let result = months_between(&lhs, &rhs).unwrap();
+/// assert_eq!(result, PrimitiveArray::from(&[Some(2), Some(1), None]));
+/// ```
+/// # Errors
+/// This function errors when the arrays have different lengths, the types are
+/// not supported or a timezone is invalid.
+pub fn months_between(lhs: &dyn Array, rhs: &dyn Array) -> Result<PrimitiveArray<i32>> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    let lhs = to_local_datetimes(lhs)?;
+    let rhs = to_local_datetimes(rhs)?;
+    Ok(lhs
+        .into_iter()
+        .zip(rhs)
+        .map(|(end, start)| Some(whole_months(start?, end?)))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -663,4 +934,163 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn date32(year: i32, month: u32, day: u32) -> i32 {
+        date(year, month, day).num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    #[test]
+    fn test_add_year_month_interval() {
+        let dates = PrimitiveArray::from(&[
+            Some(date32(2021, 1, 31)),
+            Some(date32(2020, 1, 31)),
+            Some(date32(2020, 3, 31)),
+            None,
+            Some(date32(2020, 12, 15)),
+        ])
+        .to(DataType::Date32);
+        let months = PrimitiveArray::from(&[Some(1), Some(1), Some(-1), Some(1), None])
+            .to(DataType::Interval(IntervalUnit::YearMonth));
+
+        let result = add_interval(&dates, &months).unwrap();
+        let expected = PrimitiveArray::from(&[
+            Some(date32(2021, 2, 28)),
+            Some(date32(2020, 2, 29)),
+            Some(date32(2020, 2, 29)),
+            None,
+            None,
+        ])
+        .to(DataType::Date32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = sub_interval(&dates, &months).unwrap();
+        let expected = PrimitiveArray::from(&[
+            Some(date32(2020, 12, 31)),
+            Some(date32(2019, 12, 31)),
+            Some(date32(2020, 4, 30)),
+            None,
+            None,
+        ])
+        .to(DataType::Date32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_add_day_time_interval() {
+        let ms = |datetime: NaiveDateTime| datetime.and_utc().timestamp_millis();
+        let dates = PrimitiveArray::from(&[Some(ms(date(2021, 2, 28)))]).to(DataType::Date64);
+        let interval = PrimitiveArray::from(&[Some(days_ms::new(1, 3_600_000))])
+            .to(DataType::Interval(IntervalUnit::DayTime));
+
+        let result = add_interval(&dates, &interval).unwrap();
+        let expected = date(2021, 3, 1) + Duration::hours(1);
+        let expected = PrimitiveArray::from(&[Some(ms(expected))]).to(DataType::Date64);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let timestamps = PrimitiveArray::from(&[Some(ms(date(2021, 3, 1)))])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+        let result = sub_interval(&timestamps, &interval).unwrap();
+        let expected = date(2021, 2, 27) + Duration::hours(23);
+        let expected = PrimitiveArray::from(&[Some(ms(expected))])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_add_month_day_nano_interval() {
+        let seconds = |datetime: NaiveDateTime| datetime.and_utc().timestamp();
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+        // 2021-01-31 00:00 local time
+        let timestamps = PrimitiveArray::from(&[Some(seconds(date(2021, 1, 30)) + 23 * 3600)])
+            .to(data_type.clone());
+        let interval = PrimitiveArray::from(&[Some(months_days_ns::new(1, 1, 1_000_000_000))])
+            .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+        let result = add_interval(&timestamps, &interval).unwrap();
+        // 2021-03-01 00:00:01 local time
+        let expected =
+            PrimitiveArray::from(&[Some(seconds(date(2021, 2, 28)) + 23 * 3600 + 1)]).to(data_type);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_add_interval_across_dst() {
+        let seconds = |datetime: NaiveDateTime| datetime.and_utc().timestamp();
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+        // 2021-03-13 12:00 EST
+        let timestamps = PrimitiveArray::from(&[Some(seconds(date(2021, 3, 13)) + 17 * 3600)])
+            .to(data_type.clone());
+        let interval = PrimitiveArray::from(&[Some(days_ms::new(1, 0))])
+            .to(DataType::Interval(IntervalUnit::DayTime));
+
+        let result = add_interval(&timestamps, &interval).unwrap();
+        // 2021-03-14 12:00 EDT, 23 hours later
+        let expected =
+            PrimitiveArray::from(&[Some(seconds(date(2021, 3, 14)) + 16 * 3600)]).to(data_type);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_interval_errors() {
+        let dates = PrimitiveArray::from(&[Some(1)]).to(DataType::Date32);
+        let months = PrimitiveArray::from(&[Some(1), Some(2)])
+            .to(DataType::Interval(IntervalUnit::YearMonth));
+        assert!(add_interval(&dates, &months).is_err());
+
+        let times = PrimitiveArray::from(&[Some(1)]).to(DataType::Time32(TimeUnit::Second));
+        let months =
+            PrimitiveArray::from(&[Some(1)]).to(DataType::Interval(IntervalUnit::YearMonth));
+        assert!(add_interval(&times, &months).is_err());
+        assert!(add_interval(&dates, &dates).is_err());
+    }
+
+    #[test]
+    fn test_months_between() {
+        let lhs = PrimitiveArray::from(&[
+            Some(date32(2021, 2, 28)),
+            Some(date32(2021, 2, 27)),
+            Some(date32(2020, 1, 15)),
+            Some(date32(2020, 1, 16)),
+            None,
+        ])
+        .to(DataType::Date32);
+        let rhs = PrimitiveArray::from(&[
+            Some(date32(2021, 1, 31)),
+            Some(date32(2021, 1, 31)),
+            Some(date32(2021, 1, 15)),
+            Some(date32(2021, 1, 15)),
+            Some(date32(2021, 1, 15)),
+        ])
+        .to(DataType::Date32);
+
+        let result = months_between(&lhs, &rhs).unwrap();
+        let expected = PrimitiveArray::from(&[Some(1), Some(0), Some(-12), Some(-11), None]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_arithmetic_interval() {
+        use super::super::{arithmetic, can_arithmetic, Operator};
+
+        let dates = PrimitiveArray::from(&[Some(date32(2021, 1, 31))]).to(DataType::Date32);
+        let months =
+            PrimitiveArray::from(&[Some(13)]).to(DataType::Interval(IntervalUnit::YearMonth));
+        assert!(can_arithmetic(
+            dates.data_type(),
+            Operator::Add,
+            months.data_type()
+        ));
+
+        let result = arithmetic(&dates, Operator::Add, &months).unwrap();
+        let expected = PrimitiveArray::from(&[Some(date32(2022, 2, 28))]).to(DataType::Date32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
 }
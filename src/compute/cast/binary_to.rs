@@ -1,8 +1,11 @@
 use std::convert::TryFrom;
 
+use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::{array::*, buffer::Buffer};
 
+use super::CastOptions;
+
 pub fn binary_to_large_binary(from: &BinaryArray<i32>) -> BinaryArray<i64> {
     let values = from.values().clone();
     let offsets = from.offsets().iter().map(|x| *x as i64);
@@ -23,3 +26,36 @@ pub fn binary_large_to_binary(from: &BinaryArray<i64>) -> Result<BinaryArray<i32
         from.validity().clone(),
     ))
 }
+
+/// Casts a [`BinaryArray`] to a [`Utf8Array`]. Values that are not valid utf8 are null
+/// when `options.safe` is true, and an error otherwise.
+pub(super) fn binary_to_utf8_dyn<O: Offset>(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+
+    if !options.safe {
+        let invalid = from.iter().enumerate().find_map(|(row, x)| {
+            x.filter(|x| std::str::from_utf8(x).is_err())
+                .map(|x| (row, x))
+        });
+        if let Some((row, value)) = invalid {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot cast value {:?} at row {} from {:?} to {:?}",
+                value,
+                row,
+                from.data_type(),
+                to_type
+            )));
+        }
+    }
+
+    // perf todo: the offsets are equal; we can speed-up this
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| std::str::from_utf8(x).ok()));
+
+    Ok(Box::new(Utf8Array::<O>::from_trusted_len_iter(iter)))
+}
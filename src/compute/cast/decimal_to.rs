@@ -0,0 +1,259 @@
+use num::cast::AsPrimitive;
+
+use crate::{
+    array::*,
+    compute::arity::unary,
+    datatypes::DataType,
+    error::Result,
+    types::{NativeType, NaturalDataType},
+};
+
+use super::{cast_values, CastOptions};
+
+#[inline]
+fn get_scale(data_type: &DataType) -> usize {
+    if let DataType::Decimal(_, scale) = data_type {
+        *scale
+    } else {
+        panic!("A decimal array must have a Decimal data type")
+    }
+}
+
+/// Returns whether `value` has at most `precision` digits.
+#[inline]
+pub(super) fn fits_precision(value: i128, precision: usize) -> bool {
+    // precisions larger than any i128 are always satisfied
+    10u128
+        .checked_pow(precision as u32)
+        .map(|max| value.unsigned_abs() < max)
+        .unwrap_or(true)
+}
+
+/// Changes the scale of `value` from `from_scale` to `to_scale`. Digits dropped when the scale
+/// decreases are truncated, or rounded half away from zero when `round` is true.
+/// Returns `None` on overflow.
+#[inline]
+pub(super) fn rescale(
+    value: i128,
+    from_scale: usize,
+    to_scale: usize,
+    round: bool,
+) -> Option<i128> {
+    if to_scale >= from_scale {
+        10i128
+            .checked_pow((to_scale - from_scale) as u32)
+            .and_then(|factor| value.checked_mul(factor))
+    } else {
+        let divisor = match 10i128.checked_pow((from_scale - to_scale) as u32) {
+            Some(divisor) => divisor,
+            // the divisor is larger than any value
+            None => return Some(0),
+        };
+        let quotient = value / divisor;
+        let remainder = (value % divisor).abs();
+        if round && remainder >= divisor - remainder {
+            Some(quotient + value.signum())
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
+/// Parses a decimal number such as `-123.45` into its integer representation with `scale`
+/// digits after the point. Digits beyond `scale` are truncated, or rounded half away from zero
+/// when `round` is true.
+/// Returns `None` when `value` is not a decimal number or does not fit `precision`.
+pub(super) fn parse_decimal(
+    value: &str,
+    precision: usize,
+    scale: usize,
+    round: bool,
+) -> Option<i128> {
+    let (negative, value) = match value.as_bytes().first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (integer, fraction) = match value.find('.') {
+        Some(point) => (&value[..point], &value[point + 1..]),
+        None => (value, ""),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut digits = integer.bytes().chain(fraction.bytes().take(scale));
+    let mut result = digits.try_fold(0i128, |acc, digit| {
+        acc.checked_mul(10)?.checked_add((digit - b'0') as i128)
+    })?;
+    if fraction.len() < scale {
+        result = result.checked_mul(10i128.checked_pow((scale - fraction.len()) as u32)?)?;
+    } else if round && fraction.as_bytes().get(scale).map(|x| *x >= b'5') == Some(true) {
+        result = result.checked_add(1)?;
+    }
+    let result = if negative { -result } else { result };
+    fits_precision(result, precision).then_some(result)
+}
+
+/// Returns the decimal representation of `value` with `scale` digits after the point.
+fn format_decimal(value: i128, scale: usize) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Casts a Decimal [`PrimitiveArray`] to a Decimal array of another precision and scale.
+/// Values are rescaled as described in [`CastOptions::round`]; values that do not fit
+/// `to_precision` are handled according to [`CastOptions::safe`].
+/// # Errors
+/// This function errors iff `options.safe` is false and a value does not fit `to_precision`.
+pub fn decimal_to_decimal(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>> {
+    let from_scale = get_scale(from.data_type());
+    let to_type = DataType::Decimal(to_precision, to_scale);
+    let iter = from.iter().map(|x| x.copied());
+    cast_values(iter, from.data_type(), &to_type, options, |x| {
+        rescale(x, from_scale, to_scale, options.round).filter(|x| fits_precision(*x, to_precision))
+    })
+}
+
+pub(super) fn decimal_to_decimal_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    decimal_to_decimal(from, to_precision, to_scale, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Casts a Decimal [`PrimitiveArray`] to an integer [`PrimitiveArray`]. The fractional digits
+/// are dropped as described in [`CastOptions::round`]; values that overflow `T` wrap around
+/// or are handled according to [`CastOptions::safe`].
+/// # Errors
+/// This function errors iff `options.safe` and `options.wrapped` are false and a value
+/// overflows `T`.
+pub fn decimal_to_integer<T>(
+    from: &PrimitiveArray<i128>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + NaturalDataType + num::NumCast,
+    i128: AsPrimitive<T>,
+{
+    let scale = get_scale(from.data_type());
+    let iter = from.iter().map(|x| x.copied());
+    let unscaled = |x| rescale(x, scale, 0, options.round);
+    if options.wrapped {
+        cast_values(iter, from.data_type(), to_type, options, |x| {
+            unscaled(x).map(|x| x.as_())
+        })
+    } else {
+        cast_values(iter, from.data_type(), to_type, options, |x| {
+            unscaled(x).and_then(num::cast::<i128, T>)
+        })
+    }
+}
+
+pub(super) fn decimal_to_integer_dyn<T>(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + NaturalDataType + num::NumCast,
+    i128: AsPrimitive<T>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    decimal_to_integer::<T>(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Casts a Decimal [`PrimitiveArray`] to a float [`PrimitiveArray`], which may lose precision.
+pub fn decimal_to_float<T>(from: &PrimitiveArray<i128>, to_type: &DataType) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    f64: AsPrimitive<T>,
+{
+    let divisor = 10f64.powi(get_scale(from.data_type()) as i32);
+    unary(from, |x| (x as f64 / divisor).as_(), to_type.clone())
+}
+
+pub(super) fn decimal_to_float_dyn<T>(
+    from: &dyn Array,
+    to_type: &DataType,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType,
+    f64: AsPrimitive<T>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_float::<T>(from, to_type)))
+}
+
+/// Returns a [`Utf8Array`] where every element is the decimal representation of the value,
+/// with as many digits after the point as the scale of `from`, e.g. `-0.05` for `-5` with
+/// scale 2.
+pub fn decimal_to_utf8<O: Offset>(from: &PrimitiveArray<i128>) -> Utf8Array<O> {
+    let scale = get_scale(from.data_type());
+    let iter = from.iter().map(|x| x.map(|x| format_decimal(*x, scale)));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+pub(super) fn decimal_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_utf8::<O>(from)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_decimal("123.45", 5, 2, false), Some(12345));
+        assert_eq!(parse_decimal("-1.5", 5, 2, false), Some(-150));
+        assert_eq!(parse_decimal("+.5", 5, 2, false), Some(50));
+        assert_eq!(parse_decimal("7.", 5, 0, false), Some(7));
+        assert_eq!(parse_decimal("1.005", 5, 2, false), Some(100));
+        assert_eq!(parse_decimal("1.005", 5, 2, true), Some(101));
+        assert_eq!(parse_decimal("-1.995", 5, 2, true), Some(-200));
+        assert_eq!(parse_decimal("1000.00", 5, 2, false), None);
+        assert_eq!(parse_decimal("", 5, 2, false), None);
+        assert_eq!(parse_decimal(".", 5, 2, false), None);
+        assert_eq!(parse_decimal("1.2.3", 5, 2, false), None);
+        assert_eq!(parse_decimal("1e3", 5, 2, false), None);
+        assert_eq!(parse_decimal("-", 5, 2, false), None);
+    }
+
+    #[test]
+    fn rescale_and_format() {
+        assert_eq!(rescale(12345, 2, 4, false), Some(1234500));
+        assert_eq!(rescale(-12345, 2, 0, false), Some(-123));
+        assert_eq!(rescale(-12350, 2, 0, true), Some(-124));
+        assert_eq!(rescale(12349, 2, 0, true), Some(123));
+        assert_eq!(rescale(i128::MAX, 0, 1, false), None);
+        assert_eq!(rescale(i128::MAX, 40, 0, true), Some(0));
+
+        assert_eq!(format_decimal(12345, 2), "123.45");
+        assert_eq!(format_decimal(-5, 2), "-0.05");
+        assert_eq!(format_decimal(-5, 0), "-5");
+        assert_eq!(format_decimal(0, 3), "0.000");
+    }
+}
@@ -1,4 +1,4 @@
-use super::{cast, primitive_to_primitive};
+use super::{cast, cast_with_options, primitive_to_primitive, CastOptions};
 use crate::{
     array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
     compute::take::take,
@@ -63,6 +63,7 @@ where
 pub(super) fn dictionary_cast_dyn<K: DictionaryKey>(
     array: &dyn Array,
     to_type: &DataType,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
     let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
     let keys = array.keys();
@@ -70,7 +71,19 @@ pub(super) fn dictionary_cast_dyn<K: DictionaryKey>(
 
     match to_type {
         DataType::Dictionary(to_keys_type, to_values_type) => {
-            let values = cast(values.as_ref(), to_values_type)?.into();
+            let values = match cast_with_options(values.as_ref(), to_values_type, options) {
+                Err(ArrowError::InvalidArgumentError(_)) if !options.safe => {
+                    // only values referenced by a key must be castable: errors name their row
+                    unpack_dictionary(keys, values.as_ref(), to_values_type, options)?;
+                    let options = CastOptions {
+                        safe: true,
+                        ..options
+                    };
+                    cast_with_options(values.as_ref(), to_values_type, options)?
+                }
+                values => values?,
+            }
+            .into();
 
             // create the appropriate array type
             match to_keys_type.as_ref() {
@@ -85,7 +98,7 @@ pub(super) fn dictionary_cast_dyn<K: DictionaryKey>(
                 _ => unreachable!(),
             }
         }
-        _ => unpack_dictionary::<K>(keys, values.as_ref(), to_type),
+        _ => unpack_dictionary::<K>(keys, values.as_ref(), to_type, options),
     }
 }
 
@@ -94,17 +107,25 @@ fn unpack_dictionary<K>(
     keys: &PrimitiveArray<K>,
     values: &dyn Array,
     to_type: &DataType,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
     K: DictionaryKey,
 {
-    // attempt to cast the dict values to the target type
-    // use the take kernel to expand out the dictionary
-    let values = cast(values, to_type)?;
-
     // take requires first casting i32
     let indices = primitive_to_primitive::<_, i32>(keys, &DataType::Int32);
 
+    if !options.safe {
+        // expand the dictionary first so that only values referenced by a key must be
+        // castable and errors name the row of the key
+        let values = take(values, &indices)?;
+        return cast_with_options(values.as_ref(), to_type, options);
+    }
+
+    // attempt to cast the dict values to the target type
+    // use the take kernel to expand out the dictionary
+    let values = cast_with_options(values, to_type, options)?;
+
     take(values.as_ref(), &indices)
 }
 
@@ -0,0 +1,263 @@
+use crate::{
+    array::{growable::make_growable, *},
+    bitmap::MutableBitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{cast_with_options, CastOptions};
+
+/// Casts the `values` of the list array `array` to `child_type`, the child type of `to_type`,
+/// where `range(row)` returns the start and length of the values of `row`. When
+/// `options.safe` is false, only the values of valid lists must be castable, and errors name
+/// the row of the list that cannot be cast.
+fn cast_list_values<F>(
+    array: &dyn Array,
+    values: &dyn Array,
+    range: F,
+    to_type: &DataType,
+    child_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
+where
+    F: Fn(usize) -> (usize, usize),
+{
+    let result = cast_with_options(values, child_type, options);
+    if options.safe || !matches!(result, Err(ArrowError::InvalidArgumentError(_))) {
+        return result;
+    }
+
+    // values of null lists are not reachable and are allowed to fail
+    let is_valid = |row: &usize| array.is_valid(*row);
+    let mut growable = make_growable(&[values], false, values.len());
+    (0..array.len()).filter(is_valid).for_each(|row| {
+        let (start, length) = range(row);
+        growable.extend(0, start, length)
+    });
+    let message = match cast_with_options(growable.as_box().as_ref(), child_type, options) {
+        Ok(_) => {
+            let options = CastOptions {
+                safe: true,
+                ..options
+            };
+            return cast_with_options(values, child_type, options);
+        }
+        Err(ArrowError::InvalidArgumentError(message)) => message,
+        Err(error) => return Err(error),
+    };
+
+    for row in (0..array.len()).filter(is_valid) {
+        let (start, length) = range(row);
+        let list = values.slice(start, length);
+        if let Err(ArrowError::InvalidArgumentError(message)) =
+            cast_with_options(list.as_ref(), child_type, options)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot cast list at row {} from {:?} to {:?}: {}",
+                row,
+                array.data_type(),
+                to_type,
+                message
+            )));
+        }
+    }
+    Err(ArrowError::InvalidArgumentError(message))
+}
+
+/// Casts a [`ListArray`] to a [`ListArray`] of `to_type`, casting its values to the child type
+/// of `to_type`.
+/// # Errors
+/// This function errors iff the values of a valid list cannot be cast.
+pub fn cast_list<O: Offset>(
+    array: &ListArray<O>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<O>> {
+    let offsets = array.offsets();
+    let new_values = cast_list_values(
+        array,
+        array.values().as_ref(),
+        |row| {
+            (
+                offsets[row].to_usize(),
+                (offsets[row + 1] - offsets[row]).to_usize(),
+            )
+        },
+        to_type,
+        ListArray::<O>::get_child_type(to_type),
+        options,
+    )?
+    .into();
+
+    Ok(ListArray::<O>::from_data(
+        to_type.clone(),
+        array.offsets().clone(),
+        new_values,
+        array.validity().clone(),
+    ))
+}
+
+/// Casts a [`ListArray`] to a [`ListArray`] with another offset type (e.g. `List` to
+/// `LargeList`), casting its values to the child type of `to_type`.
+/// # Errors
+/// This function errors iff the values of a valid list cannot be cast or the offsets do not
+/// fit `O`.
+pub fn cast_list_offsets<I: Offset, O: Offset>(
+    array: &ListArray<I>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<O>> {
+    let offsets = array.offsets();
+    // offsets are monotonically increasing: all fit if the last one fits
+    O::from_usize(offsets[offsets.len() - 1].to_usize()).ok_or(ArrowError::KeyOverflowError)?;
+
+    let values = cast_list_values(
+        array,
+        array.values().as_ref(),
+        |row| {
+            (
+                offsets[row].to_usize(),
+                (offsets[row + 1] - offsets[row]).to_usize(),
+            )
+        },
+        to_type,
+        ListArray::<O>::get_child_type(to_type),
+        options,
+    )?;
+
+    let offsets = offsets.iter().map(|x| O::from_usize(x.to_usize()).unwrap());
+    let offsets = Buffer::from_trusted_len_iter(offsets);
+
+    Ok(ListArray::<O>::from_data(
+        to_type.clone(),
+        offsets,
+        values.into(),
+        array.validity().clone(),
+    ))
+}
+
+/// Casts a [`FixedSizeListArray`] to a [`ListArray`] of `to_type`, casting its values to the
+/// child type of `to_type`.
+/// # Errors
+/// This function errors iff the values of a valid list cannot be cast or the offsets do not
+/// fit `O`.
+pub fn fixed_size_list_to_list<O: Offset>(
+    array: &FixedSizeListArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<O>> {
+    let size = *FixedSizeListArray::get_child_and_size(array.data_type()).1 as usize;
+    O::from_usize(array.len() * size).ok_or(ArrowError::KeyOverflowError)?;
+    let offsets = (0..=array.len())
+        .map(|x| O::from_usize(x * size).unwrap())
+        .collect::<Buffer<O>>();
+
+    let values = cast_list_values(
+        array,
+        array.values().as_ref(),
+        |row| (row * size, size),
+        to_type,
+        ListArray::<O>::get_child_type(to_type),
+        options,
+    )?;
+
+    Ok(ListArray::<O>::from_data(
+        to_type.clone(),
+        offsets,
+        values.into(),
+        array.validity().clone(),
+    ))
+}
+
+/// Casts a [`ListArray`] to a [`FixedSizeListArray`] of `to_type`, casting its values to the
+/// child type of `to_type`. Lists whose length differs from the size of `to_type` are handled
+/// according to [`CastOptions::safe`].
+/// # Errors
+/// This function errors iff the values of a valid list cannot be cast, or when `options.safe`
+/// is false and a list does not have the size of `to_type`.
+pub fn list_to_fixed_size_list<O: Offset>(
+    array: &ListArray<O>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    let (child_type, size) = FixedSizeListArray::get_child_and_size(to_type);
+    let size = *size as usize;
+    let offsets = array.offsets();
+
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    // whether every list, including null ones, has `size` values
+    let mut is_aligned = true;
+    for (row, window) in offsets.windows(2).enumerate() {
+        let length = (window[1] - window[0]).to_usize();
+        let is_valid = array.is_valid(row);
+        if is_valid && length != size && !options.safe {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot cast list of length {} at row {} from {:?} to {:?}",
+                length,
+                row,
+                array.data_type(),
+                to_type
+            )));
+        }
+        validity.push(is_valid && length == size);
+        is_aligned &= length == size;
+    }
+
+    let values = if is_aligned {
+        array
+            .values()
+            .slice(offsets[0].to_usize(), array.len() * size)
+    } else {
+        let mut growable = make_growable(&[array.values().as_ref()], true, array.len() * size);
+        for (row, window) in offsets.windows(2).enumerate() {
+            if validity.get(row) {
+                growable.extend(0, window[0].to_usize(), size)
+            } else {
+                growable.extend_validity(size)
+            }
+        }
+        growable.as_box()
+    };
+    let values = cast_list_values(
+        array,
+        values.as_ref(),
+        |row| (row * size, size),
+        to_type,
+        child_type,
+        options,
+    )?;
+
+    Ok(FixedSizeListArray::from_data(
+        to_type.clone(),
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Casts a [`FixedSizeListArray`] to a [`FixedSizeListArray`] of the same size, casting its
+/// values to the child type of `to_type`.
+/// # Errors
+/// This function errors iff the values of a valid list cannot be cast.
+pub fn cast_fixed_size_list(
+    array: &FixedSizeListArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    let (child_type, size) = FixedSizeListArray::get_child_and_size(to_type);
+    let size = *size as usize;
+    let values = cast_list_values(
+        array,
+        array.values().as_ref(),
+        |row| (row * size, size),
+        to_type,
+        child_type,
+        options,
+    )?;
+
+    Ok(FixedSizeListArray::from_data(
+        to_type.clone(),
+        values.into(),
+        array.validity().clone(),
+    ))
+}
@@ -20,19 +20,28 @@ use crate::{
     buffer::Buffer,
     datatypes::*,
     error::{ArrowError, Result},
+    temporal_conversions::is_valid_timezone,
+    trusted_len::TrustedLen,
+    types::{NativeType, NaturalDataType},
 };
 
 mod binary_to;
 mod boolean_to;
+mod decimal_to;
 mod dictionary_to;
+mod list_to;
 mod primitive_to;
+mod struct_to;
 mod timestamps;
 mod utf8_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
+pub use decimal_to::*;
 pub use dictionary_to::*;
+pub use list_to::*;
 pub use primitive_to::*;
+pub use struct_to::*;
 pub use timestamps::*;
 pub use utf8_to::*;
 
@@ -45,6 +54,69 @@ fn is_numeric(t: &DataType) -> bool {
     )
 }
 
+/// Options that control the behavior of [`cast_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastOptions {
+    /// Whether values that cannot be cast, such as unparseable strings or numbers that
+    /// overflow the target type, are null (`true`) or make the cast fail (`false`).
+    pub safe: bool,
+    /// Whether numbers that overflow an integer target type wrap around, like `as` does in
+    /// Rust, instead of being handled according to `safe`. Floats saturate at the bounds of
+    /// the target type, and NaNs become zero.
+    pub wrapped: bool,
+    /// Whether floats and decimals are rounded (half away from zero) instead of truncated when
+    /// cast to integers or to decimals with a smaller scale.
+    pub round: bool,
+}
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        Self {
+            safe: true,
+            wrapped: false,
+            round: false,
+        }
+    }
+}
+
+/// Applies `op` to every value of `iter`. Values for which `op` returns `None` are null when
+/// `options.safe` is true, and an error naming the offending row and value otherwise.
+fn cast_values<V, T, I, F>(
+    iter: I,
+    from_type: &DataType,
+    to_type: &DataType,
+    options: CastOptions,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    V: Copy + std::fmt::Debug,
+    T: NativeType + NaturalDataType,
+    I: TrustedLen<Item = Option<V>>,
+    F: Fn(V) -> Option<T>,
+{
+    let array = if options.safe {
+        PrimitiveArray::<T>::from_trusted_len_iter(iter.map(|x| x.and_then(&op)))
+    } else {
+        iter.enumerate()
+            .map(|(row, x)| {
+                x.map(|x| {
+                    op(x).ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "Cannot cast value {:?} at row {} from {:?} to {:?}",
+                            x, row, from_type, to_type
+                        ))
+                    })
+                })
+                .transpose()
+            })
+            // `Vec` stops at the first error, so that it is the one returned
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .collect::<PrimitiveArray<T>>()
+    };
+    Ok(array.to(to_type.clone()))
+}
+
 macro_rules! primitive_dyn {
     ($from:expr, $expr:tt) => {{
         let from = $from.as_any().downcast_ref().unwrap();
@@ -75,16 +147,22 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 
     match (from_type, to_type) {
+        (Struct(from_fields), Struct(to_fields)) => can_cast_fields(from_fields, to_fields),
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
-        (List(list_from), List(list_to)) => {
+        (List(list_from), List(list_to))
+        | (LargeList(list_from), LargeList(list_to))
+        | (List(list_from), LargeList(list_to))
+        | (LargeList(list_from), List(list_to))
+        | (FixedSizeList(list_from, _), List(list_to))
+        | (FixedSizeList(list_from, _), LargeList(list_to))
+        | (List(list_from), FixedSizeList(list_to, _))
+        | (LargeList(list_from), FixedSizeList(list_to, _)) => {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
-        (LargeList(list_from), LargeList(list_to)) => {
-            can_cast_types(list_from.data_type(), list_to.data_type())
+        (FixedSizeList(list_from, from_size), FixedSizeList(list_to, to_size)) => {
+            from_size == to_size && can_cast_types(list_from.data_type(), list_to.data_type())
         }
-        (List(list_from), LargeList(list_to)) if list_from == list_to => true,
-        (LargeList(list_from), List(list_to)) if list_from == list_to => true,
         (_, List(list_to)) => can_cast_types(from_type, list_to.data_type()),
         (Dictionary(_, from_value_type), Dictionary(_, to_value_type)) => {
             can_cast_types(from_value_type, to_value_type)
@@ -99,13 +177,25 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Utf8, Date64) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
         (Utf8, LargeUtf8) => true,
+        (Utf8, Decimal(_, _)) => true,
         (Utf8, _) => is_numeric(to_type),
         (LargeUtf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
         (LargeUtf8, Utf8) => true,
+        (LargeUtf8, Decimal(_, _)) => true,
         (LargeUtf8, _) => is_numeric(to_type),
+        (Timestamp(_, Some(tz)), Utf8) => is_valid_timezone(tz),
+        (Timestamp(_, Some(tz)), LargeUtf8) => is_valid_timezone(tz),
+        (Timestamp(_, _), Utf8) => true,
+        (Timestamp(_, _), LargeUtf8) => true,
+        (Decimal(_, _), Utf8) => true,
+        (Decimal(_, _), LargeUtf8) => true,
         (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
         (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
 
+        (Decimal(_, _), Decimal(_, _)) => true,
+        (Decimal(_, _), _) => is_numeric(to_type),
+        (_, Decimal(_, _)) => is_numeric(from_type),
+
         // start numeric casts
         (UInt8, UInt16) => true,
         (UInt8, UInt32) => true,
@@ -230,6 +320,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Int64) => true,
         (Int64, Timestamp(_, _)) => true,
         (Timestamp(_, _), Timestamp(_, _)) => true,
+        (Timestamp(_, Some(tz)), Date32) => is_valid_timezone(tz),
+        (Timestamp(_, Some(tz)), Date64) => is_valid_timezone(tz),
         (Timestamp(_, _), Date32) => true,
         (Timestamp(_, _), Date64) => true,
         (Int64, Duration(_)) => true,
@@ -239,44 +331,6 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 }
 
-fn cast_list<O: Offset>(array: &ListArray<O>, to_type: &DataType) -> Result<ListArray<O>> {
-    let values = array.values();
-    let new_values = cast(values.as_ref(), ListArray::<O>::get_child_type(to_type))?.into();
-
-    Ok(ListArray::<O>::from_data(
-        to_type.clone(),
-        array.offsets().clone(),
-        new_values,
-        array.validity().clone(),
-    ))
-}
-
-fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListArray<i64> {
-    let offsets = array.offsets();
-    let offsets = offsets.iter().map(|x| *x as i64);
-    let offets = Buffer::from_trusted_len_iter(offsets);
-
-    ListArray::<i64>::from_data(
-        to_type.clone(),
-        offets,
-        array.values().clone(),
-        array.validity().clone(),
-    )
-}
-
-fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i32> {
-    let offsets = array.offsets();
-    let offsets = offsets.iter().map(|x| *x as i32);
-    let offets = Buffer::from_trusted_len_iter(offsets);
-
-    ListArray::<i32>::from_data(
-        to_type.clone(),
-        offets,
-        array.values().clone(),
-        array.validity().clone(),
-    )
-}
-
 /// Cast `array` to the provided data type and return a new [`Array`] with
 /// type `to_type`, if possible.
 ///
@@ -285,19 +339,62 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
-/// * List to List: the underlying data type is cast
+/// * List, LargeList and FixedSizeList to each other: the underlying data type is cast.
+///   Lists cast to a FixedSizeList must have its size
+/// * Struct to Struct: fields are matched by name and cast, in the order of `to_type`.
+///   Missing nullable fields are null
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Decimal to/from numeric, Utf8 and Decimal: values are rescaled, and digits beyond the
+///   target scale are truncated (see [`CastOptions::round`]). Utf8 values are decimal numbers
+///   such as `-123.45`.
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, except from another `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
 /// * Interval and duration
+///
+/// Values that cannot be cast, such as unparseable strings or numbers that overflow
+/// `to_type` (including its precision, for decimals), are null. Use [`cast_with_options`]
+/// to make them an error instead.
 pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
+    cast_with_options(array, to_type, CastOptions::default())
+}
+
+/// Cast `array` to the provided data type and return a new [`Array`] with
+/// type `to_type`, if possible, with the behavior described in [`CastOptions`].
+///
+/// See [`cast`] for the supported casts.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int8Array, Utf8Array};
+/// use arrow2::compute::cast::{cast_with_options, CastOptions};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["1", "a", "300"]);
+/// let options = CastOptions {
+///     safe: false,
+///     ..Default::default()
+/// };
+/// let error = cast_with_options(&array, &DataType::Int8, options).unwrap_err();
+/// assert!(error.to_string().contains("\"a\" at row 1"));
+///
+/// let result = cast_with_options(&array, &DataType::Int8, CastOptions::default()).unwrap();
+/// let expected = Int8Array::from(&[Some(1), None, None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors when the cast is not supported, or when `options.safe` is false
+/// and a value cannot be cast.
+pub fn cast_with_options(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
 
@@ -306,32 +403,56 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
         return Ok(clone(array));
     }
     match (from_type, to_type) {
+        (Struct(_), Struct(_)) => {
+            cast_struct(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
         (_, Struct(_)) => Err(ArrowError::NotYetImplemented(
             "Cannot cast to struct from other types".to_string(),
         )),
-        (List(_), List(_)) => cast_list::<i32>(array.as_any().downcast_ref().unwrap(), to_type)
-            .map(|x| Box::new(x) as Box<dyn Array>),
+        (List(_), List(_)) => {
+            cast_list::<i32>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
         (LargeList(_), LargeList(_)) => {
-            cast_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type)
+            cast_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (List(_), LargeList(_)) => {
+            cast_list_offsets::<i32, i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (LargeList(_), List(_)) => {
+            cast_list_offsets::<i64, i32>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (FixedSizeList(_, _), List(_)) => {
+            fixed_size_list_to_list::<i32>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (FixedSizeList(_, _), LargeList(_)) => {
+            fixed_size_list_to_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (List(_), FixedSizeList(_, _)) => {
+            list_to_fixed_size_list::<i32>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (LargeList(_), FixedSizeList(_, _)) => {
+            list_to_fixed_size_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (FixedSizeList(_, from_size), FixedSizeList(_, to_size)) if from_size == to_size => {
+            cast_fixed_size_list(array.as_any().downcast_ref().unwrap(), to_type, options)
                 .map(|x| Box::new(x) as Box<dyn Array>)
         }
-        (List(lhs), LargeList(rhs)) if lhs == rhs => Ok(cast_list_to_large_list(
-            array.as_any().downcast_ref().unwrap(),
-            to_type,
-        ))
-        .map(|x| Box::new(x) as Box<dyn Array>),
-        (List(lhs), LargeList(rhs)) if lhs == rhs => Ok(cast_large_to_list(
-            array.as_any().downcast_ref().unwrap(),
-            to_type,
-        ))
-        .map(|x| Box::new(x) as Box<dyn Array>),
 
         (_, List(to)) => {
             // cast primitive to list's primitive
-            let values = cast(array, to.data_type())?.into();
+            let values = cast_with_options(array, to.data_type(), options)?.into();
             // create offsets, where if array.len() = 2, we have [0,1,2]
             let offsets =
                 unsafe { Buffer::from_trusted_len_iter_unchecked(0..=array.len() as i32) };
@@ -343,25 +464,25 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
         }
 
         (Dictionary(index_type, _), _) => match **index_type {
-            DataType::Int8 => dictionary_cast_dyn::<i8>(array, to_type),
-            DataType::Int16 => dictionary_cast_dyn::<i16>(array, to_type),
-            DataType::Int32 => dictionary_cast_dyn::<i32>(array, to_type),
-            DataType::Int64 => dictionary_cast_dyn::<i64>(array, to_type),
-            DataType::UInt8 => dictionary_cast_dyn::<u8>(array, to_type),
-            DataType::UInt16 => dictionary_cast_dyn::<u16>(array, to_type),
-            DataType::UInt32 => dictionary_cast_dyn::<u32>(array, to_type),
-            DataType::UInt64 => dictionary_cast_dyn::<u64>(array, to_type),
+            DataType::Int8 => dictionary_cast_dyn::<i8>(array, to_type, options),
+            DataType::Int16 => dictionary_cast_dyn::<i16>(array, to_type, options),
+            DataType::Int32 => dictionary_cast_dyn::<i32>(array, to_type, options),
+            DataType::Int64 => dictionary_cast_dyn::<i64>(array, to_type, options),
+            DataType::UInt8 => dictionary_cast_dyn::<u8>(array, to_type, options),
+            DataType::UInt16 => dictionary_cast_dyn::<u16>(array, to_type, options),
+            DataType::UInt32 => dictionary_cast_dyn::<u32>(array, to_type, options),
+            DataType::UInt64 => dictionary_cast_dyn::<u64>(array, to_type, options),
             _ => unreachable!(),
         },
         (_, Dictionary(index_type, value_type)) => match **index_type {
-            DataType::Int8 => cast_to_dictionary::<i8>(array, value_type),
-            DataType::Int16 => cast_to_dictionary::<i16>(array, value_type),
-            DataType::Int32 => cast_to_dictionary::<i32>(array, value_type),
-            DataType::Int64 => cast_to_dictionary::<i64>(array, value_type),
-            DataType::UInt8 => cast_to_dictionary::<u8>(array, value_type),
-            DataType::UInt16 => cast_to_dictionary::<u16>(array, value_type),
-            DataType::UInt32 => cast_to_dictionary::<u32>(array, value_type),
-            DataType::UInt64 => cast_to_dictionary::<u64>(array, value_type),
+            DataType::Int8 => cast_to_dictionary::<i8>(array, value_type, options),
+            DataType::Int16 => cast_to_dictionary::<i16>(array, value_type, options),
+            DataType::Int32 => cast_to_dictionary::<i32>(array, value_type, options),
+            DataType::Int64 => cast_to_dictionary::<i64>(array, value_type, options),
+            DataType::UInt8 => cast_to_dictionary::<u8>(array, value_type, options),
+            DataType::UInt16 => cast_to_dictionary::<u16>(array, value_type, options),
+            DataType::UInt32 => cast_to_dictionary::<u32>(array, value_type, options),
+            DataType::UInt64 => cast_to_dictionary::<u64>(array, value_type, options),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from type {:?} to dictionary type {:?} not supported",
                 from_type, to_type,
@@ -403,43 +524,53 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
         },
 
         (Utf8, _) => match to_type {
-            UInt8 => utf8_to_primitive_dyn::<i32, u8>(array, to_type),
-            UInt16 => utf8_to_primitive_dyn::<i32, u16>(array, to_type),
-            UInt32 => utf8_to_primitive_dyn::<i32, u32>(array, to_type),
-            UInt64 => utf8_to_primitive_dyn::<i32, u64>(array, to_type),
-            Int8 => utf8_to_primitive_dyn::<i32, i8>(array, to_type),
-            Int16 => utf8_to_primitive_dyn::<i32, i16>(array, to_type),
-            Int32 => utf8_to_primitive_dyn::<i32, i32>(array, to_type),
-            Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type),
-            Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type),
-            Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type),
-            Date32 => utf8_to_date32_dyn::<i32>(array),
-            Date64 => utf8_to_date64_dyn::<i32>(array),
+            UInt8 => utf8_to_primitive_dyn::<i32, u8>(array, to_type, options),
+            UInt16 => utf8_to_primitive_dyn::<i32, u16>(array, to_type, options),
+            UInt32 => utf8_to_primitive_dyn::<i32, u32>(array, to_type, options),
+            UInt64 => utf8_to_primitive_dyn::<i32, u64>(array, to_type, options),
+            Int8 => utf8_to_primitive_dyn::<i32, i8>(array, to_type, options),
+            Int16 => utf8_to_primitive_dyn::<i32, i16>(array, to_type, options),
+            Int32 => utf8_to_primitive_dyn::<i32, i32>(array, to_type, options),
+            Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
+            Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
+            Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
+            Date32 => utf8_to_date32_dyn::<i32>(array, options),
+            Date64 => utf8_to_date64_dyn::<i32>(array, options),
+            Decimal(precision, scale) => {
+                utf8_to_decimal_dyn::<i32>(array, *precision, *scale, options)
+            }
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_timestamp_ns_dyn::<i32>(array),
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_timestamp_ns_dyn::<i32>(array, options)
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
             ))),
         },
         (LargeUtf8, _) => match to_type {
-            UInt8 => utf8_to_primitive_dyn::<i64, u8>(array, to_type),
-            UInt16 => utf8_to_primitive_dyn::<i64, u16>(array, to_type),
-            UInt32 => utf8_to_primitive_dyn::<i64, u32>(array, to_type),
-            UInt64 => utf8_to_primitive_dyn::<i64, u64>(array, to_type),
-            Int8 => utf8_to_primitive_dyn::<i64, i8>(array, to_type),
-            Int16 => utf8_to_primitive_dyn::<i64, i16>(array, to_type),
-            Int32 => utf8_to_primitive_dyn::<i64, i32>(array, to_type),
-            Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type),
-            Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type),
-            Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type),
-            Date32 => utf8_to_date32_dyn::<i64>(array),
-            Date64 => utf8_to_date64_dyn::<i64>(array),
+            UInt8 => utf8_to_primitive_dyn::<i64, u8>(array, to_type, options),
+            UInt16 => utf8_to_primitive_dyn::<i64, u16>(array, to_type, options),
+            UInt32 => utf8_to_primitive_dyn::<i64, u32>(array, to_type, options),
+            UInt64 => utf8_to_primitive_dyn::<i64, u64>(array, to_type, options),
+            Int8 => utf8_to_primitive_dyn::<i64, i8>(array, to_type, options),
+            Int16 => utf8_to_primitive_dyn::<i64, i16>(array, to_type, options),
+            Int32 => utf8_to_primitive_dyn::<i64, i32>(array, to_type, options),
+            Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
+            Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
+            Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
+            Date32 => utf8_to_date32_dyn::<i64>(array, options),
+            Date64 => utf8_to_date64_dyn::<i64>(array, options),
+            Decimal(precision, scale) => {
+                utf8_to_decimal_dyn::<i64>(array, *precision, *scale, options)
+            }
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
                 .map(|x| Box::new(x) as Box<dyn Array>),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_timestamp_ns_dyn::<i64>(array),
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_timestamp_ns_dyn::<i64>(array, options)
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -457,17 +588,18 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| std::str::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i32>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
+            Timestamp(from_unit, None) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                naive_timestamp_to_utf8::<i32>(from, from_unit, options.safe)
+                    .map(|x| Box::new(x) as Box<dyn Array>)
             }
+            Timestamp(from_unit, Some(tz)) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                timestamp_to_utf8::<i32>(from, from_unit, tz, options.safe)
+                    .map(|x| Box::new(x) as Box<dyn Array>)
+            }
+            Binary => binary_to_utf8_dyn::<i32>(array, to_type, options),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i32>(array),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -485,17 +617,18 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| std::str::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i64>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
+            Timestamp(from_unit, None) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                naive_timestamp_to_utf8::<i64>(from, from_unit, options.safe)
+                    .map(|x| Box::new(x) as Box<dyn Array>)
+            }
+            Timestamp(from_unit, Some(tz)) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                timestamp_to_utf8::<i64>(from, from_unit, tz, options.safe)
+                    .map(|x| Box::new(x) as Box<dyn Array>)
             }
+            Binary => binary_to_utf8_dyn::<i64>(array, to_type, options),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i64>(array),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -508,106 +641,142 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
         (LargeBinary, Binary) => binary_large_to_binary(array.as_any().downcast_ref().unwrap())
             .map(|x| Box::new(x) as Box<dyn Array>),
 
+        (Decimal(_, _), Decimal(precision, scale)) => {
+            decimal_to_decimal_dyn(array, *precision, *scale, options)
+        }
+        (Decimal(_, _), _) => match to_type {
+            UInt8 => decimal_to_integer_dyn::<u8>(array, to_type, options),
+            UInt16 => decimal_to_integer_dyn::<u16>(array, to_type, options),
+            UInt32 => decimal_to_integer_dyn::<u32>(array, to_type, options),
+            UInt64 => decimal_to_integer_dyn::<u64>(array, to_type, options),
+            Int8 => decimal_to_integer_dyn::<i8>(array, to_type, options),
+            Int16 => decimal_to_integer_dyn::<i16>(array, to_type, options),
+            Int32 => decimal_to_integer_dyn::<i32>(array, to_type, options),
+            Int64 => decimal_to_integer_dyn::<i64>(array, to_type, options),
+            Float32 => decimal_to_float_dyn::<f32>(array, to_type),
+            Float64 => decimal_to_float_dyn::<f64>(array, to_type),
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            ))),
+        },
+        (_, Decimal(precision, scale)) => match from_type {
+            UInt8 => integer_to_decimal_dyn::<u8>(array, *precision, *scale, options),
+            UInt16 => integer_to_decimal_dyn::<u16>(array, *precision, *scale, options),
+            UInt32 => integer_to_decimal_dyn::<u32>(array, *precision, *scale, options),
+            UInt64 => integer_to_decimal_dyn::<u64>(array, *precision, *scale, options),
+            Int8 => integer_to_decimal_dyn::<i8>(array, *precision, *scale, options),
+            Int16 => integer_to_decimal_dyn::<i16>(array, *precision, *scale, options),
+            Int32 => integer_to_decimal_dyn::<i32>(array, *precision, *scale, options),
+            Int64 => integer_to_decimal_dyn::<i64>(array, *precision, *scale, options),
+            Float32 => float_to_decimal_dyn::<f32>(array, *precision, *scale, options),
+            Float64 => float_to_decimal_dyn::<f64>(array, *precision, *scale, options),
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            ))),
+        },
+
         // start numeric casts
-        (UInt8, UInt16) => primitive_to_primitive_dyn::<u8, u16>(array, to_type),
-        (UInt8, UInt32) => primitive_to_primitive_dyn::<u8, u32>(array, to_type),
-        (UInt8, UInt64) => primitive_to_primitive_dyn::<u8, u64>(array, to_type),
-        (UInt8, Int8) => primitive_to_primitive_dyn::<u8, i8>(array, to_type),
-        (UInt8, Int16) => primitive_to_primitive_dyn::<u8, i16>(array, to_type),
-        (UInt8, Int32) => primitive_to_primitive_dyn::<u8, i32>(array, to_type),
-        (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type),
-        (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type),
-        (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type),
-
-        (UInt16, UInt8) => primitive_to_primitive_dyn::<u16, u8>(array, to_type),
-        (UInt16, UInt32) => primitive_to_primitive_dyn::<u16, u32>(array, to_type),
-        (UInt16, UInt64) => primitive_to_primitive_dyn::<u16, u64>(array, to_type),
-        (UInt16, Int8) => primitive_to_primitive_dyn::<u16, i8>(array, to_type),
-        (UInt16, Int16) => primitive_to_primitive_dyn::<u16, i16>(array, to_type),
-        (UInt16, Int32) => primitive_to_primitive_dyn::<u16, i32>(array, to_type),
-        (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type),
-        (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type),
-        (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type),
-
-        (UInt32, UInt8) => primitive_to_primitive_dyn::<u32, u8>(array, to_type),
-        (UInt32, UInt16) => primitive_to_primitive_dyn::<u32, u16>(array, to_type),
-        (UInt32, UInt64) => primitive_to_primitive_dyn::<u32, u64>(array, to_type),
-        (UInt32, Int8) => primitive_to_primitive_dyn::<u32, i8>(array, to_type),
-        (UInt32, Int16) => primitive_to_primitive_dyn::<u32, i16>(array, to_type),
-        (UInt32, Int32) => primitive_to_primitive_dyn::<u32, i32>(array, to_type),
-        (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type),
-        (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type),
-        (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type),
-
-        (UInt64, UInt8) => primitive_to_primitive_dyn::<u64, u8>(array, to_type),
-        (UInt64, UInt16) => primitive_to_primitive_dyn::<u64, u16>(array, to_type),
-        (UInt64, UInt32) => primitive_to_primitive_dyn::<u64, u32>(array, to_type),
-        (UInt64, Int8) => primitive_to_primitive_dyn::<u64, i8>(array, to_type),
-        (UInt64, Int16) => primitive_to_primitive_dyn::<u64, i16>(array, to_type),
-        (UInt64, Int32) => primitive_to_primitive_dyn::<u64, i32>(array, to_type),
-        (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type),
-        (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type),
-        (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type),
-
-        (Int8, UInt8) => primitive_to_primitive_dyn::<i8, u8>(array, to_type),
-        (Int8, UInt16) => primitive_to_primitive_dyn::<i8, u16>(array, to_type),
-        (Int8, UInt32) => primitive_to_primitive_dyn::<i8, u32>(array, to_type),
-        (Int8, UInt64) => primitive_to_primitive_dyn::<i8, u64>(array, to_type),
-        (Int8, Int16) => primitive_to_primitive_dyn::<i8, i16>(array, to_type),
-        (Int8, Int32) => primitive_to_primitive_dyn::<i8, i32>(array, to_type),
-        (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type),
-        (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type),
-        (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type),
-
-        (Int16, UInt8) => primitive_to_primitive_dyn::<i16, u8>(array, to_type),
-        (Int16, UInt16) => primitive_to_primitive_dyn::<i16, u16>(array, to_type),
-        (Int16, UInt32) => primitive_to_primitive_dyn::<i16, u32>(array, to_type),
-        (Int16, UInt64) => primitive_to_primitive_dyn::<i16, u64>(array, to_type),
-        (Int16, Int8) => primitive_to_primitive_dyn::<i16, i8>(array, to_type),
-        (Int16, Int32) => primitive_to_primitive_dyn::<i16, i32>(array, to_type),
-        (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type),
-        (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type),
-        (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type),
-
-        (Int32, UInt8) => primitive_to_primitive_dyn::<i32, u8>(array, to_type),
-        (Int32, UInt16) => primitive_to_primitive_dyn::<i32, u16>(array, to_type),
-        (Int32, UInt32) => primitive_to_primitive_dyn::<i32, u32>(array, to_type),
-        (Int32, UInt64) => primitive_to_primitive_dyn::<i32, u64>(array, to_type),
-        (Int32, Int8) => primitive_to_primitive_dyn::<i32, i8>(array, to_type),
-        (Int32, Int16) => primitive_to_primitive_dyn::<i32, i16>(array, to_type),
-        (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type),
-        (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type),
-        (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type),
-
-        (Int64, UInt8) => primitive_to_primitive_dyn::<i64, u8>(array, to_type),
-        (Int64, UInt16) => primitive_to_primitive_dyn::<i64, u16>(array, to_type),
-        (Int64, UInt32) => primitive_to_primitive_dyn::<i64, u32>(array, to_type),
-        (Int64, UInt64) => primitive_to_primitive_dyn::<i64, u64>(array, to_type),
-        (Int64, Int8) => primitive_to_primitive_dyn::<i64, i8>(array, to_type),
-        (Int64, Int16) => primitive_to_primitive_dyn::<i64, i16>(array, to_type),
-        (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type),
-        (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type),
-        (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type),
-
-        (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type),
-        (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type),
-        (Float32, UInt32) => primitive_to_primitive_dyn::<f32, u32>(array, to_type),
-        (Float32, UInt64) => primitive_to_primitive_dyn::<f32, u64>(array, to_type),
-        (Float32, Int8) => primitive_to_primitive_dyn::<f32, i8>(array, to_type),
-        (Float32, Int16) => primitive_to_primitive_dyn::<f32, i16>(array, to_type),
-        (Float32, Int32) => primitive_to_primitive_dyn::<f32, i32>(array, to_type),
-        (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type),
-        (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type),
-
-        (Float64, UInt8) => primitive_to_primitive_dyn::<f64, u8>(array, to_type),
-        (Float64, UInt16) => primitive_to_primitive_dyn::<f64, u16>(array, to_type),
-        (Float64, UInt32) => primitive_to_primitive_dyn::<f64, u32>(array, to_type),
-        (Float64, UInt64) => primitive_to_primitive_dyn::<f64, u64>(array, to_type),
-        (Float64, Int8) => primitive_to_primitive_dyn::<f64, i8>(array, to_type),
-        (Float64, Int16) => primitive_to_primitive_dyn::<f64, i16>(array, to_type),
-        (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type),
-        (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type),
-        (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type),
+        (UInt8, UInt16) => primitive_to_primitive_dyn::<u8, u16>(array, to_type, options),
+        (UInt8, UInt32) => primitive_to_primitive_dyn::<u8, u32>(array, to_type, options),
+        (UInt8, UInt64) => primitive_to_primitive_dyn::<u8, u64>(array, to_type, options),
+        (UInt8, Int8) => primitive_to_primitive_dyn::<u8, i8>(array, to_type, options),
+        (UInt8, Int16) => primitive_to_primitive_dyn::<u8, i16>(array, to_type, options),
+        (UInt8, Int32) => primitive_to_primitive_dyn::<u8, i32>(array, to_type, options),
+        (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type, options),
+        (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, options),
+        (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, options),
+
+        (UInt16, UInt8) => primitive_to_primitive_dyn::<u16, u8>(array, to_type, options),
+        (UInt16, UInt32) => primitive_to_primitive_dyn::<u16, u32>(array, to_type, options),
+        (UInt16, UInt64) => primitive_to_primitive_dyn::<u16, u64>(array, to_type, options),
+        (UInt16, Int8) => primitive_to_primitive_dyn::<u16, i8>(array, to_type, options),
+        (UInt16, Int16) => primitive_to_primitive_dyn::<u16, i16>(array, to_type, options),
+        (UInt16, Int32) => primitive_to_primitive_dyn::<u16, i32>(array, to_type, options),
+        (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type, options),
+        (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, options),
+        (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, options),
+
+        (UInt32, UInt8) => primitive_to_primitive_dyn::<u32, u8>(array, to_type, options),
+        (UInt32, UInt16) => primitive_to_primitive_dyn::<u32, u16>(array, to_type, options),
+        (UInt32, UInt64) => primitive_to_primitive_dyn::<u32, u64>(array, to_type, options),
+        (UInt32, Int8) => primitive_to_primitive_dyn::<u32, i8>(array, to_type, options),
+        (UInt32, Int16) => primitive_to_primitive_dyn::<u32, i16>(array, to_type, options),
+        (UInt32, Int32) => primitive_to_primitive_dyn::<u32, i32>(array, to_type, options),
+        (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type, options),
+        (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, options),
+        (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, options),
+
+        (UInt64, UInt8) => primitive_to_primitive_dyn::<u64, u8>(array, to_type, options),
+        (UInt64, UInt16) => primitive_to_primitive_dyn::<u64, u16>(array, to_type, options),
+        (UInt64, UInt32) => primitive_to_primitive_dyn::<u64, u32>(array, to_type, options),
+        (UInt64, Int8) => primitive_to_primitive_dyn::<u64, i8>(array, to_type, options),
+        (UInt64, Int16) => primitive_to_primitive_dyn::<u64, i16>(array, to_type, options),
+        (UInt64, Int32) => primitive_to_primitive_dyn::<u64, i32>(array, to_type, options),
+        (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type, options),
+        (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, options),
+        (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, options),
+
+        (Int8, UInt8) => primitive_to_primitive_dyn::<i8, u8>(array, to_type, options),
+        (Int8, UInt16) => primitive_to_primitive_dyn::<i8, u16>(array, to_type, options),
+        (Int8, UInt32) => primitive_to_primitive_dyn::<i8, u32>(array, to_type, options),
+        (Int8, UInt64) => primitive_to_primitive_dyn::<i8, u64>(array, to_type, options),
+        (Int8, Int16) => primitive_to_primitive_dyn::<i8, i16>(array, to_type, options),
+        (Int8, Int32) => primitive_to_primitive_dyn::<i8, i32>(array, to_type, options),
+        (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type, options),
+        (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, options),
+        (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, options),
+
+        (Int16, UInt8) => primitive_to_primitive_dyn::<i16, u8>(array, to_type, options),
+        (Int16, UInt16) => primitive_to_primitive_dyn::<i16, u16>(array, to_type, options),
+        (Int16, UInt32) => primitive_to_primitive_dyn::<i16, u32>(array, to_type, options),
+        (Int16, UInt64) => primitive_to_primitive_dyn::<i16, u64>(array, to_type, options),
+        (Int16, Int8) => primitive_to_primitive_dyn::<i16, i8>(array, to_type, options),
+        (Int16, Int32) => primitive_to_primitive_dyn::<i16, i32>(array, to_type, options),
+        (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type, options),
+        (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, options),
+        (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, options),
+
+        (Int32, UInt8) => primitive_to_primitive_dyn::<i32, u8>(array, to_type, options),
+        (Int32, UInt16) => primitive_to_primitive_dyn::<i32, u16>(array, to_type, options),
+        (Int32, UInt32) => primitive_to_primitive_dyn::<i32, u32>(array, to_type, options),
+        (Int32, UInt64) => primitive_to_primitive_dyn::<i32, u64>(array, to_type, options),
+        (Int32, Int8) => primitive_to_primitive_dyn::<i32, i8>(array, to_type, options),
+        (Int32, Int16) => primitive_to_primitive_dyn::<i32, i16>(array, to_type, options),
+        (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type, options),
+        (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, options),
+        (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, options),
+
+        (Int64, UInt8) => primitive_to_primitive_dyn::<i64, u8>(array, to_type, options),
+        (Int64, UInt16) => primitive_to_primitive_dyn::<i64, u16>(array, to_type, options),
+        (Int64, UInt32) => primitive_to_primitive_dyn::<i64, u32>(array, to_type, options),
+        (Int64, UInt64) => primitive_to_primitive_dyn::<i64, u64>(array, to_type, options),
+        (Int64, Int8) => primitive_to_primitive_dyn::<i64, i8>(array, to_type, options),
+        (Int64, Int16) => primitive_to_primitive_dyn::<i64, i16>(array, to_type, options),
+        (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type, options),
+        (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
+        (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, options),
+
+        (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
+        (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
+        (Float32, UInt32) => primitive_to_primitive_dyn::<f32, u32>(array, to_type, options),
+        (Float32, UInt64) => primitive_to_primitive_dyn::<f32, u64>(array, to_type, options),
+        (Float32, Int8) => primitive_to_primitive_dyn::<f32, i8>(array, to_type, options),
+        (Float32, Int16) => primitive_to_primitive_dyn::<f32, i16>(array, to_type, options),
+        (Float32, Int32) => primitive_to_primitive_dyn::<f32, i32>(array, to_type, options),
+        (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type, options),
+        (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type, options),
+
+        (Float64, UInt8) => primitive_to_primitive_dyn::<f64, u8>(array, to_type, options),
+        (Float64, UInt16) => primitive_to_primitive_dyn::<f64, u16>(array, to_type, options),
+        (Float64, UInt32) => primitive_to_primitive_dyn::<f64, u32>(array, to_type, options),
+        (Float64, UInt64) => primitive_to_primitive_dyn::<f64, u64>(array, to_type, options),
+        (Float64, Int8) => primitive_to_primitive_dyn::<f64, i8>(array, to_type, options),
+        (Float64, Int16) => primitive_to_primitive_dyn::<f64, i16>(array, to_type, options),
+        (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type, options),
+        (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
+        (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
         // end numeric casts
 
         // temporal casts
@@ -655,8 +824,22 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
         (Timestamp(from_unit, tz1), Timestamp(to_unit, tz2)) if tz1 == tz2 => {
             primitive_dyn!(array, timestamp_to_timestamp, from_unit, to_unit, tz2)
         }
-        (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, from_unit),
-        (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, from_unit),
+        (Timestamp(from_unit, None), Date32) => {
+            primitive_dyn!(array, timestamp_to_date32, from_unit)
+        }
+        (Timestamp(from_unit, None), Date64) => {
+            primitive_dyn!(array, timestamp_to_date64, from_unit)
+        }
+        (Timestamp(from_unit, Some(tz)), Date32) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            timestamp_tz_to_date32(from, from_unit, tz, options.safe)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (Timestamp(from_unit, Some(tz)), Date64) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            timestamp_tz_to_date64(from, from_unit, tz, options.safe)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
@@ -677,8 +860,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<Box<dyn Array>> {
 fn cast_to_dictionary<K: DictionaryKey>(
     array: &dyn Array,
     dict_value_type: &DataType,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
-    let array = cast(array, dict_value_type)?;
+    let array = cast_with_options(array, dict_value_type, options)?;
     let array = array.as_ref();
     match *dict_value_type {
         DataType::Int8 => primitive_to_dictionary_dyn::<i8, K>(array),
@@ -700,7 +884,10 @@ fn cast_to_dictionary<K: DictionaryKey>(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use super::*;
+    use crate::bitmap::Bitmap;
 
     #[test]
     fn test_cast_i32_to_f64() {
@@ -904,6 +1091,545 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_cast_timestamp_with_timezone() {
+        // 2020-12-31T23:30:00.5 UTC
+        let array = Int64Array::from(&[Some(1609457400500), None]);
+        let array = array.to(DataType::Timestamp(
+            TimeUnit::Millisecond,
+            Some("+01:00".to_string()),
+        ));
+
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let expected = Int32Array::from(&[Some(18628), None]).to(DataType::Date32);
+        assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+        let b = cast(&array, &DataType::Date64).unwrap();
+        let expected = Int64Array::from(&[Some(18628 * 86_400_000), None]).to(DataType::Date64);
+        assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected = Utf8Array::<i32>::from(&[Some("2021-01-01 00:30:00.500+01:00"), None]);
+        assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+        let array = array.to(DataType::Timestamp(TimeUnit::Millisecond, None));
+        let b = cast(&array, &DataType::LargeUtf8).unwrap();
+        let expected = Utf8Array::<i64>::from(&[Some("2020-12-31 23:30:00.500"), None]);
+        assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+        // invalid timezones cannot be cast
+        let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+25:00".to_string()));
+        let array = array.to(data_type.clone());
+        for to_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Date32] {
+            assert!(!can_cast_types(&data_type, &to_type));
+            assert!(cast(&array, &to_type).is_err());
+        }
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_cast_timestamp_with_iana_timezone() {
+        // 2021-03-14T04:30:00 and 2021-07-01T03:30:00 UTC
+        let array = Int64Array::from(&[Some(1615696200), Some(1625110200)]).to(
+            DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string())),
+        );
+
+        let b = cast(&array, &DataType::Date32).unwrap();
+        let expected = Int32Array::from(&[Some(18699), Some(18808)]).to(DataType::Date32);
+        assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let expected = Utf8Array::<i32>::from(&[
+            Some("2021-03-13 23:30:00-05:00"),
+            Some("2021-06-30 23:30:00-04:00"),
+        ]);
+        assert_eq!(b.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_format_and_parse_temporal() {
+        let dates = Utf8Array::<i32>::from(&[Some("31/12/2020"), Some("31/02/2020"), None]);
+        assert!(utf8_to_date32_with_format(&dates, "%d/%m/%Y", false).is_err());
+        let result = utf8_to_date32_with_format(&dates, "%d/%m/%Y", true).unwrap();
+        let expected = Int32Array::from(&[Some(18627), None, None]).to(DataType::Date32);
+        assert_eq!(result, expected);
+        let result = date32_to_utf8::<i32>(&result, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            result,
+            Utf8Array::<i32>::from(&[Some("2020-12-31"), None, None])
+        );
+        let result = utf8_to_date64_with_format(&result, "%Y-%m-%d", false).unwrap();
+        let expected =
+            Int64Array::from(&[Some(18627 * 86_400_000), None, None]).to(DataType::Date64);
+        assert_eq!(result, expected);
+
+        let times = Utf8Array::<i64>::from_slice(&["10:30:00.25"]);
+        let result = utf8_to_time32(&times, "%H:%M:%S%.f", TimeUnit::Millisecond, false).unwrap();
+        assert_eq!(result.values().as_slice(), &[37_800_250]);
+        assert_eq!(
+            time32_to_utf8::<i64>(&result, "%H:%M:%S%.3f").unwrap(),
+            Utf8Array::<i64>::from_slice(&["10:30:00.250"])
+        );
+        let result = utf8_to_time64(&times, "%H:%M:%S%.f", TimeUnit::Nanosecond, false).unwrap();
+        assert_eq!(result.values().as_slice(), &[37_800_250_000_000]);
+        assert_eq!(
+            time64_to_utf8::<i64>(&result, "%I:%M %p").unwrap(),
+            Utf8Array::<i64>::from_slice(&["10:30 AM"])
+        );
+        assert!(utf8_to_time32(&times, "%H:%M:%S%.f", TimeUnit::Nanosecond, true).is_err());
+        assert!(time64_to_utf8::<i64>(&result, "%Y").is_err());
+    }
+
+    #[test]
+    fn test_format_and_parse_timestamp() {
+        let array = Utf8Array::<i32>::from(&[
+            Some("2021-01-01 00:00:00 +0200"),
+            Some("2021-01-01 00:00:00"),
+            None,
+        ]);
+        let result =
+            utf8_to_timestamp(&array, "%Y-%m-%d %H:%M:%S %z", None, TimeUnit::Second, true)
+                .unwrap();
+        let expected = Int64Array::from(&[Some(1609452000), None, None])
+            .to(DataType::Timestamp(TimeUnit::Second, None));
+        assert_eq!(result, expected);
+
+        let result = utf8_to_timestamp(
+            &array,
+            "%Y-%m-%d %H:%M:%S",
+            Some("-01:00"),
+            TimeUnit::Millisecond,
+            true,
+        )
+        .unwrap();
+        let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("-01:00".to_string()));
+        let expected = Int64Array::from(&[None, Some(1609462800000), None]).to(data_type.clone());
+        assert_eq!(result, expected);
+
+        let result =
+            timestamp_to_utf8_with_format::<i32>(&result, "%Y-%m-%dT%H:%M:%S%:z", true).unwrap();
+        assert_eq!(
+            result,
+            Utf8Array::<i32>::from(&[None, Some("2021-01-01T00:00:00-01:00"), None])
+        );
+
+        let naive = Int64Array::from_slice(&[0]).to(DataType::Timestamp(TimeUnit::Second, None));
+        assert!(timestamp_to_utf8_with_format::<i32>(&naive, "%z", true).is_err());
+        assert!(timestamp_to_utf8_with_format::<i32>(&naive, "%Q", true).is_err());
+        assert!(
+            timestamp_to_utf8_with_format::<i32>(&Int64Array::from_slice(&[0]), "%Y", true)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cast_timestamp_out_of_range() {
+        let checked = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+
+        for timezone in [None, Some("+01:00".to_string())] {
+            let data_type = DataType::Timestamp(TimeUnit::Second, timezone);
+            let array = Int64Array::from(&[Some(i64::MAX), Some(0), None]).to(data_type);
+
+            let result = cast(&array, &DataType::Utf8).unwrap();
+            assert!(result.is_null(0));
+            assert!(result.is_valid(1));
+            assert!(cast_with_options(&array, &DataType::Utf8, checked).is_err());
+
+            let result = timestamp_to_utf8_with_format::<i32>(&array, "%Y", true).unwrap();
+            assert_eq!(result, Utf8Array::<i32>::from(&[None, Some("1970"), None]));
+            assert!(timestamp_to_utf8_with_format::<i32>(&array, "%Y", false).is_err());
+        }
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+        let array = Int64Array::from_slice(&[i64::MIN, 0]).to(data_type);
+        let result = cast(&array, &DataType::Date32).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(&[None, Some(0)]).to(DataType::Date32) as &dyn Array
+        );
+        assert!(cast_with_options(&array, &DataType::Date64, checked).is_err());
+    }
+
+    #[test]
+    fn test_cast_with_options_safe() {
+        let checked = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+
+        let array = Int32Array::from(&[Some(1), None, Some(-5)]);
+        let result = cast_with_options(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+        let expected = UInt8Array::from(&[Some(1), None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        let error = cast_with_options(&array, &DataType::UInt8, checked).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: Cannot cast value -5 at row 2 from Int32 to UInt8"
+        );
+
+        let array = Utf8Array::<i64>::from(&[Some("2021-01-01"), None, Some("2021-13-01")]);
+        assert!(cast_with_options(&array, &DataType::Date32, checked).is_err());
+        let result = cast_with_options(&array.slice(0, 2), &DataType::Date32, checked).unwrap();
+        let expected = Int32Array::from(&[Some(18628), None]).to(DataType::Date32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let array = BinaryArray::<i32>::from(&[Some(b"a".as_ref()), Some(&[0xff])]);
+        let error = cast_with_options(&array, &DataType::Utf8, checked).unwrap_err();
+        assert!(error.to_string().contains("at row 1"));
+
+        // options are applied to nested values
+        let array = Utf8Array::<i32>::from_slice(&["1", "a"]);
+        let to_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32));
+        assert!(cast_with_options(&array, &to_type, checked).is_err());
+        let to_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        assert!(cast_with_options(&array, &to_type, checked).is_err());
+    }
+
+    #[test]
+    fn test_cast_with_options_safe_nested() -> Result<()> {
+        let checked = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+
+        // values of null lists are not cast, and errors name the row of the list
+        let values = Int32Array::from_slice(&[1, -5, 2, 300]);
+        let from_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        let to_type = ListArray::<i32>::default_datatype(DataType::UInt8);
+        let validity = Bitmap::from([true, false, true]);
+        let array = ListArray::<i32>::from_data(
+            from_type.clone(),
+            Buffer::from(&[0, 1, 2, 4]),
+            Arc::new(values.clone()),
+            Some(validity.clone()),
+        );
+        let error = cast_with_options(&array, &to_type, checked).unwrap_err();
+        assert!(error.to_string().contains("list at row 2"));
+        assert!(error.to_string().contains("value 300"));
+
+        let array = ListArray::<i32>::from_data(
+            from_type,
+            Buffer::from(&[0, 1, 2, 3]),
+            Arc::new(values.slice(0, 3)),
+            Some(validity.clone()),
+        );
+        let result = cast_with_options(&array, &to_type, checked)?;
+        let expected = ListArray::<i32>::from_data(
+            to_type,
+            Buffer::from(&[0, 1, 2, 3]),
+            Arc::new(UInt8Array::from(&[Some(1), None, Some(2)])),
+            Some(validity),
+        );
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        // values of a dictionary without keys are not cast, and errors name the row of the key
+        let values: Arc<dyn Array> = Arc::new(Utf8Array::<i32>::from_slice(&["1", "a", "3"]));
+        let keys = Int8Array::from(&[Some(0), Some(2), None]);
+        let array = DictionaryArray::<i8>::from_data(keys, values.clone());
+        let result = cast_with_options(&array, &DataType::Int32, checked)?;
+        let expected = Int32Array::from(&[Some(1), Some(3), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        let to_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32));
+        let result = cast_with_options(&array, &to_type, checked)?;
+        let expected = Int32Array::from(&[Some(1), Some(3), None]);
+        assert_eq!(
+            cast(result.as_ref(), &DataType::Int32)?.as_ref(),
+            &expected as &dyn Array
+        );
+
+        let keys = Int8Array::from(&[Some(2), Some(1)]);
+        let array = DictionaryArray::<i8>::from_data(keys, values);
+        let error = cast_with_options(&array, &DataType::Int32, checked).unwrap_err();
+        assert!(error.to_string().contains("\"a\" at row 1"));
+        let error = cast_with_options(&array, &to_type, checked).unwrap_err();
+        assert!(error.to_string().contains("\"a\" at row 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_with_options_wrapped_and_round() {
+        let array = Float64Array::from(&[Some(1.5), Some(-2.5), Some(300.7), Some(f64::NAN)]);
+
+        let result = cast_with_options(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+        let expected = UInt8Array::from(&[Some(1), None, None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            round: true,
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &DataType::Int16, options).unwrap();
+        let expected = Int16Array::from(&[Some(2), Some(-3), Some(301), None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        // floats are not rounded when cast to floats
+        let result = cast_with_options(&array, &DataType::Float32, options).unwrap();
+        let result = result.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(result.values().as_slice()[..3], [1.5, -2.5, 300.7]);
+        let floats = Float32Array::from_slice(&[1.5, -0.5]);
+        let result = cast_with_options(&floats, &DataType::Float64, options).unwrap();
+        let expected = Float64Array::from_slice(&[1.5, -0.5]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            wrapped: true,
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &DataType::UInt8, options).unwrap();
+        let expected = UInt8Array::from_slice(&[1, 0, 255, 0]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let array = Int32Array::from_slice(&[255, 256, -1]);
+        let result = cast_with_options(&array, &DataType::UInt8, options).unwrap();
+        let expected = UInt8Array::from_slice(&[255, 0, 255]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            safe: false,
+            round: true,
+            wrapped: false,
+        };
+        let array = Float32Array::from_slice(&[126.5, 127.5]);
+        let error = cast_with_options(&array, &DataType::Int8, options).unwrap_err();
+        assert!(error.to_string().contains("127.5 at row 1"));
+    }
+
+    #[test]
+    fn test_cast_decimal_to_decimal() {
+        let array = Int128Array::from(&[Some(12345), Some(-12355), None, Some(99999)])
+            .to(DataType::Decimal(5, 2));
+
+        let result = cast(&array, &DataType::Decimal(6, 1)).unwrap();
+        let expected = Int128Array::from(&[Some(1234), Some(-1235), None, Some(9999)])
+            .to(DataType::Decimal(6, 1));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            round: true,
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &DataType::Decimal(4, 1), options).unwrap();
+        let expected =
+            Int128Array::from(&[Some(1235), Some(-1236), None, None]).to(DataType::Decimal(4, 1));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = cast(&array, &DataType::Decimal(6, 3)).unwrap();
+        let expected = Int128Array::from(&[Some(123450), Some(-123550), None, Some(999990)])
+            .to(DataType::Decimal(6, 3));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let error = cast_with_options(&array, &DataType::Decimal(5, 3), options).unwrap_err();
+        assert!(error.to_string().contains("12345 at row 0"));
+    }
+
+    #[test]
+    fn test_cast_decimal_numeric() {
+        let array = Int128Array::from(&[Some(12345), Some(-12355), None, Some(99999)])
+            .to(DataType::Decimal(5, 2));
+
+        let result = cast(&array, &DataType::Int8).unwrap();
+        let expected = Int8Array::from(&[Some(123), Some(-123), None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            round: true,
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &DataType::UInt16, options).unwrap();
+        let expected = UInt16Array::from(&[Some(123), None, None, Some(1000)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = cast(&array, &DataType::Float64).unwrap();
+        let expected = Float64Array::from(&[Some(123.45), Some(-123.55), None, Some(999.99)]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let array = Int32Array::from(&[Some(1), Some(-999), None, Some(1000)]);
+        let result = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(100), Some(-99900), None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let array = Float64Array::from(&[Some(0.29), Some(-1.005), Some(f64::NAN), Some(1e10)]);
+        let result = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(29), Some(-100), None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = cast_with_options(&array, &DataType::Decimal(5, 2), options).unwrap();
+        let expected =
+            Int128Array::from(&[Some(29), Some(-101), None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_cast_decimal_utf8() {
+        let array = Utf8Array::<i32>::from(&[Some("123.45"), Some("-0.5"), None, Some("1.2.3")]);
+        let result = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        let expected =
+            Int128Array::from(&[Some(12345), Some(-50), None, None]).to(DataType::Decimal(5, 2));
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = cast(result.as_ref(), &DataType::LargeUtf8).unwrap();
+        let expected = Utf8Array::<i64>::from(&[Some("123.45"), Some("-0.50"), None, None]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let error = cast_with_options(&array, &DataType::Decimal(5, 2), options).unwrap_err();
+        assert!(error.to_string().contains("\"1.2.3\" at row 3"));
+
+        assert!(can_cast_types(
+            &DataType::LargeUtf8,
+            &DataType::Decimal(5, 2)
+        ));
+        assert!(can_cast_types(&DataType::Decimal(5, 2), &DataType::Utf8));
+        assert!(can_cast_types(
+            &DataType::Decimal(5, 2),
+            &DataType::Decimal(10, 0)
+        ));
+        assert!(can_cast_types(&DataType::Float32, &DataType::Decimal(5, 2)));
+        assert!(!can_cast_types(&DataType::Decimal(5, 2), &DataType::Date32));
+    }
+
+    #[test]
+    fn test_cast_list_to_large_list() {
+        let values = Int32Array::from_slice(&[1, 2, 3]);
+        let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        let array = ListArray::<i32>::from_data(
+            data_type,
+            Buffer::from(&[0, 2, 2, 3]),
+            Arc::new(values),
+            Some(Bitmap::from([true, false, true])),
+        );
+
+        let to_type = ListArray::<i64>::default_datatype(DataType::Utf8);
+        let result = cast(&array, &to_type).unwrap();
+        let expected = ListArray::<i64>::from_data(
+            to_type,
+            Buffer::from(&[0i64, 2, 2, 3]),
+            Arc::new(Utf8Array::<i32>::from_slice(&["1", "2", "3"])),
+            Some(Bitmap::from([true, false, true])),
+        );
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let to_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        let result = cast(result.as_ref(), &to_type).unwrap();
+        let result = cast(result.as_ref(), &array.data_type().clone()).unwrap();
+        assert_eq!(result.as_ref(), &array as &dyn Array);
+    }
+
+    #[test]
+    fn test_cast_list_fixed_size_list() {
+        let values = Int32Array::from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+        let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        let array = ListArray::<i32>::from_data(
+            data_type,
+            Buffer::from(&[0, 2, 4, 5, 7]),
+            Arc::new(values),
+            Some(Bitmap::from([true, true, false, true])),
+        );
+
+        // the null list of length 1 is padded
+        let to_type = FixedSizeListArray::default_datatype(DataType::Int64, 2);
+        let result = cast(&array, &to_type).unwrap();
+        let values = Int64Array::from(&[
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            None,
+            None,
+            Some(6),
+            Some(7),
+        ]);
+        let expected = FixedSizeListArray::from_data(
+            to_type.clone(),
+            Arc::new(values),
+            Some(Bitmap::from([true, true, false, true])),
+        );
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        // lists of all sizes are sliced
+        let result = cast(&array.slice(1, 1), &to_type).unwrap();
+        assert_eq!(result.as_ref(), &expected.slice(1, 1) as &dyn Array);
+
+        let to_type = ListArray::<i64>::default_datatype(DataType::Int32);
+        let result = cast(&expected, &to_type).unwrap();
+        let list = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        assert_eq!(list.offsets().as_slice(), &[0, 2, 4, 6, 8]);
+        assert_eq!(list.validity(), expected.validity());
+
+        // lists of the wrong size are null, or an error
+        let to_type = FixedSizeListArray::default_datatype(DataType::Int32, 3);
+        let result = cast(&array, &to_type).unwrap();
+        assert_eq!(result.null_count(), 4);
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let error = cast_with_options(&array, &to_type, options).unwrap_err();
+        assert!(error.to_string().contains("length 2 at row 0"));
+
+        assert!(!can_cast_types(
+            &FixedSizeListArray::default_datatype(DataType::Int32, 2),
+            &to_type
+        ));
+    }
+
+    #[test]
+    fn test_cast_struct() {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let array = StructArray::from_data(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(&[Some(1), None])),
+                Arc::new(Utf8Array::<i32>::from(&[Some("x"), Some("y")])),
+            ],
+            Some(Bitmap::from([true, false])),
+        );
+
+        let to_fields = vec![
+            Field::new("b", DataType::LargeUtf8, true),
+            Field::new("c", DataType::Int8, true),
+            Field::new("a", DataType::Int64, true),
+        ];
+        let to_type = DataType::Struct(to_fields.clone());
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let result = cast(&array, &to_type).unwrap();
+        let expected = StructArray::from_data(
+            to_fields,
+            vec![
+                Arc::new(Utf8Array::<i64>::from(&[Some("x"), Some("y")])),
+                Arc::new(Int8Array::from(&[None, None])),
+                Arc::new(Int64Array::from(&[Some(1), None])),
+            ],
+            Some(Bitmap::from([true, false])),
+        );
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        // missing non-nullable fields and uncastable fields are not supported
+        let to_type = DataType::Struct(vec![Field::new("c", DataType::Int8, false)]);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        assert!(cast(&array, &to_type).is_err());
+        let to_type = DataType::Struct(vec![Field::new("a", DataType::Binary, true)]);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        assert!(cast(&array, &to_type).is_err());
+        let to_type = DataType::Struct(vec![]);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        assert!(cast(&array, &to_type).is_err());
+    }
+
     /*
     #[test]
     fn test_cast_list_i32_to_list_u16() {
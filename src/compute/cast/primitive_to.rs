@@ -1,14 +1,26 @@
+use std::fmt::Write;
 use std::hash::Hash;
 
+use chrono::format::{Item, StrftimeItems};
+use chrono::Datelike;
+
+use num::cast::AsPrimitive;
+
 use crate::{
     array::*,
     bitmap::Bitmap,
     compute::arity::unary,
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
-    types::NativeType,
+    types::{NativeType, NaturalDataType},
+};
+use crate::{
+    error::{ArrowError, Result},
+    util::lexical_to_string,
 };
-use crate::{error::Result, util::lexical_to_string};
+
+use super::decimal_to::{fits_precision, parse_decimal, rescale};
+use super::{cast_values, CastOptions};
 
 /// Returns a [`BooleanArray`] where every element is different from zero.
 /// Validity is preserved.
@@ -48,13 +60,41 @@ where
 pub(super) fn primitive_to_primitive_dyn<I, O>(
     from: &dyn Array,
     to_type: &DataType,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
-    I: NativeType + num::NumCast,
-    O: NativeType + num::NumCast,
+    I: NativeType + num::NumCast + AsPrimitive<O>,
+    O: NativeType + num::NumCast + NaturalDataType,
+    f64: AsPrimitive<O>,
 {
     let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
-    Ok(Box::new(primitive_to_primitive::<I, O>(from, to_type)))
+    let from_type = from.data_type();
+    let round = options.round
+        && matches!(from_type, DataType::Float32 | DataType::Float64)
+        && matches!(
+            to_type,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+        );
+    let iter = from.iter().map(|x| x.copied());
+
+    let array = match (round, options.wrapped) {
+        (false, false) => cast_values(iter, from_type, to_type, options, num::cast::<I, O>),
+        (false, true) => cast_values(iter, from_type, to_type, options, |x| Some(x.as_())),
+        (true, false) => cast_values(iter, from_type, to_type, options, |x| {
+            num::cast::<I, f64>(x).and_then(|x| num::cast::<f64, O>(x.round()))
+        }),
+        (true, true) => cast_values(iter, from_type, to_type, options, |x| {
+            num::cast::<I, f64>(x).map(|x| x.round().as_())
+        }),
+    }?;
+    Ok(Box::new(array))
 }
 
 /// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of another physical type via numeric conversion.
@@ -72,6 +112,77 @@ where
     PrimitiveArray::<O>::from_trusted_len_iter(iter).to(to_type.clone())
 }
 
+/// Casts an integer [`PrimitiveArray`] to a Decimal(`precision`, `scale`) [`PrimitiveArray`].
+/// Values that do not fit `precision` are handled according to [`CastOptions::safe`].
+/// # Errors
+/// This function errors iff `options.safe` is false and a value does not fit `precision`.
+pub fn integer_to_decimal<T>(
+    from: &PrimitiveArray<T>,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>>
+where
+    T: NativeType + num::NumCast,
+{
+    let to_type = DataType::Decimal(precision, scale);
+    let iter = from.iter().map(|x| x.copied());
+    cast_values(iter, from.data_type(), &to_type, options, |x| {
+        num::cast::<T, i128>(x)
+            .and_then(|x| rescale(x, 0, scale, false))
+            .filter(|x| fits_precision(*x, precision))
+    })
+}
+
+pub(super) fn integer_to_decimal_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + num::NumCast,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    integer_to_decimal::<T>(from, precision, scale, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Casts a float [`PrimitiveArray`] to a Decimal(`precision`, `scale`) [`PrimitiveArray`].
+/// Floats are converted from their shortest decimal representation, so that e.g. `0.29`
+/// becomes `0.29` and not `0.28` with scale 2. Digits beyond `scale` are handled as described
+/// in [`CastOptions::round`]; NaNs, infinities and values that do not fit `precision` are
+/// handled according to [`CastOptions::safe`].
+/// # Errors
+/// This function errors iff `options.safe` is false and a value cannot be cast.
+pub fn float_to_decimal<T>(
+    from: &PrimitiveArray<T>,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>>
+where
+    T: NativeType + std::fmt::Display,
+{
+    let to_type = DataType::Decimal(precision, scale);
+    let iter = from.iter().map(|x| x.copied());
+    cast_values(iter, from.data_type(), &to_type, options, |x| {
+        parse_decimal(&x.to_string(), precision, scale, options.round)
+    })
+}
+
+pub(super) fn float_to_decimal_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + std::fmt::Display,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    float_to_decimal::<T>(from, precision, scale, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
 /// Cast [`PrimitiveArray`] to a [`PrimitiveArray`] of the same physical type.
 /// This is O(1).
 pub fn primitive_to_same_primitive<T>(
@@ -185,6 +296,266 @@ pub fn timestamp_to_date32(
     unary(from, |x| (x / from_size) as i32, DataType::Date32)
 }
 
+/// Returns `from` where the timestamps in `time_unit` that are out of the range of dates of
+/// `chrono` are null when `safe` is true.
+/// # Errors
+/// This function errors iff `safe` is false and a timestamp is out of range.
+fn timestamps_in_range(
+    from: &PrimitiveArray<i64>,
+    time_unit: &TimeUnit,
+    safe: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let in_range = |x: &i64| timestamp_to_naive_datetime_opt(*x, time_unit).is_some();
+    match from.iter().flatten().find(|x| !in_range(x)) {
+        None => Ok(from.clone()),
+        Some(_) if safe => {
+            let iter = from.iter().map(|x| x.filter(|x| in_range(x)).copied());
+            Ok(PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(from.data_type().clone()))
+        }
+        Some(x) => Err(ArrowError::InvalidArgumentError(format!(
+            "Timestamp {} of type {:?} is out of the range of dates",
+            x,
+            from.data_type()
+        ))),
+    }
+}
+
+/// Returns the local date, in `timezone`, of every timestamp of `from`. Timestamps out of the
+/// range of dates are null when `safe` is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `timezone` is not a valid timezone, or `safe` is false and a
+/// timestamp is out of range.
+pub fn timestamp_tz_to_date32(
+    from: &PrimitiveArray<i64>,
+    from_unit: &TimeUnit,
+    timezone: &str,
+    safe: bool,
+) -> Result<PrimitiveArray<i32>> {
+    let from = timestamps_in_range(from, from_unit, safe)?;
+    with_timezone!(timezone, |tz| {
+        let iter = from.iter().map(|x| {
+            x.map(|x| {
+                let datetime = timestamp_to_datetime(*x, from_unit, &tz).naive_local();
+                datetime.num_days_from_ce() - EPOCH_DAYS_FROM_CE
+            })
+        });
+        Ok(PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32))
+    })
+}
+
+/// Returns the local date, in `timezone`, of every timestamp of `from`, as milliseconds
+/// since the epoch. Timestamps out of the range of dates are null when `safe` is true, and an
+/// error otherwise.
+/// # Errors
+/// This function errors iff `timezone` is not a valid timezone, or `safe` is false and a
+/// timestamp is out of range.
+pub fn timestamp_tz_to_date64(
+    from: &PrimitiveArray<i64>,
+    from_unit: &TimeUnit,
+    timezone: &str,
+    safe: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let days = timestamp_tz_to_date32(from, from_unit, timezone, safe)?;
+    Ok(unary(
+        &days,
+        |x| x as i64 * MILLISECONDS_IN_DAY,
+        DataType::Date64,
+    ))
+}
+
+/// Returns a [`Utf8Array`] with the representation of every timestamp of `from`,
+/// e.g. `2021-01-02 03:04:05.006`. Timestamps out of the range of dates are null when `safe`
+/// is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `safe` is false and a timestamp is out of range.
+pub fn naive_timestamp_to_utf8<O: Offset>(
+    from: &PrimitiveArray<i64>,
+    time_unit: &TimeUnit,
+    safe: bool,
+) -> Result<Utf8Array<O>> {
+    let from = timestamps_in_range(from, time_unit, safe)?;
+    let iter = from
+        .iter()
+        .map(|x| x.map(|x| timestamp_to_naive_datetime(*x, time_unit).to_string()));
+    Ok(Utf8Array::from_trusted_len_iter(iter))
+}
+
+/// Returns a [`Utf8Array`] with the representation of every timestamp of `from` in its local
+/// time in `timezone`, e.g. `2021-01-02 03:04:05.006-05:00`. Timestamps out of the range of
+/// dates are null when `safe` is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `timezone` is not a valid timezone, or `safe` is false and a
+/// timestamp is out of range.
+pub fn timestamp_to_utf8<O: Offset>(
+    from: &PrimitiveArray<i64>,
+    time_unit: &TimeUnit,
+    timezone: &str,
+    safe: bool,
+) -> Result<Utf8Array<O>> {
+    let from = timestamps_in_range(from, time_unit, safe)?;
+    with_timezone!(timezone, |tz| {
+        let iter = from.iter().map(|x| {
+            x.map(|x| {
+                timestamp_to_datetime(*x, time_unit, &tz)
+                    .format("%Y-%m-%d %H:%M:%S%.f%:z")
+                    .to_string()
+            })
+        });
+        Ok(Utf8Array::from_trusted_len_iter(iter))
+    })
+}
+
+/// Parses a strftime `format` into its items.
+fn strftime_items(format: &str) -> Result<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|x| matches!(x, Item::Error)) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid strftime format \"{}\"",
+            format
+        )));
+    }
+    Ok(items)
+}
+
+/// Returns a [`Utf8Array`] with every value of `from` formatted by `op` with the items of the
+/// strftime `format`.
+fn format_with<O, T, F>(from: &PrimitiveArray<T>, format: &str, op: F) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    T: NativeType,
+    F: Fn(T, std::slice::Iter<Item>, &mut String) -> std::fmt::Result,
+{
+    let items = strftime_items(format)?;
+    let mut array = MutableUtf8Array::<O>::with_capacity(from.len());
+    let mut scratch = String::new();
+    for value in from.iter() {
+        match value {
+            Some(value) => {
+                scratch.clear();
+                op(*value, items.iter(), &mut scratch).map_err(|_| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Unable to format {:?} of type {:?} with format \"{}\"",
+                        value,
+                        from.data_type(),
+                        format
+                    ))
+                })?;
+                array.try_push(Some(&scratch))?;
+            }
+            None => array.try_push::<&str>(None)?,
+        }
+    }
+    Ok(array.into())
+}
+
+/// Returns a [`Utf8Array`] with every timestamp of `from` formatted with the strftime `format`.
+/// Timestamps with a timezone are formatted in their local time, and `format` may refer to
+/// their offset, e.g. with `%z`. Timestamps out of the range of dates are null when `safe` is
+/// true, and an error otherwise.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::cast::timestamp_to_utf8_with_format;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// let array = Int64Array::from(&[Some(0), None]).to(data_type);
+/// let result = timestamp_to_utf8_with_format::<i32>(&array, "%d/%m/%Y %H:%M %z", true).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("01/01/1970 01:00 +0100"), None]));
+/// ```
+/// # Errors
+/// This function errors iff `from` is not a timestamp array, its timezone is invalid, `format`
+/// is not a valid strftime format, `format` refers to an offset and `from` has no timezone, or
+/// `safe` is false and a timestamp is out of range.
+pub fn timestamp_to_utf8_with_format<O: Offset>(
+    from: &PrimitiveArray<i64>,
+    format: &str,
+    safe: bool,
+) -> Result<Utf8Array<O>> {
+    match from.data_type() {
+        DataType::Timestamp(time_unit, None) => {
+            let from = timestamps_in_range(from, time_unit, safe)?;
+            format_with(&from, format, |x, items, buffer| {
+                let datetime = timestamp_to_naive_datetime(x, time_unit);
+                write!(buffer, "{}", datetime.format_with_items(items))
+            })
+        }
+        DataType::Timestamp(time_unit, Some(timezone)) => {
+            let from = timestamps_in_range(from, time_unit, safe)?;
+            with_timezone!(timezone, |tz| format_with(
+                &from,
+                format,
+                |x, items, buffer| {
+                    let datetime = timestamp_to_datetime(x, time_unit, &tz);
+                    write!(buffer, "{}", datetime.format_with_items(items))
+                }
+            ))
+        }
+        dt => Err(ArrowError::InvalidArgumentError(format!(
+            "\"timestamp_to_utf8_with_format\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Returns a [`Utf8Array`] with every date of `from` formatted with the strftime `format`.
+/// # Errors
+/// This function errors iff `format` is not a valid strftime format or refers to time fields.
+pub fn date32_to_utf8<O: Offset>(from: &PrimitiveArray<i32>, format: &str) -> Result<Utf8Array<O>> {
+    format_with(from, format, |x, items, buffer| {
+        write!(buffer, "{}", date32_to_date(x).format_with_items(items))
+    })
+}
+
+/// Returns a [`Utf8Array`] with every date of `from` formatted with the strftime `format`.
+/// # Errors
+/// This function errors iff `format` is not a valid strftime format or refers to an offset.
+pub fn date64_to_utf8<O: Offset>(from: &PrimitiveArray<i64>, format: &str) -> Result<Utf8Array<O>> {
+    format_with(from, format, |x, items, buffer| {
+        write!(buffer, "{}", date64_to_datetime(x).format_with_items(items))
+    })
+}
+
+/// Returns a [`Utf8Array`] with every time of `from` formatted with the strftime `format`.
+/// # Errors
+/// This function errors iff `from` is not a `Time32` array or `format` is not a valid strftime
+/// format or refers to date fields.
+pub fn time32_to_utf8<O: Offset>(from: &PrimitiveArray<i32>, format: &str) -> Result<Utf8Array<O>> {
+    let op = match from.data_type() {
+        DataType::Time32(TimeUnit::Second) => time32s_to_time,
+        DataType::Time32(TimeUnit::Millisecond) => time32ms_to_time,
+        dt => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "\"time32_to_utf8\" does not support type {:?}",
+                dt
+            )))
+        }
+    };
+    format_with(from, format, |x, items, buffer| {
+        write!(buffer, "{}", op(x).format_with_items(items))
+    })
+}
+
+/// Returns a [`Utf8Array`] with every time of `from` formatted with the strftime `format`.
+/// # Errors
+/// This function errors iff `from` is not a `Time64` array or `format` is not a valid strftime
+/// format or refers to date fields.
+pub fn time64_to_utf8<O: Offset>(from: &PrimitiveArray<i64>, format: &str) -> Result<Utf8Array<O>> {
+    let op = match from.data_type() {
+        DataType::Time64(TimeUnit::Microsecond) => time64us_to_time,
+        DataType::Time64(TimeUnit::Nanosecond) => time64ns_to_time,
+        dt => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "\"time64_to_utf8\" does not support type {:?}",
+                dt
+            )))
+        }
+    };
+    format_with(from, format, |x, items, buffer| {
+        write!(buffer, "{}", op(x).format_with_items(items))
+    })
+}
+
 pub fn time32_to_time64(
     from: &PrimitiveArray<i32>,
     from_unit: &TimeUnit,
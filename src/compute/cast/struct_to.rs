@@ -0,0 +1,67 @@
+use crate::{
+    array::*,
+    datatypes::{DataType, Field},
+    error::{ArrowError, Result},
+};
+
+use super::{can_cast_types, cast_with_options, CastOptions};
+
+/// Returns whether a struct with `from` fields can be cast to a struct with `to` fields.
+pub(super) fn can_cast_fields(from: &[Field], to: &[Field]) -> bool {
+    // a struct must have at least one field
+    !to.is_empty()
+        && to.iter().all(
+            |to| match from.iter().find(|from| from.name() == to.name()) {
+                Some(from) => can_cast_types(from.data_type(), to.data_type()),
+                None => to.is_nullable(),
+            },
+        )
+}
+
+/// Casts a [`StructArray`] to a [`StructArray`] of `to_type`, matching fields by name.
+/// Fields of `to_type` are cast from the field of `array` with the same name, in the order of
+/// `to_type`; fields missing from `array` are null, and fields not in `to_type` are dropped.
+/// # Errors
+/// This function errors iff `to_type` has no fields, a field cannot be cast, or a non-nullable
+/// field of `to_type` is missing from `array`.
+pub fn cast_struct(
+    array: &StructArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<StructArray> {
+    let fields = array.fields();
+    let to_fields = StructArray::get_fields(to_type);
+    if to_fields.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot cast {:?} to a struct without fields",
+            array.data_type()
+        )));
+    }
+
+    let values = to_fields
+        .iter()
+        .map(
+            |to| match fields.iter().position(|x| x.name() == to.name()) {
+                Some(index) => {
+                    cast_with_options(array.values()[index].as_ref(), to.data_type(), options)
+                        .map(|x| x.into())
+                }
+                None if to.is_nullable() => {
+                    Ok(new_null_array(to.data_type().clone(), array.len()).into())
+                }
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot cast {:?} to {:?}: the non-nullable field \"{}\" is missing",
+                    array.data_type(),
+                    to_type,
+                    to.name()
+                ))),
+            },
+        )
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(StructArray::from_data(
+        to_fields.to_vec(),
+        values,
+        array.validity().clone(),
+    ))
+}
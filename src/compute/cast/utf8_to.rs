@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use chrono::Datelike;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
 
 use crate::{
     array::*,
@@ -10,10 +10,12 @@ use crate::{
 };
 use crate::{
     error::{ArrowError, Result},
-    temporal_conversions::EPOCH_DAYS_FROM_CE,
+    temporal_conversions::*,
+    types::NaturalDataType,
 };
 
-use super::utf8_to_timestamp_ns_scalar;
+use super::decimal_to::parse_decimal;
+use super::{cast_values, utf8_to_timestamp_ns_scalar, CastOptions};
 
 /// Casts a [`Utf8Array`] to a [`PrimitiveArray`], making any uncastable value a Null.
 pub fn utf8_to_primitive<O: Offset, T>(from: &Utf8Array<O>, to: &DataType) -> PrimitiveArray<T>
@@ -30,46 +32,102 @@ where
 pub(super) fn utf8_to_primitive_dyn<O: Offset, T>(
     from: &dyn Array,
     to: &DataType,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>>
 where
-    T: NativeType + lexical_core::FromLexical,
+    T: NativeType + NaturalDataType + lexical_core::FromLexical,
 {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_primitive::<O, T>(from, to)))
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let array = cast_values(from.iter(), from.data_type(), to, options, |x| {
+        lexical_core::parse::<T>(x.as_bytes()).ok()
+    })?;
+    Ok(Box::new(array))
+}
+
+#[inline]
+fn parse_date32(value: &str) -> Option<i32> {
+    value
+        .parse::<chrono::NaiveDate>()
+        .ok()
+        .map(|x| x.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
 }
 
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
-    let iter = from.iter().map(|x| {
-        x.and_then(|x| {
-            x.parse::<chrono::NaiveDate>()
-                .ok()
-                .map(|x| x.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
-        })
-    });
+    let iter = from.iter().map(|x| x.and_then(parse_date32));
     PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
 }
 
-pub(super) fn utf8_to_date32_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date32::<O>(from)))
+pub(super) fn utf8_to_date32_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let array = cast_values(
+        from.iter(),
+        from.data_type(),
+        &DataType::Date32,
+        options,
+        parse_date32,
+    )?;
+    Ok(Box::new(array))
+}
+
+#[inline]
+fn parse_date64(value: &str) -> Option<i64> {
+    value
+        .parse::<chrono::NaiveDateTime>()
+        .ok()
+        .map(|x| x.and_utc().timestamp_millis())
 }
 
 /// Casts a [`Utf8Array`] to a Date64 primitive, making any uncastable value a Null.
 pub fn utf8_to_date64<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
-    let iter = from.iter().map(|x| {
-        x.and_then(|x| {
-            x.parse::<chrono::NaiveDateTime>()
-                .ok()
-                .map(|x| x.timestamp_millis())
-        })
-    });
+    let iter = from.iter().map(|x| x.and_then(parse_date64));
     PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(DataType::Date64)
 }
 
-pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+pub(super) fn utf8_to_date64_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let array = cast_values(
+        from.iter(),
+        from.data_type(),
+        &DataType::Date64,
+        options,
+        parse_date64,
+    )?;
+    Ok(Box::new(array))
+}
+
+/// Casts a [`Utf8Array`] of decimal numbers such as `-123.45` to a Decimal(`precision`, `scale`)
+/// [`PrimitiveArray`]. Digits beyond `scale` are handled as described in
+/// [`CastOptions::round`]; values that are not decimal numbers or do not fit `precision` are
+/// handled according to [`CastOptions::safe`].
+/// # Errors
+/// This function errors iff `options.safe` is false and a value cannot be cast.
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<PrimitiveArray<i128>> {
+    let to_type = DataType::Decimal(precision, scale);
+    cast_values(from.iter(), from.data_type(), &to_type, options, |x| {
+        parse_decimal(x, precision, scale, options.round)
+    })
+}
+
+pub(super) fn utf8_to_decimal_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date64::<O>(from)))
+    utf8_to_decimal::<O>(from, precision, scale, options).map(|x| Box::new(x) as Box<dyn Array>)
 }
 
 pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
@@ -92,9 +150,19 @@ pub fn utf8_to_dictionary<O: Offset, K: DictionaryKey>(
     Ok(array.into())
 }
 
-pub(super) fn utf8_to_timestamp_ns_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_timestamp_ns::<O>(from)))
+pub(super) fn utf8_to_timestamp_ns_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let array = cast_values(
+        from.iter(),
+        from.data_type(),
+        &DataType::Timestamp(TimeUnit::Nanosecond, None),
+        options,
+        |x| utf8_to_timestamp_ns_scalar(x).ok(),
+    )?;
+    Ok(Box::new(array))
 }
 
 /// The array version of [`utf8_to_timestamp_ns_scalar`].
@@ -106,6 +174,180 @@ pub fn utf8_to_timestamp_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i6
         .to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
+/// Parses every value of `from` with `op`. Values that `op` cannot parse are null when `safe`
+/// is true, and an error otherwise.
+fn utf8_parse<O, T, F>(
+    from: &Utf8Array<O>,
+    format: &str,
+    safe: bool,
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + NaturalDataType,
+    F: Fn(&str) -> Option<T>,
+{
+    let array = if safe {
+        PrimitiveArray::<T>::from_trusted_len_iter(from.iter().map(|x| x.and_then(&op)))
+    } else {
+        from.iter()
+            .map(|x| {
+                x.map(|x| {
+                    op(x).ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "Unable to parse \"{}\" as {:?} with format \"{}\"",
+                            x, data_type, format
+                        ))
+                    })
+                })
+                .transpose()
+            })
+            // `Vec` stops at the first error, so that it is the one returned
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .collect::<PrimitiveArray<T>>()
+    };
+    Ok(array.to(data_type))
+}
+
+/// Parses every value of `from` with the strftime `format` into a timestamp in `time_unit`
+/// with timezone `timezone`.
+///
+/// Values whose format includes an offset (e.g. `%z`) are converted to UTC. Values without an
+/// offset are local times in `timezone` or, when it is `None`, naive timestamps. Local times
+/// that occur twice resolve to the earliest instant, and the ones that do not exist are invalid.
+///
+/// Invalid values are null when `safe` is true, and an error otherwise.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::cast::utf8_to_timestamp;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Utf8Array::<i32>::from(&[Some("01/01/1970 01:00"), Some("1970"), None]);
+/// let result =
+///     utf8_to_timestamp(&array, "%d/%m/%Y %H:%M", Some("+01:00"), TimeUnit::Second, true).unwrap();
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// assert_eq!(result, Int64Array::from(&[Some(0), None, None]).to(data_type));
+///
+/// assert!(utf8_to_timestamp(&array, "%d/%m/%Y %H:%M", None, TimeUnit::Second, false).is_err());
+/// ```
+/// # Errors
+/// This function errors iff `timezone` is invalid, or `safe` is false and a value is invalid.
+pub fn utf8_to_timestamp<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    timezone: Option<&str>,
+    time_unit: TimeUnit,
+    safe: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let data_type = DataType::Timestamp(time_unit.clone(), timezone.map(|x| x.to_string()));
+    match timezone {
+        Some(timezone) => with_timezone!(timezone, |tz| utf8_parse(
+            from,
+            format,
+            safe,
+            data_type,
+            |x| {
+                let datetime = match DateTime::parse_from_str(x, format) {
+                    Ok(datetime) => datetime.naive_utc(),
+                    Err(_) => {
+                        let local = NaiveDateTime::parse_from_str(x, format).ok()?;
+                        tz.from_local_datetime(&local).earliest()?.naive_utc()
+                    }
+                };
+                naive_datetime_to_timestamp(&datetime, &time_unit)
+            }
+        )),
+        None => utf8_parse(from, format, safe, data_type, |x| {
+            let datetime = match DateTime::parse_from_str(x, format) {
+                Ok(datetime) => datetime.naive_utc(),
+                Err(_) => NaiveDateTime::parse_from_str(x, format).ok()?,
+            };
+            naive_datetime_to_timestamp(&datetime, &time_unit)
+        }),
+    }
+}
+
+/// Parses every value of `from` with the strftime `format` into a `Date32`.
+/// Invalid values are null when `safe` is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `safe` is false and a value is invalid.
+pub fn utf8_to_date32_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    safe: bool,
+) -> Result<PrimitiveArray<i32>> {
+    utf8_parse(from, format, safe, DataType::Date32, |x| {
+        let date = NaiveDate::parse_from_str(x, format).ok()?;
+        Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+    })
+}
+
+/// Parses every value of `from` with the strftime `format` into a `Date64`.
+/// Invalid values are null when `safe` is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `safe` is false and a value is invalid.
+pub fn utf8_to_date64_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    safe: bool,
+) -> Result<PrimitiveArray<i64>> {
+    utf8_parse(from, format, safe, DataType::Date64, |x| {
+        let date = NaiveDate::parse_from_str(x, format).ok()?;
+        Some((date.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as i64 * MILLISECONDS_IN_DAY)
+    })
+}
+
+/// Parses every value of `from` with the strftime `format` into a `Time32` in `time_unit`,
+/// that must be seconds or milliseconds.
+/// Invalid values are null when `safe` is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `time_unit` is invalid, or `safe` is false and a value is invalid.
+pub fn utf8_to_time32<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    time_unit: TimeUnit,
+    safe: bool,
+) -> Result<PrimitiveArray<i32>> {
+    if !matches!(time_unit, TimeUnit::Second | TimeUnit::Millisecond) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Time32 does not support the time unit {:?}",
+            time_unit
+        )));
+    }
+    let data_type = DataType::Time32(time_unit.clone());
+    utf8_parse(from, format, safe, data_type, |x| {
+        let time = NaiveTime::parse_from_str(x, format).ok()?;
+        Some(naive_time_to_value(&time, &time_unit) as i32)
+    })
+}
+
+/// Parses every value of `from` with the strftime `format` into a `Time64` in `time_unit`,
+/// that must be microseconds or nanoseconds.
+/// Invalid values are null when `safe` is true, and an error otherwise.
+/// # Errors
+/// This function errors iff `time_unit` is invalid, or `safe` is false and a value is invalid.
+pub fn utf8_to_time64<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    time_unit: TimeUnit,
+    safe: bool,
+) -> Result<PrimitiveArray<i64>> {
+    if !matches!(time_unit, TimeUnit::Microsecond | TimeUnit::Nanosecond) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Time64 does not support the time unit {:?}",
+            time_unit
+        )));
+    }
+    let data_type = DataType::Time64(time_unit.clone());
+    utf8_parse(from, format, safe, data_type, |x| {
+        let time = NaiveTime::parse_from_str(x, format).ok()?;
+        Some(naive_time_to_value(&time, &time_unit))
+    })
+}
+
 pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
     let values = from.values().clone();
     let offsets = from.offsets().iter().map(|x| *x as i64);
@@ -17,6 +17,9 @@
 
 //! Defines basic comparison kernels for [`PrimitiveArray`]s.
 //!
+//! Nested types, dictionaries and [`FixedSizeBinaryArray`]s are compared using the natural
+//! order defined in [`crate::array::ord`].
+//!
 //! These kernels can leverage SIMD if available on your system.  Currently no runtime
 //! detection is provided, you should enable the specific SIMD intrinsics using
 //! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
@@ -25,9 +28,11 @@
 use crate::array::*;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
-use crate::types::days_ms;
+use crate::types::{days_ms, months_days_ns};
 
+mod binary;
 mod boolean;
+mod ord;
 mod primitive;
 mod utf8;
 
@@ -123,6 +128,17 @@ pub fn compare(lhs: &dyn Array, rhs: &dyn Array, operator: Operator) -> Result<B
                 .unwrap();
             primitive::compare(lhs, rhs, operator)
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let lhs = lhs
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let rhs = rhs
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            primitive::compare(lhs, rhs, operator)
+        }
         DataType::Utf8 => {
             let lhs = lhs.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
             let rhs = rhs.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
@@ -138,6 +154,26 @@ pub fn compare(lhs: &dyn Array, rhs: &dyn Array, operator: Operator) -> Result<B
             let rhs = rhs.as_any().downcast_ref::<Int128Array>().unwrap();
             primitive::compare(lhs, rhs, operator)
         }
+        DataType::Binary => {
+            let lhs = lhs.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary::compare(lhs, rhs, operator)
+        }
+        DataType::LargeBinary => {
+            let lhs = lhs.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            binary::compare(lhs, rhs, operator)
+        }
+        DataType::FixedSizeBinary(_)
+        | DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_)
+        | DataType::Dictionary(_, _)
+            if can_compare(data_type) =>
+        {
+            ord::compare(lhs, rhs, operator)
+        }
         _ => Err(ArrowError::NotYetImplemented(format!(
             "Comparison between {:?} is not supported",
             data_type
@@ -145,6 +181,7 @@ pub fn compare(lhs: &dyn Array, rhs: &dyn Array, operator: Operator) -> Result<B
     }
 }
 
+pub use binary::compare_scalar as binary_compare_scalar;
 pub use boolean::compare_scalar as boolean_compare_scalar;
 pub use primitive::compare_scalar as primitive_compare_scalar;
 pub(crate) use primitive::compare_values_op as primitive_compare_values_op;
@@ -162,33 +199,56 @@ pub use utf8::compare_scalar as utf8_compare_scalar;
 /// assert_eq!(can_compare(&data_type), true);
 ///
 /// let data_type = DataType::LargeBinary;
+/// assert_eq!(can_compare(&data_type), true);
+///
+/// let data_type = DataType::Null;
 /// assert_eq!(can_compare(&data_type), false)
 /// ```
 pub fn can_compare(data_type: &DataType) -> bool {
-    matches!(
-        data_type,
+    match data_type {
         DataType::Boolean
-            | DataType::Int8
-            | DataType::Int16
-            | DataType::Int32
-            | DataType::Date32
-            | DataType::Time32(_)
-            | DataType::Interval(_)
-            | DataType::Int64
-            | DataType::Timestamp(_, None)
-            | DataType::Date64
-            | DataType::Time64(_)
-            | DataType::Duration(_)
-            | DataType::UInt8
-            | DataType::UInt16
-            | DataType::UInt32
-            | DataType::UInt64
-            | DataType::Float32
-            | DataType::Float64
-            | DataType::Utf8
-            | DataType::LargeUtf8
-            | DataType::Decimal(_, _)
-    )
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(_)
+        | DataType::Int64
+        | DataType::Timestamp(_, None)
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_)
+        | DataType::Decimal(_, _) => true,
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            can_compare(field.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().all(|field| can_compare(field.data_type())),
+        DataType::Dictionary(key_type, value_type) => {
+            matches!(
+                key_type.as_ref(),
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+            ) && can_compare(value_type.as_ref())
+        }
+        _ => false,
+    }
 }
 
 #[cfg(test)]
@@ -199,7 +259,7 @@ mod tests {
     fn consistency() {
         use crate::array::new_null_array;
         use crate::datatypes::DataType::*;
-        use crate::datatypes::TimeUnit;
+        use crate::datatypes::{Field, TimeUnit};
 
         let datatypes = vec![
             Null,
@@ -228,6 +288,11 @@ mod tests {
             LargeUtf8,
             Binary,
             LargeBinary,
+            FixedSizeBinary(2),
+            Decimal(10, 2),
+            List(Box::new(Field::new("a", Utf8, true))),
+            Struct(vec![Field::new("a", Int32, true)]),
+            Dictionary(Box::new(Int32), Box::new(Utf8)),
             Duration(TimeUnit::Second),
             Duration(TimeUnit::Millisecond),
             Duration(TimeUnit::Microsecond),
@@ -0,0 +1,101 @@
+use std::cmp::Ordering;
+
+use crate::error::{ArrowError, Result};
+use crate::{array::*, bitmap::Bitmap};
+
+use super::{super::utils::combine_validities, Operator};
+
+/// Compares two arrays of the same type using the natural order defined in
+/// [`crate::array::ord`]. This is used for types without a specialized kernel, such as
+/// [`FixedSizeBinaryArray`], [`DictionaryArray`], [`ListArray`] and [`StructArray`].
+///
+/// Dictionaries are compared by their values, which are not materialized.
+pub fn compare(lhs: &dyn Array, rhs: &dyn Array, op: Operator) -> Result<BooleanArray> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform comparison operation on arrays of different length".to_string(),
+        ));
+    }
+    let comparator = ord::build_compare(lhs, rhs)?;
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = (0..lhs.len()).map(|i| {
+        // null slots may not point to a valid value (e.g. keys of a dictionary)
+        if !(lhs.is_valid(i) && rhs.is_valid(i)) {
+            return false;
+        }
+        let ordering = comparator(i, i);
+        match op {
+            Operator::Eq => ordering == Ordering::Equal,
+            Operator::Neq => ordering != Ordering::Equal,
+            Operator::Gt => ordering == Ordering::Greater,
+            Operator::GtEq => ordering != Ordering::Less,
+            Operator::Lt => ordering == Ordering::Less,
+            Operator::LtEq => ordering != Ordering::Greater,
+        }
+    });
+    // Safety: `0..lhs.len()` is TrustedLen
+    let values = unsafe { Bitmap::from_trusted_len_iter_unchecked(values) };
+
+    Ok(BooleanArray::from_data(values, validity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::DataType;
+
+    #[test]
+    fn fixed_size_binary() -> Result<()> {
+        let data_type = DataType::FixedSizeBinary(2);
+        let lhs = FixedSizeBinaryArray::from_data(
+            data_type.clone(),
+            vec![1, 2, 3, 4, 5, 6].into(),
+            Some([true, true, false].into()),
+        );
+        let rhs = FixedSizeBinaryArray::from_data(data_type, vec![1, 2, 3, 5, 0, 0].into(), None);
+
+        let result = compare(&lhs, &rhs, Operator::Eq)?;
+        assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+        let result = compare(&lhs, &rhs, Operator::Lt)?;
+        assert_eq!(result, BooleanArray::from(&[Some(false), Some(true), None]));
+        Ok(())
+    }
+
+    #[test]
+    fn list() -> Result<()> {
+        let mut lhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        lhs.try_extend(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            None,
+        ])?;
+        let lhs: ListArray<i32> = lhs.into();
+        let mut rhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        rhs.try_extend(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), None]),
+            Some(vec![]),
+        ])?;
+        let rhs: ListArray<i32> = rhs.into();
+
+        let result = compare(&lhs, &rhs, Operator::GtEq)?;
+        assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary() -> Result<()> {
+        let mut lhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        lhs.try_extend(vec![Some("a"), Some("b"), None])?;
+        let lhs: DictionaryArray<i32> = lhs.into();
+        let mut rhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        rhs.try_extend(vec![Some("b"), Some("b"), Some("a")])?;
+        let rhs: DictionaryArray<i32> = rhs.into();
+
+        let result = compare(&lhs, &rhs, Operator::Eq)?;
+        assert_eq!(result, BooleanArray::from(&[Some(false), Some(true), None]));
+        Ok(())
+    }
+}
@@ -24,7 +24,7 @@ use crate::{
 use crate::{array::*, bitmap::Bitmap, types::NativeType};
 use crate::{
     bitmap::{utils::SlicesIterator, MutableBitmap},
-    types::days_ms,
+    types::{days_ms, months_days_ns},
 };
 use crate::{buffer::MutableBuffer, error::Result};
 
@@ -144,6 +144,9 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_build_filter!(days_ms, array, filter_count, chunks)
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            dyn_build_filter!(months_days_ns, array, filter_count, chunks)
+        }
         DataType::Float32 => {
             dyn_build_filter!(f32, array, filter_count, chunks)
         }
@@ -230,6 +233,10 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<days_ms>(array, filter)))
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_primitive::<months_days_ns>(array, filter)))
+        }
         DataType::Float32 => {
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<f32>(array, filter)))
@@ -19,11 +19,14 @@ macro_rules! new_hasher {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
+        FixedSizeListArray, ListArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+    },
     buffer::Buffer,
     datatypes::{DataType, IntervalUnit},
     error::{ArrowError, Result},
-    types::{days_ms, NativeType},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 use super::arity::unary;
@@ -98,6 +101,7 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         | DataType::Time32(_)
         | DataType::Interval(IntervalUnit::YearMonth) => hash_dyn!(i32, array),
         DataType::Interval(IntervalUnit::DayTime) => hash_dyn!(days_ms, array),
+        DataType::Interval(IntervalUnit::MonthDayNano) => hash_dyn!(months_days_ns, array),
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
@@ -163,10 +167,427 @@ pub fn can_hash(data_type: &DataType) -> bool {
     )
 }
 
+/// The algorithm used by [`hash_columns_with_options`] to hash rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HashMode {
+    /// The hasher used by [`hash`]. It is fast, but its output may differ between versions of
+    /// this crate and between platforms.
+    #[default]
+    Fast,
+    /// [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) over the little-endian bytes of
+    /// the values, followed by the finalizer of MurmurHash3. Its output does not change between
+    /// versions nor platforms, so it can be persisted (e.g. to assign rows to buckets).
+    Stable,
+}
+
+/// Options to configure [`hash_columns_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HashOptions {
+    /// The initial hash of every row, combined with the hash of every column.
+    pub seed: u64,
+    /// The algorithm used to hash rows.
+    pub mode: HashMode,
+}
+
+/// The hash of a null slot, independent of its data type.
+const NULL_HASH: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// The hasher of [`HashMode::Stable`].
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut hash = self.0;
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        hash ^ (hash >> 33)
+    }
+}
+
+// values are always written as explicit little-endian bytes, as the `Hash` implementations of
+// integers and slices depend on the endianness and pointer width of the platform.
+fn hash_bytes<H: Hasher, F: Fn() -> H>(new: &F, bytes: &[u8]) -> u64 {
+    let mut hasher = new();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn hash_hashes<H: Hasher, F: Fn() -> H, I: Iterator<Item = u64>>(new: &F, hashes: I) -> u64 {
+    let mut hasher = new();
+    hashes.for_each(|x| hasher.write(&x.to_le_bytes()));
+    hasher.finish()
+}
+
+fn primitive_rows<T: NativeType, H: Hasher, F: Fn() -> H>(array: &dyn Array, new: &F) -> Vec<u64> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, x.to_le_bytes().as_ref())))
+        .collect()
+}
+
+fn list_rows<O: Offset, H: Hasher, F: Fn() -> H>(array: &dyn Array, new: &F) -> Result<Vec<u64>> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let values = rows(array.values().as_ref(), new)?;
+    Ok(array
+        .offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(row, offsets)| {
+            if array.is_valid(row) {
+                let range = offsets[0].to_usize()..offsets[1].to_usize();
+                hash_hashes(new, values[range].iter().copied())
+            } else {
+                NULL_HASH
+            }
+        })
+        .collect())
+}
+
+fn dictionary_rows<K: DictionaryKey, H: Hasher, F: Fn() -> H>(
+    array: &dyn Array,
+    new: &F,
+) -> Result<Vec<u64>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = rows(array.values().as_ref(), new)?;
+    Ok(array
+        .keys()
+        .iter()
+        .map(|key| key.map_or(NULL_HASH, |key| values[key.to_usize().unwrap()]))
+        .collect())
+}
+
+macro_rules! rows_dyn {
+    ($ty:ty, $array:expr, $new:expr) => {{
+        primitive_rows::<$ty, _, _>($array, $new)
+    }};
+}
+
+/// Returns the hash of every row of `array`, where nested values are hashed from the hashes
+/// of their children and dictionaries are hashed from their values.
+fn rows<H: Hasher, F: Fn() -> H>(array: &dyn Array, new: &F) -> Result<Vec<u64>> {
+    use DataType::*;
+    Ok(match array.data_type() {
+        Null => vec![NULL_HASH; array.len()],
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, &[x as u8])))
+                .collect()
+        }
+        Int8 => rows_dyn!(i8, array, new),
+        Int16 => rows_dyn!(i16, array, new),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            rows_dyn!(i32, array, new)
+        }
+        Interval(IntervalUnit::DayTime) => rows_dyn!(days_ms, array, new),
+        Interval(IntervalUnit::MonthDayNano) => rows_dyn!(months_days_ns, array, new),
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => rows_dyn!(i64, array, new),
+        Decimal(_, _) => rows_dyn!(i128, array, new),
+        UInt8 => rows_dyn!(u8, array, new),
+        UInt16 => rows_dyn!(u16, array, new),
+        UInt32 => rows_dyn!(u32, array, new),
+        UInt64 => rows_dyn!(u64, array, new),
+        Float32 => rows_dyn!(f32, array, new),
+        Float64 => rows_dyn!(f64, array, new),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, x.as_bytes())))
+                .collect()
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, x.as_bytes())))
+                .collect()
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, x)))
+                .collect()
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, x)))
+                .collect()
+        }
+        FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array
+                .iter()
+                .map(|x| x.map_or(NULL_HASH, |x| hash_bytes(new, x)))
+                .collect()
+        }
+        List(_) => list_rows::<i32, _, _>(array, new)?,
+        LargeList(_) => list_rows::<i64, _, _>(array, new)?,
+        FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = rows(array.values().as_ref(), new)?;
+            (0..array.len())
+                .map(|row| {
+                    if array.is_valid(row) {
+                        let range = row * size..(row + 1) * size;
+                        hash_hashes(new, values[range].iter().copied())
+                    } else {
+                        NULL_HASH
+                    }
+                })
+                .collect()
+        }
+        Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = array
+                .values()
+                .iter()
+                .map(|x| rows(x.as_ref(), new))
+                .collect::<Result<Vec<_>>>()?;
+            (0..array.len())
+                .map(|row| {
+                    if array.is_valid(row) {
+                        hash_hashes(new, fields.iter().map(|x| x[row]))
+                    } else {
+                        NULL_HASH
+                    }
+                })
+                .collect()
+        }
+        Dictionary(key_type, _) => match key_type.as_ref() {
+            Int8 => dictionary_rows::<i8, _, _>(array, new)?,
+            Int16 => dictionary_rows::<i16, _, _>(array, new)?,
+            Int32 => dictionary_rows::<i32, _, _>(array, new)?,
+            Int64 => dictionary_rows::<i64, _, _>(array, new)?,
+            UInt8 => dictionary_rows::<u8, _, _>(array, new)?,
+            UInt16 => dictionary_rows::<u16, _, _>(array, new)?,
+            UInt32 => dictionary_rows::<u32, _, _>(array, new)?,
+            UInt64 => dictionary_rows::<u64, _, _>(array, new)?,
+            _ => unreachable!(),
+        },
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash not implemented for type {:?}",
+                t
+            )))
+        }
+    })
+}
+
+fn combine_rows<H: Hasher, F: Fn() -> H>(
+    columns: &[&dyn Array],
+    seed: u64,
+    new: &F,
+) -> Result<Vec<u64>> {
+    let mut hashes = vec![seed; columns[0].len()];
+    for column in columns {
+        let column = rows(*column, new)?;
+        hashes
+            .iter_mut()
+            .zip(column)
+            .for_each(|(hash, x)| *hash = hash_hashes(new, [*hash, x].iter().copied()));
+    }
+    Ok(hashes)
+}
+
+/// Returns the hash of every row of `columns`, combining the hashes of the columns with
+/// [`HashOptions::default`]. See [`hash_columns_with_options`].
+/// # Errors
+/// This function errors iff `columns` is empty, the columns do not have the same length or
+/// a column's data type is not supported (see [`can_hash_columns`]).
+pub fn hash_columns(columns: &[&dyn Array]) -> Result<PrimitiveArray<u64>> {
+    hash_columns_with_options(columns, &HashOptions::default())
+}
+
+/// Returns the hash of every row of `columns`: starting from `options.seed`, the hash of every
+/// column is combined into the hash of the row, in order. Null slots hash to the same value
+/// regardless of their type, nested values are hashed from their children and dictionaries
+/// are hashed from their values, so that equal values have equal hashes regardless of their
+/// keys. The result has no nulls.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::hash::{hash_columns_with_options, HashMode, HashOptions};
+///
+/// let a = Int32Array::from(&[Some(1), None, Some(1)]);
+/// let b = Utf8Array::<i32>::from_slice(&["a", "b", "a"]);
+/// let options = HashOptions {
+///     seed: 42,
+///     mode: HashMode::Stable,
+/// };
+/// let result = hash_columns_with_options(&[&a, &b], &options).unwrap();
+///
+/// assert_eq!(result.null_count(), 0);
+/// assert_eq!(result.value(0), result.value(2));
+/// assert_ne!(result.value(0), result.value(1));
+/// ```
+/// # Errors
+/// This function errors iff `columns` is empty, the columns do not have the same length or
+/// a column's data type is not supported (see [`can_hash_columns`]).
+pub fn hash_columns_with_options(
+    columns: &[&dyn Array],
+    options: &HashOptions,
+) -> Result<PrimitiveArray<u64>> {
+    let first = columns.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Hashing rows requires at least one column".to_string())
+    })?;
+    if columns.iter().any(|x| x.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Hashing rows requires all columns to have the same length".to_string(),
+        ));
+    }
+
+    let hashes = match options.mode {
+        HashMode::Fast => combine_rows(columns, options.seed, &|| new_hasher!())?,
+        HashMode::Stable => combine_rows(columns, options.seed, &StableHasher::new)?,
+    };
+    Ok(PrimitiveArray::from_data(
+        DataType::UInt64,
+        hashes.into(),
+        None,
+    ))
+}
+
+/// Returns whether [`hash_columns`] supports columns of type `data_type`.
+pub fn can_hash_columns(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type {
+        Float16 | Union(_) => false,
+        List(field) | LargeList(field) | FixedSizeList(field, _) => {
+            can_hash_columns(field.data_type())
+        }
+        Struct(fields) => fields.iter().all(|x| can_hash_columns(x.data_type())),
+        Dictionary(_, values) => can_hash_columns(values),
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn stable_hasher() {
+        // test vector of FNV-1a
+        let mut hasher = StableHasher::new();
+        hasher.write(b"a");
+        assert_eq!(hasher.0, 0xaf63_dc4c_8601_ec8c);
+
+        let options = HashOptions {
+            seed: 0,
+            mode: HashMode::Stable,
+        };
+        let array = crate::array::Int32Array::from(&[Some(1), None]);
+        let result = hash_columns_with_options(&[&array], &options).unwrap();
+        assert_eq!(
+            result.values().as_slice(),
+            &[4647542198934167131, 16708370476132032561]
+        );
+    }
+
+    #[test]
+    fn columns() {
+        use std::sync::Arc;
+
+        use crate::array::*;
+        use crate::bitmap::Bitmap;
+        use crate::datatypes::Field;
+
+        for mode in [HashMode::Fast, HashMode::Stable] {
+            let options = HashOptions { seed: 7, mode };
+
+            // dictionaries hash as their values
+            let values = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("a")]);
+            let mut dictionary = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+            dictionary.try_extend(values.iter()).unwrap();
+            let dictionary: DictionaryArray<u8> = dictionary.into();
+            let lhs = hash_columns_with_options(&[&values], &options).unwrap();
+            let rhs = hash_columns_with_options(&[&dictionary], &options).unwrap();
+            assert_eq!(lhs, rhs);
+            assert_eq!(lhs.value(0), lhs.value(3));
+            assert_ne!(lhs.value(0), lhs.value(2));
+
+            // the seed and the order of the columns change the hash
+            let other = HashOptions { seed: 8, mode };
+            let seeded = hash_columns_with_options(&[&values], &other).unwrap();
+            assert_ne!(lhs.value(0), seeded.value(0));
+            let ints = Int64Array::from_slice(&[1, 2, 3, 4]);
+            let ab = hash_columns_with_options(&[&values, &ints], &options).unwrap();
+            let ba = hash_columns_with_options(&[&ints, &values], &options).unwrap();
+            assert_ne!(ab.value(0), ba.value(0));
+
+            // nested values are hashed from their children, regardless of offsets
+            let list = ListArray::<i32>::from_data(
+                ListArray::<i32>::default_datatype(DataType::Int32),
+                Buffer::from(&[0, 2, 2, 4, 6]),
+                Arc::new(Int32Array::from_slice(&[1, 2, 3, 4, 1, 2])),
+                Some(Bitmap::from([true, false, true, true])),
+            );
+            let result = hash_columns_with_options(&[&list], &options).unwrap();
+            assert_eq!(result.value(0), result.value(3));
+            assert_ne!(result.value(0), result.value(2));
+            let sliced = hash_columns_with_options(&[&list.slice(2, 2)], &options).unwrap();
+            assert_eq!(sliced.value(1), result.value(0));
+
+            let structs = StructArray::from_data(
+                vec![
+                    Field::new("a", DataType::Int64, false),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+                vec![Arc::new(ints.clone()), Arc::new(values.clone())],
+                Some(Bitmap::from([true, true, false, true])),
+            );
+            let result = hash_columns_with_options(&[&structs], &options).unwrap();
+            let nulls =
+                hash_columns_with_options(&[&Int32Array::new_null(DataType::Int32, 1)], &options)
+                    .unwrap();
+            assert_eq!(result.value(2), nulls.value(0));
+            assert_ne!(result.value(0), result.value(3));
+
+            let binary = FixedSizeBinaryArray::from_data(
+                DataType::FixedSizeBinary(2),
+                Buffer::from(&[1, 2, 3, 4, 1, 2]),
+                None,
+            );
+            let result = hash_columns_with_options(&[&binary], &options).unwrap();
+            assert_eq!(result.value(0), result.value(2));
+        }
+
+        let a = crate::array::Int32Array::from_slice(&[1, 2]);
+        assert!(hash_columns(&[]).is_err());
+        assert!(hash_columns(&[&a, &a.slice(0, 1)]).is_err());
+        let union = DataType::Union(vec![]);
+        assert!(!can_hash_columns(&union));
+        assert!(can_hash_columns(&DataType::Dictionary(
+            Box::new(DataType::Int32),
+            Box::new(DataType::Decimal(10, 2))
+        )));
+    }
+
     #[test]
     fn consistency() {
         use crate::array::new_null_array;
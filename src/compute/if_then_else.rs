@@ -2,6 +2,8 @@ use crate::array::growable;
 use crate::error::{ArrowError, Result};
 use crate::{array::*, bitmap::utils::SlicesIterator};
 
+use super::utils::build_from_slots;
+
 /// Returns the values from `lhs` if the predicate is `true` or from the `lhs` if the predicate is false
 /// Returns `None` if the predicate is `None`.
 /// # Example
@@ -73,6 +75,88 @@ pub fn if_then_else(
     Ok(result)
 }
 
+/// Returns, for every slot, the value of the first branch whose predicate is `true`, or the value
+/// of `otherwise` when no predicate is `true`. This has the same semantics as `CASE WHEN` in SQL:
+/// * null predicates count as `false`;
+/// * slots without a matching branch are null when `otherwise` is `None`;
+/// * values with a single slot are broadcast to all slots.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::if_then_else::case_when;
+/// use arrow2::array::{Utf8Array, BooleanArray};
+///
+/// # fn main() -> Result<()> {
+/// let small = BooleanArray::from(&[Some(true), Some(false), None, Some(false)]);
+/// let large = BooleanArray::from(&[Some(true), Some(true), None, Some(false)]);
+/// let names = Utf8Array::<i32>::from_slice(&["a", "b", "c", "d"]);
+/// let large_value = Utf8Array::<i32>::from_slice(&["large"]);
+/// let otherwise = Utf8Array::<i32>::from_slice(&["other"]);
+///
+/// let result = case_when(&[(small, &names), (large, &large_value)], Some(&otherwise))?;
+///
+/// let expected = Utf8Array::<i32>::from_slice(&["a", "large", "other", "other"]);
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `branches` is empty;
+/// * the values do not have the same data type;
+/// * the predicates do not have the same length;
+/// * a value has neither the length of the predicates nor a single slot.
+pub fn case_when(
+    branches: &[(BooleanArray, &dyn Array)],
+    otherwise: Option<&dyn Array>,
+) -> Result<Box<dyn Array>> {
+    let (first, first_value) = branches.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Case when requires at least one branch".to_string())
+    })?;
+    let len = first.len();
+    if let Some((predicate, _)) = branches.iter().find(|(x, _)| x.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Case when requires all predicates to have the same length ({} != {})",
+            predicate.len(),
+            len
+        )));
+    }
+
+    let mut arrays = branches.iter().map(|(_, x)| *x).collect::<Vec<_>>();
+    arrays.extend(otherwise);
+    let data_type = first_value.data_type();
+    if let Some(array) = arrays.iter().find(|x| x.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Case when requires all values to have the same datatype ({} != {})",
+            array.data_type(),
+            data_type
+        )));
+    }
+    if let Some(array) = arrays.iter().find(|x| x.len() != len && x.len() != 1) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Case when requires all values to have the length of the predicates ({}) or a single slot, but one has {}",
+            len,
+            array.len()
+        )));
+    }
+
+    let row_of = |array: usize, row: usize| {
+        if arrays[array].len() == 1 {
+            (array, 0)
+        } else {
+            (array, row)
+        }
+    };
+    let sources = (0..len).map(|row| {
+        branches
+            .iter()
+            .position(|(predicate, _)| predicate.is_valid(row) && predicate.value(row))
+            .or_else(|| otherwise.map(|_| branches.len()))
+            .map(|array| row_of(array, row))
+    });
+    Ok(build_from_slots(&arrays, true, len, sources))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +199,27 @@ mod tests {
         assert_eq!(expected, result.as_ref());
         Ok(())
     }
+
+    #[test]
+    fn case_when_branches() -> Result<()> {
+        let first = BooleanArray::from(&[Some(true), None, Some(false), Some(false)]);
+        let second = BooleanArray::from(&[Some(true), Some(true), None, Some(false)]);
+        let a = Int32Array::from(&[Some(1), Some(2), Some(3), None]);
+        let b = Int32Array::from(&[None]);
+
+        let result = case_when(&[(first.clone(), &a), (second.clone(), &b)], None)?;
+        let expected = Int32Array::from(&[Some(1), None, None, None]);
+        assert_eq!(expected, result.as_ref());
+
+        let otherwise = Int32Array::from_slice(&[10, 20, 30, 40]);
+        let result = case_when(&[(second, &a)], Some(&otherwise))?;
+        let expected = Int32Array::from(&[Some(1), Some(2), Some(30), Some(40)]);
+        assert_eq!(expected, result.as_ref());
+
+        let c = Int64Array::from_slice(&[1]);
+        assert!(case_when(&[(first.clone(), &a), (first.clone(), &c)], None).is_err());
+        assert!(case_when(&[(first, &a.slice(0, 2))], None).is_err());
+        assert!(case_when(&[], Some(&otherwise)).is_err());
+        Ok(())
+    }
 }
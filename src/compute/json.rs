@@ -0,0 +1,311 @@
+//! Defines kernels to extract values from JSON documents stored in [`Utf8Array`]s.
+//!
+//! Values are located by a path of the form `$.a.b[0]`: `$` is the document, `.name` or
+//! `["name"]` selects the member `name` of an object and `[i]` selects the element `i` of
+//! an array. Slots that are null, are not valid JSON, or do not contain the path are null.
+use serde_json::Value;
+
+use crate::{
+    array::{BooleanArray, Offset, PrimitiveArray, StructArray, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::{DataType, IntervalUnit},
+    error::{ArrowError, Result},
+    io::json::deserialize,
+};
+
+/// A step of a JSON path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+fn invalid_path(path: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Invalid JSON path \"{}\"", path))
+}
+
+/// Parses `path` into its steps.
+fn parse_path(path: &str) -> Result<Vec<Step>> {
+    let mut rest = path.strip_prefix('$').ok_or_else(|| invalid_path(path))?;
+    let mut steps = vec![];
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix('.') {
+            let end = tail.find(['.', '[']).unwrap_or(tail.len());
+            if end == 0 {
+                return Err(invalid_path(path));
+            }
+            steps.push(Step::Key(tail[..end].to_string()));
+            rest = &tail[end..];
+        } else if let Some(tail) = rest.strip_prefix('[') {
+            let end = tail.find(']').ok_or_else(|| invalid_path(path))?;
+            let inner = &tail[..end];
+            let quoted = inner.len() >= 2
+                && ((inner.starts_with('"') && inner.ends_with('"'))
+                    || (inner.starts_with('\'') && inner.ends_with('\'')));
+            if quoted {
+                steps.push(Step::Key(inner[1..inner.len() - 1].to_string()));
+            } else {
+                let index = inner.parse().map_err(|_| invalid_path(path))?;
+                steps.push(Step::Index(index));
+            }
+            rest = &tail[end + 1..];
+        } else {
+            return Err(invalid_path(path));
+        }
+    }
+    Ok(steps)
+}
+
+/// Returns the value at `steps` of `value`, if any.
+fn get<'a>(value: &'a Value, steps: &[Step]) -> Option<&'a Value> {
+    steps.iter().try_fold(value, |value, step| match step {
+        Step::Key(key) => value.as_object()?.get(key),
+        Step::Index(index) => value.as_array()?.get(*index),
+    })
+}
+
+/// Applies `op` to the value at `path` of every document of `values`.
+fn extract<O, T, F>(values: &Utf8Array<O>, path: &str, op: F) -> Result<Vec<Option<T>>>
+where
+    O: Offset,
+    F: Fn(&Value) -> Option<T>,
+{
+    let steps = parse_path(path)?;
+    Ok(values
+        .iter()
+        .map(|document| {
+            let document = serde_json::from_str::<Value>(document?).ok()?;
+            get(&document, &steps).and_then(&op)
+        })
+        .collect())
+}
+
+/// Returns the value at `path` of every JSON document of `values`. Strings are returned
+/// without quotes and other values are returned as JSON; `null` values are null.
+/// # Example
+/// ```rust
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::json::json_extract;
+///
+/// let values = Utf8Array::<i32>::from(&[
+///     Some(r#"{"a": {"b": ["x", "y"]}}"#),
+///     Some(r#"{"a": {"b": [{"c": 1}]}}"#),
+///     Some(r#"{"a": 1}"#),
+///     Some("not json"),
+///     None,
+/// ]);
+/// let result = json_extract(&values, "$.a.b[0]").unwrap();
+///
+/// let expected = Utf8Array::<i32>::from(&[Some("x"), Some(r#"{"c":1}"#), None, None, None]);
+/// assert_eq!(result, expected);
+/// ```
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract<O: Offset>(values: &Utf8Array<O>, path: &str) -> Result<Utf8Array<O>> {
+    let result = extract(values, path, |value| match value {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        value => Some(value.to_string()),
+    })?;
+    Ok(result.into_iter().collect())
+}
+
+/// Returns the integer at `path` of every JSON document of `values`, as an `Int64` array.
+/// Values that are not integers representable as `i64` are null.
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract_int<O: Offset>(
+    values: &Utf8Array<O>,
+    path: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let result = extract(values, path, |value| value.as_i64())?;
+    Ok(result.into_iter().collect())
+}
+
+/// Returns the number at `path` of every JSON document of `values`, as a `Float64` array.
+/// Values that are not numbers are null.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Float64Array, Utf8Array};
+/// use arrow2::compute::json::json_extract_float;
+///
+/// let values = Utf8Array::<i32>::from_slice(&[r#"{"a": 1.5}"#, r#"{"a": 2}"#, r#"{"a": "3"}"#]);
+/// let result = json_extract_float(&values, "$.a").unwrap();
+/// assert_eq!(result, Float64Array::from(&[Some(1.5), Some(2.0), None]));
+/// ```
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract_float<O: Offset>(
+    values: &Utf8Array<O>,
+    path: &str,
+) -> Result<PrimitiveArray<f64>> {
+    let result = extract(values, path, |value| value.as_f64())?;
+    Ok(result.into_iter().collect())
+}
+
+/// Returns the boolean at `path` of every JSON document of `values`.
+/// Values that are not booleans are null.
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract_bool<O: Offset>(values: &Utf8Array<O>, path: &str) -> Result<BooleanArray> {
+    let result = extract(values, path, |value| value.as_bool())?;
+    Ok(result.into_iter().collect())
+}
+
+/// Returns whether [`json_to_struct`] supports `data_type`.
+pub fn can_json_to_struct(data_type: &DataType) -> bool {
+    fn is_supported(data_type: &DataType) -> bool {
+        use DataType::*;
+        match data_type {
+            Null
+            | Boolean
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Float32
+            | Float64
+            | Date32
+            | Date64
+            | Time32(_)
+            | Time64(_)
+            | Timestamp(_, _)
+            | Duration(_)
+            | Interval(IntervalUnit::YearMonth)
+            | Utf8
+            | LargeUtf8 => true,
+            List(field) | LargeList(field) => is_supported(field.data_type()),
+            Struct(fields) => fields.iter().all(|x| is_supported(x.data_type())),
+            // the reader panics when the keys overflow and nulls most value types
+            _ => false,
+        }
+    }
+    matches!(data_type, DataType::Struct(_)) && is_supported(data_type)
+}
+
+/// Parses every JSON document of `values` into a [`StructArray`] of `data_type`, with the
+/// same semantics as the JSON reader (see [`crate::io::json::read`]). Slots that are null or
+/// are not JSON objects are null.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Array, Int64Array, Utf8Array};
+/// use arrow2::compute::json::json_to_struct;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let values = Utf8Array::<i32>::from(&[Some(r#"{"a": 1, "b": "x"}"#), Some("[]"), None]);
+/// let data_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)]);
+/// let result = json_to_struct(&values, &data_type).unwrap();
+///
+/// assert_eq!(result.null_count(), 2);
+/// assert_eq!(Int64Array::from(&[Some(1), None, None]), result.values()[0].as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `data_type` is not a struct of types supported by the JSON reader,
+/// other than dictionaries.
+pub fn json_to_struct<O: Offset>(
+    values: &Utf8Array<O>,
+    data_type: &DataType,
+) -> Result<StructArray> {
+    if !can_json_to_struct(data_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Parsing JSON into {:?} is not supported",
+            data_type
+        )));
+    }
+
+    let documents = values
+        .iter()
+        .map(|x| {
+            x.and_then(|x| serde_json::from_str::<Value>(x).ok())
+                .filter(|x| x.is_object())
+                .unwrap_or(Value::Null)
+        })
+        .collect::<Vec<_>>();
+    let validity = documents.iter().map(|x| !x.is_null()).collect::<Bitmap>();
+    let rows = documents.iter().collect::<Vec<_>>();
+
+    let array = deserialize::read(&rows, data_type.clone());
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    Ok(StructArray::from_data(
+        array.fields().to_vec(),
+        array.values().to_vec(),
+        Some(validity),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::datatypes::Field;
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            parse_path(r#"$.a["b.c"][2]['d']"#).unwrap(),
+            vec![
+                Step::Key("a".to_string()),
+                Step::Key("b.c".to_string()),
+                Step::Index(2),
+                Step::Key("d".to_string())
+            ]
+        );
+        assert!(parse_path("$").unwrap().is_empty());
+        assert!(parse_path("a.b").is_err());
+        assert!(parse_path("$..b").is_err());
+        assert!(parse_path("$[x]").is_err());
+        assert!(parse_path("$[0").is_err());
+    }
+
+    #[test]
+    fn typed() {
+        let values = Utf8Array::<i64>::from(&[
+            Some(r#"{"a": [1, true]}"#),
+            Some(r#"{"a": [1.5, false]}"#),
+            Some(r#"{"a": []}"#),
+        ]);
+        let result = json_extract_int(&values, "$.a[0]").unwrap();
+        assert_eq!(result, Int64Array::from(&[Some(1), None, None]));
+        let result = json_extract_bool(&values, "$.a[1]").unwrap();
+        assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+        let result = json_extract(&values, "$.a").unwrap();
+        assert_eq!(
+            result,
+            Utf8Array::<i64>::from_slice(&["[1,true]", "[1.5,false]", "[]"])
+        );
+    }
+
+    #[test]
+    fn to_struct() {
+        let values = Utf8Array::<i32>::from(&[
+            Some(r#"{"a": {"b": [1, 2]}, "c": "x"}"#),
+            Some(r#"{"c": 1}"#),
+            Some("{"),
+        ]);
+        let b = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+        let a = DataType::Struct(vec![Field::new("b", b, true)]);
+        let data_type = DataType::Struct(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new("a", a, true),
+        ]);
+        let result = json_to_struct(&values, &data_type).unwrap();
+
+        assert_eq!(result.data_type(), &data_type);
+        assert_eq!(result.validity(), &Some(Bitmap::from([true, true, false])));
+        let expected = Utf8Array::<i32>::from(&[Some("x"), Some("1"), None]);
+        assert_eq!(&expected as &dyn Array, result.values()[0].as_ref());
+
+        let data_type = DataType::Struct(vec![Field::new("a", DataType::Binary, true)]);
+        assert!(json_to_struct(&values, &data_type).is_err());
+        assert!(json_to_struct(&values, &DataType::Utf8).is_err());
+
+        let dictionary = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let data_type = DataType::Struct(vec![Field::new("c", dictionary, true)]);
+        assert!(!can_json_to_struct(&data_type));
+        assert!(json_to_struct(&values, &data_type).is_err());
+    }
+}
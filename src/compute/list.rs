@@ -0,0 +1,674 @@
+//! Defines kernels for arrays of lists: [`ListArray`] (`List` and `LargeList`) and
+//! [`FixedSizeListArray`].
+//!
+//! Kernels returning lists return arrays of the same type as their input, except when the
+//! lengths of the lists change, in which case a `FixedSizeList` becomes a `List`.
+use std::cmp::Ordering;
+use std::ops::Add;
+use std::sync::Arc;
+
+use num::ToPrimitive;
+
+use crate::{
+    array::{growable::make_growable, ord::build_compare, *},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::{simd::*, NativeType},
+};
+
+use super::{
+    aggregate::{max_primitive, min_primitive, sum, SimdOrd, Sum},
+    sort::{sort_to_indices, SortOptions},
+    take::take,
+    utils::build_from_slots,
+};
+
+/// An array of lists, as its values and the `(start, length)` in the values of every list.
+struct Lists<'a> {
+    values: &'a dyn Array,
+    ranges: Vec<(usize, usize)>,
+    validity: &'a Option<Bitmap>,
+}
+
+impl<'a> Lists<'a> {
+    fn try_new(array: &'a dyn Array) -> Result<Self> {
+        match array.data_type() {
+            DataType::List(_) => Ok(Self::from_list::<i32>(array)),
+            DataType::LargeList(_) => Ok(Self::from_list::<i64>(array)),
+            DataType::FixedSizeList(_, size) => {
+                let size = *size as usize;
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                Ok(Self {
+                    values: array.values().as_ref(),
+                    ranges: (0..array.len()).map(|x| (x * size, size)).collect(),
+                    validity: array.validity(),
+                })
+            }
+            other => Err(ArrowError::InvalidArgumentError(format!(
+                "List kernels are not supported for {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn from_list<O: Offset>(array: &'a dyn Array) -> Self {
+        let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+        let ranges = array
+            .offsets()
+            .windows(2)
+            .map(|x| (x[0].to_usize(), (x[1] - x[0]).to_usize()))
+            .collect();
+        Self {
+            values: array.values().as_ref(),
+            ranges,
+            validity: array.validity(),
+        }
+    }
+
+    #[inline]
+    fn is_valid(&self, row: usize) -> bool {
+        self.validity
+            .as_ref()
+            .map(|x| x.get_bit(row))
+            .unwrap_or(true)
+    }
+
+    /// Returns the `(start, length)` of every list, with an empty range for null lists.
+    fn valid_ranges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.ranges.iter().enumerate().map(move |(row, range)| {
+            if self.is_valid(row) {
+                *range
+            } else {
+                (range.0, 0)
+            }
+        })
+    }
+}
+
+/// Returns a list array of the type of `array` (`List` for `FixedSizeList`) whose lists
+/// have `lengths` and whose values are `values`.
+fn new_lists<I: Iterator<Item = usize>>(
+    array: &dyn Array,
+    values: Arc<dyn Array>,
+    lengths: I,
+) -> Result<Box<dyn Array>> {
+    fn offsets<O: Offset, I: Iterator<Item = usize>>(lengths: I) -> Result<Buffer<O>> {
+        let mut length = 0;
+        std::iter::once(Ok(O::default()))
+            .chain(lengths.map(|x| {
+                length += x;
+                O::from_usize(length).ok_or(ArrowError::KeyOverflowError)
+            }))
+            .collect()
+    }
+
+    let validity = array.validity().clone();
+    Ok(match array.data_type() {
+        DataType::LargeList(_) => Box::new(ListArray::<i64>::from_data(
+            array.data_type().clone(),
+            offsets(lengths)?,
+            values,
+            validity,
+        )),
+        DataType::List(_) => Box::new(ListArray::<i32>::from_data(
+            array.data_type().clone(),
+            offsets(lengths)?,
+            values,
+            validity,
+        )),
+        DataType::FixedSizeList(field, _) => Box::new(ListArray::<i32>::from_data(
+            DataType::List(field.clone()),
+            offsets(lengths)?,
+            values,
+            validity,
+        )),
+        _ => unreachable!(),
+    })
+}
+
+/// Returns the values of `ranges` of `values`, concatenated.
+fn concat_ranges<I: Iterator<Item = (usize, usize)>>(
+    values: &dyn Array,
+    ranges: I,
+    capacity: usize,
+) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], false, capacity);
+    ranges
+        .filter(|(_, len)| *len > 0)
+        .for_each(|(start, len)| growable.extend(0, start, len));
+    growable.as_box()
+}
+
+/// Returns the values of the non-null lists of `array`, concatenated.
+/// # Example
+/// ```rust
+/// use arrow2::array::{ListArray, Int32Array, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_flatten;
+///
+/// let data = vec![Some(vec![Some(1), Some(2)]), None, Some(vec![None, Some(3)])];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_flatten(&array).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), Some(2), None, Some(3)]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array.
+pub fn list_flatten(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    Ok(flatten(&lists))
+}
+
+fn flatten(lists: &Lists) -> Box<dyn Array> {
+    let start = lists.ranges.first().map(|x| x.0).unwrap_or(0);
+    let end = lists.ranges.last().map(|x| x.0 + x.1).unwrap_or(0);
+    if lists.validity.as_ref().map(|x| x.null_count()).unwrap_or(0) == 0 {
+        // lists are contiguous in the values
+        lists.values.slice(start, end - start)
+    } else {
+        concat_ranges(lists.values, lists.valid_ranges(), end - start)
+    }
+}
+
+/// Returns the values of the non-null lists of `array`, concatenated, together with the index
+/// of the list of every value. Other columns can be aligned with the values by
+/// [`take`](crate::compute::take::take)ing the indices from them.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Array, ListArray, UInt64Array, Utf8Array, MutableListArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::{list::explode, take::take};
+///
+/// let data = vec![Some(vec![Some("a"), Some("b")]), Some(vec![]), Some(vec![Some("c")])];
+/// let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let (values, indices) = explode(&array).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from_slice(&["a", "b", "c"]), values.as_ref());
+/// assert_eq!(indices, UInt64Array::from_slice(&[0, 0, 2]));
+///
+/// let ids = UInt64Array::from_slice(&[10, 20, 30]);
+/// let ids = take(&ids, &indices).unwrap();
+/// assert_eq!(UInt64Array::from_slice(&[10, 10, 30]), ids.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array.
+pub fn explode(array: &dyn Array) -> Result<(Box<dyn Array>, PrimitiveArray<u64>)> {
+    let lists = Lists::try_new(array)?;
+    let indices = lists
+        .valid_ranges()
+        .enumerate()
+        .flat_map(|(row, (_, len))| (0..len).map(move |_| row as u64));
+    Ok((flatten(&lists), PrimitiveArray::from_values(indices)))
+}
+
+/// Returns the element at `index` of every list of `array`. A negative `index` counts from
+/// the end of the list, so that `-1` is its last element. Slots whose list is null or does not
+/// have the element are null.
+/// # Example
+/// ```rust
+/// use arrow2::array::{FixedSizeListArray, Int32Array};
+/// use arrow2::compute::list::list_get;
+/// use arrow2::datatypes::{DataType, Field};
+/// use std::sync::Arc;
+///
+/// let values = Arc::new(Int32Array::from_slice(&[1, 2, 3, 4, 5, 6]));
+/// let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 3);
+/// let array = FixedSizeListArray::from_data(data_type, values, None);
+///
+/// let result = list_get(&array, -1).unwrap();
+/// assert_eq!(Int32Array::from_slice(&[3, 6]), result.as_ref());
+/// let result = list_get(&array, 3).unwrap();
+/// assert_eq!(Int32Array::from(&[None, None]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array.
+pub fn list_get(array: &dyn Array, index: i64) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let sources = lists.valid_ranges().map(|(start, len)| {
+        let index = if index < 0 {
+            (len as i64).checked_add(index)
+        } else {
+            Some(index)
+        };
+        index
+            .filter(|x| (0..len as i64).contains(x))
+            .map(|x| (0, start + x as usize))
+    });
+    Ok(build_from_slots(
+        &[lists.values],
+        true,
+        array.len(),
+        sources,
+    ))
+}
+
+/// Returns the sub-lists of every list of `array` starting at `offset`, with at most `length`
+/// elements (all remaining elements when `None`). A negative `offset` counts from the end of
+/// the list. Sub-lists out of bounds are empty.
+/// # Example
+/// ```rust
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_slice;
+///
+/// let data = vec![Some(vec![Some(1), Some(2), Some(3)]), Some(vec![Some(4)]), None];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_slice(&array, -2, Some(1)).unwrap();
+///
+/// let data = vec![Some(vec![Some(2)]), Some(vec![Some(4)]), None];
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(data).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array or the offsets of the result overflow.
+pub fn list_slice(array: &dyn Array, offset: i64, length: Option<usize>) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let ranges = lists
+        .valid_ranges()
+        .map(|(start, len)| {
+            let offset = if offset < 0 {
+                len.saturating_sub(offset.unsigned_abs() as usize)
+            } else {
+                (offset as usize).min(len)
+            };
+            let length = length.unwrap_or(usize::MAX).min(len - offset);
+            (start + offset, length)
+        })
+        .collect::<Vec<_>>();
+
+    let capacity = ranges.iter().map(|x| x.1).sum();
+    let values = concat_ranges(lists.values, ranges.iter().copied(), capacity);
+    new_lists(array, values.into(), ranges.iter().map(|x| x.1))
+}
+
+/// Returns `array` with the values of every list sorted according to `options`.
+/// # Example
+/// ```rust
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::{list::list_sort, sort::SortOptions};
+///
+/// let data = vec![Some(vec![Some("b"), None, Some("a")]), None];
+/// let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_sort(&array, &SortOptions::default()).unwrap();
+///
+/// let data = vec![Some(vec![None, Some("a"), Some("b")]), None];
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend(data).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array or its values cannot be sorted.
+pub fn list_sort(array: &dyn Array, options: &SortOptions) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    // the values of null lists are sorted too, so that the lengths of all lists are kept
+    let mut indices = Vec::<u64>::with_capacity(lists.values.len());
+    for (start, len) in lists.ranges.iter().copied() {
+        let sorted =
+            sort_to_indices::<u64>(lists.values.slice(start, len).as_ref(), options, None)?;
+        indices.extend(sorted.values().iter().map(|x| x + start as u64));
+    }
+    let values = take(lists.values, &PrimitiveArray::from_values(indices))?;
+
+    match array.data_type() {
+        DataType::FixedSizeList(_, _) => Ok(Box::new(FixedSizeListArray::from_data(
+            array.data_type().clone(),
+            values.into(),
+            array.validity().clone(),
+        ))),
+        _ => new_lists(array, values.into(), lists.ranges.iter().map(|x| x.1)),
+    }
+}
+
+/// Returns `array` with the distinct values of every list, in the order of their first
+/// occurrence. Null values are considered equal to each other.
+/// # Example
+/// ```rust
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_unique;
+///
+/// let data = vec![Some(vec![Some(2), None, Some(1), Some(2), None])];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_unique(&array).unwrap();
+///
+/// let data = vec![Some(vec![Some(2), None, Some(1)])];
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(data).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array, its values have no natural order or
+/// the offsets of the result overflow.
+pub fn list_unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let values = lists.values;
+    let compare_values = build_compare(values, values)?;
+    let compare = |lhs: usize, rhs: usize| match (values.is_valid(lhs), values.is_valid(rhs)) {
+        (true, true) => compare_values(lhs, rhs),
+        (false, false) => Ordering::Equal,
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+    };
+
+    let mut indices = Vec::<u64>::with_capacity(values.len());
+    let mut lengths = Vec::with_capacity(lists.ranges.len());
+    let mut list = vec![];
+    for (start, len) in lists.valid_ranges() {
+        list.clear();
+        list.extend(start..start + len);
+        // ties are broken by position so that the first occurrence is kept
+        list.sort_unstable_by(|lhs, rhs| compare(*lhs, *rhs).then(lhs.cmp(rhs)));
+        list.dedup_by(|current, previous| compare(*previous, *current) == Ordering::Equal);
+        list.sort_unstable();
+        indices.extend(list.iter().map(|x| *x as u64));
+        lengths.push(list.len());
+    }
+    let values = take(values, &PrimitiveArray::from_values(indices))?;
+    new_lists(array, values.into(), lengths.into_iter())
+}
+
+/// Returns the number of elements of every list of `array`, as an `Int64` array for
+/// `LargeList` and an `Int32` array otherwise.
+/// # Errors
+/// This function errors iff `array` is not a list array.
+pub fn list_len(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let validity = array.validity().clone();
+    Ok(match array.data_type() {
+        DataType::LargeList(_) => {
+            let values = lists.ranges.iter().map(|x| x.1 as i64).collect();
+            Box::new(PrimitiveArray::<i64>::from_data(
+                DataType::Int64,
+                values,
+                validity,
+            ))
+        }
+        _ => {
+            let values = lists.ranges.iter().map(|x| x.1 as i32).collect();
+            Box::new(PrimitiveArray::<i32>::from_data(
+                DataType::Int32,
+                values,
+                validity,
+            ))
+        }
+    })
+}
+
+/// Applies `op` to the values of every list of `lists`, as a [`PrimitiveArray<T>`].
+fn aggregate<T, O, F>(lists: &Lists, data_type: DataType, op: F) -> PrimitiveArray<O>
+where
+    T: NativeType,
+    O: NativeType,
+    F: Fn(&PrimitiveArray<T>) -> Option<O>,
+{
+    let values = lists
+        .values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let iter = lists.ranges.iter().enumerate().map(|(row, (start, len))| {
+        if lists.is_valid(row) {
+            op(&values.slice(*start, *len))
+        } else {
+            None
+        }
+    });
+    MutablePrimitiveArray::<O>::from_trusted_len_iter(iter)
+        .to(data_type)
+        .into()
+}
+
+fn sum_list<T>(lists: &Lists) -> Box<dyn Array>
+where
+    T: NativeType + Simd + Add<Output = T>,
+    T::Simd: Add<Output = T::Simd> + Sum<T>,
+{
+    let data_type = lists.values.data_type().clone();
+    Box::new(aggregate::<T, T, _>(lists, data_type, |x| sum(x)))
+}
+
+fn min_list<T>(lists: &Lists) -> Box<dyn Array>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let data_type = lists.values.data_type().clone();
+    Box::new(aggregate::<T, T, _>(lists, data_type, |x| min_primitive(x)))
+}
+
+fn max_list<T>(lists: &Lists) -> Box<dyn Array>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let data_type = lists.values.data_type().clone();
+    Box::new(aggregate::<T, T, _>(lists, data_type, |x| max_primitive(x)))
+}
+
+fn mean_list<T: NativeType + ToPrimitive>(lists: &Lists) -> PrimitiveArray<f64> {
+    aggregate::<T, f64, _>(lists, DataType::Float64, |x| {
+        let (sum, count) = x.iter().flatten().fold((0.0, 0), |(sum, count), x| {
+            (sum + x.to_f64().unwrap(), count + 1)
+        });
+        (count > 0).then(|| sum / count as f64)
+    })
+}
+
+macro_rules! dyn_aggregate {
+    ($func:ident, $lists:expr, $name:expr, [$($($variant:pat)|+ => $ty:ty),*]) => {{
+        let lists = $lists;
+        match lists.values.data_type() {
+            $($($variant)|+ => Ok($func::<$ty>(&lists)),)*
+            other => Err(ArrowError::InvalidArgumentError(format!(
+                "{} is not supported for lists of {:?}",
+                $name, other
+            ))),
+        }
+    }};
+}
+
+/// Returns the sum of the values of every list of numeric values of `array`, ignoring nulls.
+/// Slots whose list is null, empty or only has nulls are null.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_sum;
+///
+/// let data = vec![Some(vec![Some(1), None, Some(2)]), None, Some(vec![])];
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(data).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_sum(&array).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(3), None, None]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list array of numeric values.
+pub fn list_sum(array: &dyn Array) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    dyn_aggregate!(sum_list, Lists::try_new(array)?, "Sum", [
+        Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64,
+        UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64,
+        Float32 => f32, Float64 => f64
+    ])
+}
+
+/// Returns the minimum of the values of every list of numeric or temporal values of `array`,
+/// ignoring nulls. Slots whose list is null, empty or only has nulls are null.
+/// # Errors
+/// This function errors iff `array` is not a list array of numeric or temporal values.
+pub fn list_min(array: &dyn Array) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    dyn_aggregate!(min_list, Lists::try_new(array)?, "Min", [
+        Int8 => i8, Int16 => i16, Int32 | Date32 | Time32(_) => i32,
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => i64,
+        UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64,
+        Float32 => f32, Float64 => f64
+    ])
+}
+
+/// Returns the maximum of the values of every list of numeric or temporal values of `array`,
+/// ignoring nulls. Slots whose list is null, empty or only has nulls are null.
+/// # Errors
+/// This function errors iff `array` is not a list array of numeric or temporal values.
+pub fn list_max(array: &dyn Array) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    dyn_aggregate!(max_list, Lists::try_new(array)?, "Max", [
+        Int8 => i8, Int16 => i16, Int32 | Date32 | Time32(_) => i32,
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => i64,
+        UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64,
+        Float32 => f32, Float64 => f64
+    ])
+}
+
+/// Returns the mean of the values of every list of numeric values of `array`, ignoring nulls.
+/// Slots whose list is null, empty or only has nulls are null.
+/// # Errors
+/// This function errors iff `array` is not a list array of numeric values.
+pub fn list_mean(array: &dyn Array) -> Result<PrimitiveArray<f64>> {
+    use DataType::*;
+    dyn_aggregate!(mean_list, Lists::try_new(array)?, "Mean", [
+        Int8 => i8, Int16 => i16, Int32 => i32, Int64 => i64,
+        UInt8 => u8, UInt16 => u16, UInt32 => u32, UInt64 => u64,
+        Float32 => f32, Float64 => f64
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::Field;
+
+    fn new_list<O: Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<O> {
+        let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data).unwrap();
+        array.into()
+    }
+
+    fn new_fixed_size_list() -> FixedSizeListArray {
+        let values = Int32Array::from(&[
+            Some(3),
+            None,
+            Some(1),
+            Some(1),
+            Some(5),
+            Some(4),
+            None,
+            None,
+            Some(2),
+        ]);
+        let data_type =
+            DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 3);
+        FixedSizeListArray::from_data(
+            data_type,
+            Arc::new(values),
+            Some(Bitmap::from([true, false, true])),
+        )
+    }
+
+    #[test]
+    fn flatten_and_explode_sliced() {
+        let array = new_list::<i64>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![Some(2), None]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let array = array.slice(1, 3);
+
+        let (values, indices) = explode(&array).unwrap();
+        assert_eq!(Int32Array::from(&[Some(2), None, Some(3)]), values.as_ref());
+        assert_eq!(indices, UInt64Array::from_slice(&[0, 0, 2]));
+
+        let result = list_get(&array, 1).unwrap();
+        assert_eq!(Int32Array::from(&[None, None, None]), result.as_ref());
+        let result = list_get(&array, -2).unwrap();
+        assert_eq!(Int32Array::from(&[Some(2), None, None]), result.as_ref());
+
+        let result = list_len(&array).unwrap();
+        let expected = Int64Array::from(&[Some(2), None, Some(1)]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+    }
+
+    #[test]
+    fn fixed_size_list() {
+        let array = new_fixed_size_list();
+
+        let result = list_sort(&array, &SortOptions::default()).unwrap();
+        let values = Int32Array::from(&[
+            None,
+            Some(1),
+            Some(3),
+            Some(1),
+            Some(4),
+            Some(5),
+            None,
+            None,
+            Some(2),
+        ]);
+        let expected = FixedSizeListArray::from_data(
+            array.data_type().clone(),
+            Arc::new(values),
+            array.validity().clone(),
+        );
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let result = list_slice(&array, 1, None).unwrap();
+        let expected = new_list::<i32>(vec![
+            Some(vec![None, Some(1)]),
+            None,
+            Some(vec![None, Some(2)]),
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let result = list_unique(&array).unwrap();
+        let expected = new_list::<i32>(vec![
+            Some(vec![Some(3), None, Some(1)]),
+            None,
+            Some(vec![None, Some(2)]),
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+    }
+
+    #[test]
+    fn aggregates() {
+        let array = new_fixed_size_list();
+        assert_eq!(
+            Int32Array::from(&[Some(4), None, Some(2)]),
+            list_sum(&array).unwrap().as_ref()
+        );
+        assert_eq!(
+            Int32Array::from(&[Some(1), None, Some(2)]),
+            list_min(&array).unwrap().as_ref()
+        );
+        assert_eq!(
+            Int32Array::from(&[Some(3), None, Some(2)]),
+            list_max(&array).unwrap().as_ref()
+        );
+        assert_eq!(
+            list_mean(&array).unwrap(),
+            Float64Array::from(&[Some(2.0), None, Some(2.0)])
+        );
+
+        let array = new_list::<i32>(vec![Some(vec![]), Some(vec![None])]);
+        assert_eq!(list_mean(&array).unwrap().null_count(), 2);
+
+        let array = Int32Array::from_slice(&[1]);
+        assert!(list_sum(&array).is_err());
+    }
+}
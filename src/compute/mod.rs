@@ -21,19 +21,30 @@ pub mod hash;
 pub mod if_then_else;
 pub mod length;
 pub mod limit;
+pub mod list;
 pub mod nullif;
+pub mod nulls;
 pub mod partition;
+pub mod row;
 pub mod sort;
+pub mod strings;
+pub mod structure;
 pub mod substring;
 pub mod take;
 pub mod temporal;
 mod utils;
 pub mod window;
 
+#[cfg(feature = "io_json")]
+pub mod json;
 #[cfg(feature = "regex")]
 pub mod like;
 #[cfg(feature = "regex")]
+pub mod regex_extract;
+#[cfg(feature = "regex")]
 pub mod regex_match;
+#[cfg(feature = "regex")]
+pub mod regex_replace;
 
 #[cfg(feature = "merge_sort")]
 pub mod merge_sort;
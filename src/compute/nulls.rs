@@ -0,0 +1,314 @@
+//! Defines kernels to replace or remove the null values of an [`Array`].
+//!
+//! All kernels support the same types as [`make_growable`] and return a clone of the array
+//! (an `O(1)` operation) when it has no null values.
+use crate::array::{clone, Array, BooleanArray};
+use crate::error::{ArrowError, Result};
+
+use super::{filter::filter, utils::build_from_slots};
+
+/// Returns an array whose slots are the first non-null value of the slot in `arrays`, or null
+/// when all of them are null. This has the same semantics as `COALESCE` in SQL.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::nulls::coalesce;
+/// use arrow2::array::Int32Array;
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int32Array::from(&[Some(4), Some(5), None]);
+/// let result = coalesce(&[&a, &b])?;
+///
+/// assert_eq!(Int32Array::from(&[Some(1), Some(5), None]), result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty or the arrays do not have the same data type
+/// and length.
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Coalesce requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.data_type() != first.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Coalesce requires all arrays to have the same data type".to_string(),
+        ));
+    }
+    if arrays.iter().any(|x| x.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Coalesce requires all arrays to have the same length".to_string(),
+        ));
+    }
+    if first.null_count() == 0 {
+        return Ok(clone(*first));
+    }
+
+    // arrays after the first one without nulls are never reached
+    let last = arrays
+        .iter()
+        .position(|x| x.null_count() == 0)
+        .unwrap_or(arrays.len() - 1);
+    let arrays = &arrays[..=last];
+    let use_validity = arrays[last].null_count() > 0;
+
+    let sources = (0..first.len()).map(|row| {
+        arrays
+            .iter()
+            .position(|x| x.is_valid(row))
+            .map(|index| (index, row))
+    });
+    Ok(build_from_slots(arrays, use_validity, first.len(), sources))
+}
+
+/// Returns `array` with its null values replaced by `value`, an array with a single slot.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::nulls::fill_null;
+/// use arrow2::array::Utf8Array;
+///
+/// # fn main() -> Result<()> {
+/// let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+/// let value = Utf8Array::<i32>::from_slice(&["b"]);
+/// let result = fill_null(&array, &value)?;
+///
+/// assert_eq!(Utf8Array::<i32>::from_slice(&["a", "b", "c"]), result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `value` does not have the data type of `array` or its length
+/// is not 1.
+pub fn fill_null(array: &dyn Array, value: &dyn Array) -> Result<Box<dyn Array>> {
+    if array.data_type() != value.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Fill null requires the value to have the data type of the array ({:?} != {:?})",
+            value.data_type(),
+            array.data_type()
+        )));
+    }
+    if value.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Fill null requires the value to have a single slot, but it has {}",
+            value.len()
+        )));
+    }
+    if array.null_count() == 0 || value.null_count() == 1 {
+        return Ok(clone(array));
+    }
+
+    let sources = (0..array.len()).map(|row| {
+        if array.is_valid(row) {
+            Some((0, row))
+        } else {
+            Some((1, 0))
+        }
+    });
+    Ok(build_from_slots(
+        &[array, value],
+        false,
+        array.len(),
+        sources,
+    ))
+}
+
+/// Returns `array` with its null values replaced by the last non-null value before them.
+/// When `limit` is set, at most `limit` consecutive nulls are replaced.
+/// Nulls before the first non-null value remain null.
+/// # Example
+/// ```rust
+/// use arrow2::compute::nulls::fill_null_forward;
+/// use arrow2::array::Int32Array;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, None, Some(2), None]);
+///
+/// let result = fill_null_forward(&array, None);
+/// let expected = Int32Array::from(&[None, Some(1), Some(1), Some(1), Some(2), Some(2)]);
+/// assert_eq!(expected, result.as_ref());
+///
+/// let result = fill_null_forward(&array, Some(1));
+/// let expected = Int32Array::from(&[None, Some(1), Some(1), None, Some(2), Some(2)]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn fill_null_forward(array: &dyn Array, limit: Option<usize>) -> Box<dyn Array> {
+    if array.null_count() == 0 {
+        return clone(array);
+    }
+    let sources = fill_sources(array, limit, 0..array.len());
+    build_from_slots(&[array], true, array.len(), sources.into_iter())
+}
+
+/// Returns `array` with its null values replaced by the first non-null value after them.
+/// When `limit` is set, at most `limit` consecutive nulls are replaced.
+/// Nulls after the last non-null value remain null.
+/// # Example
+/// ```rust
+/// use arrow2::compute::nulls::fill_null_backward;
+/// use arrow2::array::Int32Array;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, None, Some(2), None]);
+///
+/// let result = fill_null_backward(&array, None);
+/// let expected = Int32Array::from(&[Some(1), Some(1), Some(2), Some(2), Some(2), None]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn fill_null_backward(array: &dyn Array, limit: Option<usize>) -> Box<dyn Array> {
+    if array.null_count() == 0 {
+        return clone(array);
+    }
+    let mut sources = fill_sources(array, limit, (0..array.len()).rev());
+    sources.reverse();
+    build_from_slots(&[array], true, array.len(), sources.into_iter())
+}
+
+/// Returns the source of every row of `rows` when filling nulls with the last non-null row
+/// visited, in the order of `rows`.
+fn fill_sources<I: Iterator<Item = usize>>(
+    array: &dyn Array,
+    limit: Option<usize>,
+    rows: I,
+) -> Vec<Option<(usize, usize)>> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut last_valid = None;
+    let mut filled = 0;
+    rows.map(|row| {
+        if array.is_valid(row) {
+            last_valid = Some(row);
+            filled = 0;
+            Some((0, row))
+        } else if filled < limit {
+            filled += 1;
+            last_valid.map(|x| (0, x))
+        } else {
+            None
+        }
+    })
+    .collect()
+}
+
+/// Returns `array` without its null values.
+/// # Example
+/// ```rust
+/// use arrow2::compute::nulls::drop_nulls;
+/// use arrow2::array::Int32Array;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, Some(2)]);
+/// let result = drop_nulls(&array);
+/// assert_eq!(Int32Array::from_slice(&[1, 2]), result.as_ref());
+/// ```
+pub fn drop_nulls(array: &dyn Array) -> Box<dyn Array> {
+    match array.validity() {
+        Some(validity) if validity.null_count() > 0 => {
+            let predicate = BooleanArray::from_data(validity.clone(), None);
+            // all types supported by `make_growable` are supported by `filter`
+            filter(array, &predicate).unwrap()
+        }
+        _ => clone(array),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::array::*;
+    use crate::bitmap::Bitmap;
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field};
+
+    #[test]
+    fn test_coalesce() {
+        let a = Utf8Array::<i32>::from(&[Some("a"), None, None, None]);
+        let b = Utf8Array::<i32>::from(&[Some("b"), None, Some("c"), None]);
+        let c = Utf8Array::<i32>::from(&[None, Some("d"), Some("e"), None]);
+        let result = coalesce(&[&a, &b, &c]).unwrap();
+        let expected = Utf8Array::<i32>::from(&[Some("a"), Some("d"), Some("c"), None]);
+        assert_eq!(expected, result.as_ref());
+
+        let d = Utf8Array::<i32>::from_slice(&["f", "g", "h", "i"]);
+        let result = coalesce(&[&a, &d, &c]).unwrap();
+        let expected = Utf8Array::<i32>::from_slice(&["a", "g", "h", "i"]);
+        assert_eq!(expected, result.as_ref());
+
+        let result = coalesce(&[&d, &a]).unwrap();
+        assert_eq!(&d as &dyn Array, result.as_ref());
+
+        let e = Int32Array::from_slice(&[1, 2, 3, 4]);
+        assert!(coalesce(&[&a, &e]).is_err());
+        assert!(coalesce(&[&a, &d.slice(0, 2)]).is_err());
+        assert!(coalesce(&[]).is_err());
+    }
+
+    #[test]
+    fn test_fill_null() {
+        let array = ListArray::<i32>::from_data(
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            Buffer::from(&[0, 2, 2, 3]),
+            Arc::new(Int32Array::from_slice(&[1, 2, 3])),
+            Some(Bitmap::from([true, false, true])),
+        );
+        let value = array.slice(2, 1);
+        let result = fill_null(&array, &value).unwrap();
+        let expected = ListArray::<i32>::from_data(
+            array.data_type().clone(),
+            Buffer::from(&[0, 2, 3, 4]),
+            Arc::new(Int32Array::from_slice(&[1, 2, 3, 3])),
+            None,
+        );
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let value = Int32Array::from(&[None]);
+        let array = Int32Array::from(&[Some(1), None]);
+        let result = fill_null(&array, &value).unwrap();
+        assert_eq!(&array as &dyn Array, result.as_ref());
+
+        let value = Int32Array::from_slice(&[1, 2]);
+        assert!(fill_null(&array, &value).is_err());
+    }
+
+    #[test]
+    fn test_fill_null_forward_backward() {
+        let array = BooleanArray::from(&[None, Some(true), None, None, None, Some(false), None]);
+
+        let result = fill_null_forward(&array, Some(2));
+        let expected = BooleanArray::from(&[
+            None,
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let result = fill_null_backward(&array, Some(2));
+        let expected = BooleanArray::from(&[
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+        ]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let array = BooleanArray::from(&[None, None]);
+        assert_eq!(fill_null_forward(&array, None).null_count(), 2);
+    }
+
+    #[test]
+    fn test_drop_nulls() {
+        let array = Utf8Array::<i32>::from(&[None, Some("a"), None, Some("b"), None]);
+        let result = drop_nulls(&array);
+        let expected = Utf8Array::<i32>::from_slice(&["a", "b"]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let array = Utf8Array::<i32>::from_slice(&["a", "b"]);
+        assert_eq!(&array as &dyn Array, drop_nulls(&array).as_ref());
+    }
+}
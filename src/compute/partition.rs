@@ -17,8 +17,13 @@
 
 //! Defines partition kernel for `ArrayRef`
 
-use crate::compute::sort::{build_compare, Compare, SortColumn};
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::concat::concatenate;
+use crate::compute::hash::{hash_columns_with_options, HashOptions};
+use crate::compute::sort::{build_compare, Compare, SortColumn, SortOptions};
+use crate::compute::take::take;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
 use std::cmp::Ordering;
 use std::iter::Iterator;
 use std::ops::Range;
@@ -115,6 +120,202 @@ impl<'a> Iterator for LexicographicalPartitionIterator<'a> {
     }
 }
 
+/// Returns the columns of `batch` at `indices`.
+fn get_columns<'a>(batch: &'a RecordBatch, indices: &[usize]) -> Result<Vec<&'a dyn Array>> {
+    indices
+        .iter()
+        .map(|index| {
+            batch
+                .columns()
+                .get(*index)
+                .map(|x| x.as_ref())
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Unable to partition by column {}, as the batch has {} columns",
+                        index,
+                        batch.num_columns()
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Splits `batch` into one batch per partition, where `partitions` is the partition of every
+/// row of `batch`. Every batch is returned with the indices of its rows in `batch`.
+fn split(
+    batch: &RecordBatch,
+    partitions: impl Iterator<Item = usize>,
+    num_partitions: usize,
+) -> Result<Vec<(RecordBatch, PrimitiveArray<u64>)>> {
+    let mut indices = vec![vec![]; num_partitions];
+    partitions
+        .enumerate()
+        .for_each(|(row, partition)| indices[partition].push(row as u64));
+
+    indices
+        .into_iter()
+        .map(|indices| {
+            let indices = PrimitiveArray::<u64>::from_values(indices);
+            let columns = batch
+                .columns()
+                .iter()
+                .map(|x| take(x.as_ref(), &indices).map(|x| x.into()))
+                .collect::<Result<Vec<_>>>()?;
+            let batch = RecordBatch::try_new(batch.schema().clone(), columns)?;
+            Ok((batch, indices))
+        })
+        .collect()
+}
+
+/// Partitions the rows of `batch` into `num_partitions` batches by the hash of the columns at
+/// `key_columns`, so that rows with equal keys are in the same partition. Every partition is
+/// returned with the indices of its rows in `batch`, and partitions may be empty.
+/// See [`hash_partition_with_options`] to use a hash that is stable across versions.
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::partition::hash_partition;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::record_batch::RecordBatch;
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let a = Int32Array::from_slice(&[1, 2, 1, 3, 2]);
+/// let batch = RecordBatch::try_new(schema, vec![Arc::new(a)]).unwrap();
+///
+/// let partitions = hash_partition(&batch, &[0], 4).unwrap();
+/// assert_eq!(partitions.len(), 4);
+/// let rows = partitions.iter().map(|(batch, _)| batch.num_rows()).sum::<usize>();
+/// assert_eq!(rows, 5);
+/// ```
+/// # Errors
+/// This function errors iff `num_partitions` is 0, `key_columns` is empty or out of bounds,
+/// or the key columns cannot be hashed (see [`hash_columns_with_options`]).
+pub fn hash_partition(
+    batch: &RecordBatch,
+    key_columns: &[usize],
+    num_partitions: usize,
+) -> Result<Vec<(RecordBatch, PrimitiveArray<u64>)>> {
+    hash_partition_with_options(batch, key_columns, num_partitions, &HashOptions::default())
+}
+
+/// Same as [`hash_partition`], but hashing the key columns with `options`.
+/// # Errors
+/// This function errors iff `num_partitions` is 0, `key_columns` is empty or out of bounds,
+/// or the key columns cannot be hashed (see [`hash_columns_with_options`]).
+pub fn hash_partition_with_options(
+    batch: &RecordBatch,
+    key_columns: &[usize],
+    num_partitions: usize,
+    options: &HashOptions,
+) -> Result<Vec<(RecordBatch, PrimitiveArray<u64>)>> {
+    if num_partitions == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Hash partitioning requires at least one partition".to_string(),
+        ));
+    }
+    let columns = get_columns(batch, key_columns)?;
+    let hashes = hash_columns_with_options(&columns, options)?;
+    let partitions = hashes
+        .values()
+        .iter()
+        .map(|hash| (hash % num_partitions as u64) as usize);
+    split(batch, partitions, num_partitions)
+}
+
+/// Partitions the rows of `batch` by ranges of the columns at `sort_columns`, ordered by their
+/// [`SortOptions`]. `boundaries` contains an array per sort column with the values of the `k`
+/// boundaries, which must be sorted in that order. Returns `k + 1` partitions, where partition
+/// `i` contains the rows that are at or after boundary `i - 1` and before boundary `i`. Every
+/// partition is returned with the indices of its rows in `batch`, and partitions may be empty.
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, UInt64Array};
+/// use arrow2::compute::partition::range_partition;
+/// use arrow2::compute::sort::SortOptions;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::record_batch::RecordBatch;
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+/// let a = Int32Array::from(&[Some(5), Some(1), None, Some(10), Some(7)]);
+/// let batch = RecordBatch::try_new(schema, vec![Arc::new(a)]).unwrap();
+///
+/// let boundaries = Int32Array::from_slice(&[5, 10]);
+/// let partitions =
+///     range_partition(&batch, &[(0, SortOptions::default())], &[&boundaries]).unwrap();
+///
+/// let indices = partitions.iter().map(|(_, x)| x.clone()).collect::<Vec<_>>();
+/// assert_eq!(
+///     indices,
+///     vec![
+///         UInt64Array::from_slice(&[1, 2]),
+///         UInt64Array::from_slice(&[0, 4]),
+///         UInt64Array::from_slice(&[3])
+///     ]
+/// );
+/// ```
+/// # Errors
+/// This function errors iff `sort_columns` is empty or out of bounds, `boundaries` does not
+/// have an array per sort column of its data type, the boundaries do not have the same length,
+/// or the sort columns cannot be compared.
+pub fn range_partition(
+    batch: &RecordBatch,
+    sort_columns: &[(usize, SortOptions)],
+    boundaries: &[&dyn Array],
+) -> Result<Vec<(RecordBatch, PrimitiveArray<u64>)>> {
+    let indices = sort_columns.iter().map(|x| x.0).collect::<Vec<_>>();
+    let columns = get_columns(batch, &indices)?;
+    if columns.is_empty() || columns.len() != boundaries.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Range partitioning requires at least one sort column and an array of boundaries per sort column (got {} and {})",
+            columns.len(),
+            boundaries.len()
+        )));
+    }
+    let num_boundaries = boundaries[0].len();
+    if boundaries.iter().any(|x| x.len() != num_boundaries) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Range partitioning requires all boundaries to have the same length".to_string(),
+        ));
+    }
+
+    // rows and boundaries are compared within a single array, with boundary `i` at `num_rows + i`
+    let num_rows = batch.num_rows();
+    let arrays = columns
+        .iter()
+        .zip(boundaries.iter())
+        .map(|(column, boundary)| {
+            if column.data_type() != boundary.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Range partitioning requires boundaries of the data type of their column ({:?} != {:?})",
+                    boundary.data_type(),
+                    column.data_type()
+                )));
+            }
+            concatenate(&[*column, *boundary])
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let comparators = arrays
+        .iter()
+        .zip(sort_columns.iter())
+        .map(|(array, (_, options))| build_compare(array.as_ref(), *options))
+        .collect::<Result<Vec<_>>>()?;
+    let compare = |a: usize, b: usize| {
+        comparators
+            .iter()
+            .map(|comparator| comparator(a, b))
+            .find(|x| *x != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    };
+
+    let boundaries = (num_rows..num_rows + num_boundaries).collect::<Vec<_>>();
+    let partitions = (0..num_rows).map(|row| {
+        boundaries.partition_point(|boundary| compare(*boundary, row) != Ordering::Greater)
+    });
+    split(batch, partitions, num_boundaries + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,6 +323,81 @@ mod tests {
     use crate::compute::sort::SortOptions;
     use crate::datatypes::DataType;
 
+    fn batch() -> RecordBatch {
+        use crate::datatypes::{Field, Schema};
+        use std::sync::Arc;
+
+        let a = Int32Array::from(&[Some(1), Some(2), Some(1), None, Some(2), Some(3)]);
+        let b = Utf8Array::<i32>::from_slice(&["x", "y", "x", "z", "w", "x"]);
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)]).unwrap()
+    }
+
+    #[test]
+    fn test_hash_partition() -> Result<()> {
+        let batch = batch();
+        let partitions = hash_partition(&batch, &[0, 1], 3)?;
+        assert_eq!(partitions.len(), 3);
+
+        let mut rows = vec![];
+        for (partition, indices) in partitions.iter() {
+            let expected = take(batch.column(1).as_ref(), indices)?;
+            assert_eq!(partition.column(1).as_ref(), expected.as_ref());
+            rows.extend(indices.values().iter().map(|x| *x as usize));
+        }
+        rows.sort_unstable();
+        assert_eq!(rows, vec![0, 1, 2, 3, 4, 5]);
+
+        // rows 0 and 2 have equal keys
+        let partition_of = |row: u64| {
+            partitions
+                .iter()
+                .position(|(_, indices)| indices.values().contains(&row))
+        };
+        assert_eq!(partition_of(0), partition_of(2));
+
+        assert!(hash_partition(&batch, &[0], 0).is_err());
+        assert!(hash_partition(&batch, &[2], 2).is_err());
+        assert!(hash_partition(&batch, &[], 2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_partition() -> Result<()> {
+        let batch = batch();
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let a = Int32Array::from_slice(&[2, 1]);
+        let b = Utf8Array::<i32>::from_slice(&["x", "x"]);
+        let partitions = range_partition(
+            &batch,
+            &[(0, descending), (1, SortOptions::default())],
+            &[&a, &b],
+        )?;
+
+        let indices = partitions
+            .iter()
+            .map(|(_, indices)| indices.values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(indices, vec![vec![4, 5], vec![1], vec![0, 2, 3]]);
+        assert_eq!(partitions[2].0.column(0).null_count(), 1);
+
+        let partitions = range_partition(&batch, &[(0, descending)], &[&a.slice(0, 0)])?;
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].0.num_rows(), 6);
+
+        let sort_columns = [(0, descending)];
+        assert!(range_partition(&batch, &sort_columns, &[&b]).is_err());
+        assert!(range_partition(&batch, &sort_columns, &[&a, &b]).is_err());
+        assert!(range_partition(&batch, &[], &[]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_lexicographical_partition_ranges_empty() {
         let input = vec![];
@@ -0,0 +1,245 @@
+//! Defines kernels that extract capture groups of a regex from [`Utf8Array`]s.
+use std::sync::Arc;
+
+use regex::Regex;
+
+use super::regex_match::{check_lengths, compile, try_for_each_regex, COMPILE_ERROR};
+use super::utils::combine_validities;
+use crate::array::{Array, ListArray, MutableArray, MutableUtf8Array, Offset, Utf8Array};
+use crate::bitmap::Bitmap;
+use crate::buffer::MutableBuffer;
+use crate::error::{ArrowError, Result};
+
+fn check_group(regex: &Regex, group_idx: usize) -> Result<()> {
+    if group_idx < regex.captures_len() {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "The regex \"{}\" has no capture group {}",
+            regex.as_str(),
+            group_idx
+        )))
+    }
+}
+
+#[inline]
+fn extract<'a>(haystack: &'a str, regex: &Regex, group_idx: usize) -> Option<&'a str> {
+    regex
+        .captures(haystack)
+        .and_then(|captures| captures.get(group_idx))
+        .map(|m| m.as_str())
+}
+
+#[inline]
+fn extract_all<O: Offset>(
+    values: &mut MutableUtf8Array<O>,
+    haystack: &str,
+    regex: &Regex,
+    group_idx: usize,
+) {
+    regex
+        .captures_iter(haystack)
+        .for_each(|captures| values.push(captures.get(group_idx).map(|m| m.as_str())))
+}
+
+/// Returns the capture group `group_idx` of the first match of the regex in `pattern` in every
+/// value of `values`. Group `0` is the whole match. Every distinct pattern is compiled once.
+///
+/// Slots where either `values` or `pattern` is null, where the pattern does not match, or where
+/// the group does not participate in the match are null.
+/// # Errors
+/// This function errors when the arrays have different lengths, a pattern is not a valid
+/// regex or a pattern has no group `group_idx`.
+pub fn regex_extract<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &Utf8Array<O>,
+    group_idx: usize,
+) -> Result<Utf8Array<O>> {
+    let mut result = MutableUtf8Array::<O>::with_capacity(values.len());
+    try_for_each_regex(values, pattern, COMPILE_ERROR, |pair| {
+        match pair {
+            Some((haystack, regex)) => {
+                check_group(regex, group_idx)?;
+                result.push(extract(haystack, regex, group_idx))
+            }
+            None => result.push::<&str>(None),
+        };
+        Ok(())
+    })?;
+    Ok(result.into())
+}
+
+/// Returns the capture group `group_idx` of the first match of the regex `pattern` in every
+/// value of `values`. Group `0` is the whole match.
+///
+/// Slots where `values` is null, where the pattern does not match, or where the group does not
+/// participate in the match are null.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_extract::regex_extract_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("key=value"), None, Some("key")]);
+/// let result = regex_extract_scalar(&array, r"(\w+)=(\w+)", 2).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("value"), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex or has no group `group_idx`.
+pub fn regex_extract_scalar<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    group_idx: usize,
+) -> Result<Utf8Array<O>> {
+    let regex = compile(pattern)?;
+    check_group(&regex, group_idx)?;
+
+    Ok(values
+        .iter()
+        .map(|haystack| haystack.and_then(|haystack| extract(haystack, &regex, group_idx)))
+        .collect::<MutableUtf8Array<O>>()
+        .into())
+}
+
+fn list<O: Offset>(
+    offsets: MutableBuffer<O>,
+    values: MutableUtf8Array<O>,
+    validity: Option<Bitmap>,
+) -> ListArray<O> {
+    let values: Utf8Array<O> = values.into();
+    let data_type = ListArray::<O>::default_datatype(values.data_type().clone());
+    ListArray::<O>::from_data(data_type, offsets.into(), Arc::new(values), validity)
+}
+
+/// Returns the capture group `group_idx` of every match of the regex in `pattern` in every
+/// value of `values`, as a [`ListArray`]. Group `0` is the whole match. Every distinct
+/// pattern is compiled once.
+///
+/// Slots where either `values` or `pattern` is null are null lists; values without matches
+/// result in empty lists, and groups that do not participate in a match are null items.
+/// # Errors
+/// This function errors when the arrays have different lengths, a pattern is not a valid
+/// regex or a pattern has no group `group_idx`.
+pub fn regex_extract_all<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &Utf8Array<O>,
+    group_idx: usize,
+) -> Result<ListArray<O>> {
+    check_lengths(values, pattern)?;
+    let validity = combine_validities(values.validity(), pattern.validity());
+
+    let mut offsets = MutableBuffer::<O>::with_capacity(values.len() + 1);
+    offsets.push(O::zero());
+    let mut items = MutableUtf8Array::<O>::new();
+    try_for_each_regex(values, pattern, COMPILE_ERROR, |pair| {
+        if let Some((haystack, regex)) = pair {
+            check_group(regex, group_idx)?;
+            extract_all(&mut items, haystack, regex, group_idx);
+        }
+        offsets.push(O::from_usize(items.len()).ok_or(ArrowError::KeyOverflowError)?);
+        Ok(())
+    })?;
+
+    Ok(list(offsets, items, validity))
+}
+
+/// Returns the capture group `group_idx` of every match of the regex `pattern` in every
+/// value of `values`, as a [`ListArray`]. Group `0` is the whole match.
+///
+/// Null values result in null lists; values without matches result in empty lists, and groups
+/// that do not participate in a match are null items.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::regex_extract::regex_extract_all_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a1b22"), None, Some("c")]);
+/// let result = regex_extract_all_scalar(&array, r"\d+", 0).unwrap();
+/// let values = result.values().as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+/// assert_eq!(values, &Utf8Array::<i32>::from_slice(&["1", "22"]));
+/// assert_eq!(result.offsets().as_slice(), &[0, 2, 2, 2]);
+/// ```
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex or has no group `group_idx`.
+pub fn regex_extract_all_scalar<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    group_idx: usize,
+) -> Result<ListArray<O>> {
+    let regex = compile(pattern)?;
+    check_group(&regex, group_idx)?;
+
+    let mut offsets = MutableBuffer::<O>::with_capacity(values.len() + 1);
+    offsets.push(O::zero());
+    let mut items = MutableUtf8Array::<O>::new();
+    for haystack in values.iter() {
+        if let Some(haystack) = haystack {
+            extract_all(&mut items, haystack, &regex, group_idx);
+        }
+        offsets.push(O::from_usize(items.len()).ok_or(ArrowError::KeyOverflowError)?);
+    }
+
+    Ok(list(offsets, items, values.validity().clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_per_row() {
+        let values = Utf8Array::<i32>::from(&[Some("a1"), Some("a1"), None, Some("b")]);
+        let pattern = Utf8Array::<i32>::from(&[Some(r"(\d)"), Some(r"(a)"), Some("a"), None]);
+
+        let result = regex_extract(&values, &pattern, 1).unwrap();
+        let expected = Utf8Array::<i32>::from(&[Some("1"), Some("a"), None, None]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn extract_optional_group() {
+        let values = Utf8Array::<i64>::from_slice(&["ab", "b", "c"]);
+        let result = regex_extract_scalar(&values, "(a)?b", 1).unwrap();
+        let expected = Utf8Array::<i64>::from(&[Some("a"), None, None]);
+        assert_eq!(result, expected);
+
+        let result = regex_extract_scalar(&values, "(a)?b", 0).unwrap();
+        let expected = Utf8Array::<i64>::from(&[Some("ab"), Some("b"), None]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn extract_all_per_row() {
+        let values = Utf8Array::<i32>::from(&[Some("k1=v1,k2=v2"), Some("x"), None]);
+        let pattern = Utf8Array::<i32>::from(&[Some(r"(\w+)=(\w+)"), Some("(y)(z)"), Some("(a)")]);
+
+        let result = regex_extract_all(&values, &pattern, 2).unwrap();
+        let items = result
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        assert_eq!(items, &Utf8Array::<i32>::from_slice(&["v1", "v2"]));
+        assert_eq!(result.offsets().as_slice(), &[0, 2, 2, 2]);
+        assert_eq!(result.null_count(), 1);
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn errors() {
+        let values = Utf8Array::<i32>::from_slice(&["a"]);
+        assert!(regex_extract_scalar(&values, "(a)", 2).is_err());
+        assert!(regex_extract_all_scalar(&values, "(", 0).is_err());
+
+        let pattern = Utf8Array::<i32>::from_slice(&["a"]);
+        assert!(regex_extract(&values, &pattern, 1).is_err());
+        assert!(regex_extract_all(&values, &pattern, 1).is_err());
+
+        let pattern = Utf8Array::<i32>::from_slice(&["a", "b"]);
+        assert!(regex_extract(&values, &pattern, 0).is_err());
+
+        let values = Utf8Array::<i32>::from(&[Some("a"), None]);
+        let pattern = Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]);
+        assert!(regex_extract(&values, &pattern, 0).is_err());
+        assert!(regex_extract_all(&values, &pattern, 0).is_err());
+    }
+}
@@ -22,44 +22,103 @@ use regex::Regex;
 use super::utils::{combine_validities, unary_utf8_boolean};
 use crate::array::{BooleanArray, Offset, Utf8Array};
 use crate::error::{ArrowError, Result};
-use crate::{array::*, bitmap::Bitmap};
+use crate::{array::*, bitmap::MutableBitmap};
 
-pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Result<BooleanArray> {
-    if values.len() != regex.len() {
+/// A cache of compiled [`Regex`]es, keyed by their pattern, so that every distinct pattern
+/// of an array is compiled once.
+pub(crate) struct RegexCache<'a> {
+    map: HashMap<&'a str, Regex>,
+    message: &'static str,
+}
+
+impl<'a> RegexCache<'a> {
+    /// Returns an empty cache whose errors start with `message`.
+    pub(crate) fn new(message: &'static str) -> Self {
+        Self {
+            map: HashMap::new(),
+            message,
+        }
+    }
+
+    /// Returns the compiled `pattern`, compiling it if it was not yet seen.
+    pub(crate) fn get(&mut self, pattern: &'a str) -> Result<&Regex> {
+        if !self.map.contains_key(pattern) {
+            self.map
+                .insert(pattern, compile_with_message(pattern, self.message)?);
+        }
+        Ok(&self.map[pattern])
+    }
+}
+
+fn compile_with_message(pattern: &str, message: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| ArrowError::InvalidArgumentError(format!("{}: {}", message, e)))
+}
+
+/// The start of the error of patterns that are not a valid regex.
+pub(crate) const COMPILE_ERROR: &str = "Unable to compile regex";
+
+/// Compiles `pattern`, mapping errors to [`ArrowError::InvalidArgumentError`].
+pub(crate) fn compile(pattern: &str) -> Result<Regex> {
+    compile_with_message(pattern, COMPILE_ERROR)
+}
+
+/// Errors when `values` and `patterns` have different lengths. Kernels must call this before
+/// combining their validities.
+pub(crate) fn check_lengths<O: Offset>(
+    values: &Utf8Array<O>,
+    patterns: &Utf8Array<O>,
+) -> Result<()> {
+    if values.len() != patterns.len() {
         return Err(ArrowError::InvalidArgumentError(
             "Cannot perform comparison operation on arrays of different length".to_string(),
         ));
     }
+    Ok(())
+}
+
+/// Calls `op` with every value of `values` and its compiled pattern in `patterns`, or with
+/// `None` when either of them is null.
+/// # Errors
+/// This function errors when the arrays have different lengths, when a pattern is not a
+/// valid regex (with an error starting with `message`), or when `op` errors.
+pub(crate) fn try_for_each_regex<'a, O, F>(
+    values: &'a Utf8Array<O>,
+    patterns: &'a Utf8Array<O>,
+    message: &'static str,
+    mut op: F,
+) -> Result<()>
+where
+    O: Offset,
+    F: FnMut(Option<(&'a str, &Regex)>) -> Result<()>,
+{
+    check_lengths(values, patterns)?;
+
+    let mut cache = RegexCache::new(message);
+    values
+        .iter()
+        .zip(patterns.iter())
+        .try_for_each(|(haystack, pattern)| match (haystack, pattern) {
+            (Some(haystack), Some(pattern)) => op(Some((haystack, cache.get(pattern)?))),
+            // regex is expensive => short-circuit if null
+            _ => op(None),
+        })
+}
 
-    let mut map = HashMap::new();
+pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Result<BooleanArray> {
+    check_lengths(values, regex)?;
     let validity = combine_validities(values.validity(), regex.validity());
 
-    let iterator = values.iter().zip(regex.iter()).map(|(haystack, regex)| {
-        if haystack.is_none() | regex.is_none() {
-            // regex is expensive => short-circuit if null
-            return Result::Ok(false);
-        };
-        let haystack = haystack.unwrap();
-        let regex = regex.unwrap();
-
-        let regex = if let Some(regex) = map.get(regex) {
-            regex
-        } else {
-            let re = Regex::new(regex).map_err(|e| {
-                ArrowError::InvalidArgumentError(format!(
-                    "Unable to build regex from LIKE pattern: {}",
-                    e
-                ))
-            })?;
-            map.insert(regex, re);
-            map.get(regex).unwrap()
-        };
-
-        Ok(regex.is_match(haystack))
-    });
-    let new_values = Bitmap::try_from_trusted_len_iter(iterator)?;
-
-    Ok(BooleanArray::from_data(new_values, validity))
+    let mut new_values = MutableBitmap::with_capacity(values.len());
+    let message = "Unable to build regex from LIKE pattern";
+    try_for_each_regex(values, regex, message, |pair| {
+        new_values.push(
+            pair.map(|(haystack, regex)| regex.is_match(haystack))
+                .unwrap_or(false),
+        );
+        Ok(())
+    })?;
+
+    Ok(BooleanArray::from_data(new_values.into(), validity))
 }
 
 /// Regex matches
@@ -74,8 +133,7 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
 /// assert_eq!(result, BooleanArray::from_slice(&vec![true, false, true]));
 /// ```
 pub fn regex_match_scalar<O: Offset>(values: &Utf8Array<O>, regex: &str) -> Result<BooleanArray> {
-    let regex = Regex::new(regex)
-        .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+    let regex = compile(regex)?;
     Ok(unary_utf8_boolean(values, |x| regex.is_match(x)))
 }
 
@@ -136,4 +194,11 @@ mod tests {
             vec![true, false, false, false],
         )
     }
+
+    #[test]
+    fn test_like_different_lengths() {
+        let values = Utf8Array::<i32>::from(&[Some("a"), None]);
+        let pattern = Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]);
+        assert!(regex_match(&values, &pattern).is_err());
+    }
 }
@@ -0,0 +1,147 @@
+//! Defines kernels that replace the matches of a regex in [`Utf8Array`]s.
+use regex::Regex;
+
+use super::regex_match::{check_lengths, compile, try_for_each_regex, COMPILE_ERROR};
+use super::utils::combine_validities;
+use crate::array::{Array, Offset, Utf8Array};
+use crate::buffer::MutableBuffer;
+use crate::error::{ArrowError, Result};
+
+#[inline]
+fn push_replaced(values: &mut String, haystack: &str, regex: &Regex, replacement: &str, all: bool) {
+    if all {
+        values.push_str(&regex.replace_all(haystack, replacement))
+    } else {
+        values.push_str(&regex.replace(haystack, replacement))
+    }
+}
+
+/// Replaces the first match (or all matches, when `all` is true) of the regex in `pattern`
+/// by `replacement` in every value of `values`. Every distinct pattern is compiled once.
+///
+/// `replacement` may refer to capture groups of the pattern, e.g. `$1` or `${name}`.
+/// Slots where either `values` or `pattern` is null are null.
+/// # Errors
+/// This function errors when the arrays have different lengths, a pattern is not a valid regex
+/// or the values of the result do not fit in offsets of type `O`.
+pub fn regex_replace<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &Utf8Array<O>,
+    replacement: &str,
+    all: bool,
+) -> Result<Utf8Array<O>> {
+    check_lengths(values, pattern)?;
+    let validity = combine_validities(values.validity(), pattern.validity());
+
+    let mut offsets = MutableBuffer::<O>::with_capacity(values.len() + 1);
+    offsets.push(O::zero());
+    let mut new_values = String::with_capacity(values.values().len());
+    try_for_each_regex(values, pattern, COMPILE_ERROR, |pair| {
+        if let Some((haystack, regex)) = pair {
+            push_replaced(&mut new_values, haystack, regex, replacement, all);
+        }
+        offsets.push(O::from_usize(new_values.len()).ok_or(ArrowError::KeyOverflowError)?);
+        Ok(())
+    })?;
+
+    // Safety: `new_values` is a `String` and thus valid utf8 at every offset
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            offsets.into(),
+            new_values.into_bytes().into(),
+            validity,
+        )
+    })
+}
+
+/// Replaces the first match (or all matches, when `all` is true) of the regex `pattern`
+/// by `replacement` in every value of `values`.
+///
+/// `replacement` may refer to capture groups of the pattern, e.g. `$1` or `${name}`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_replace::regex_replace_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a1b22"), None, Some("c")]);
+/// let result = regex_replace_scalar(&array, r"(\d+)", "<$1>", true).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("a<1>b<22>"), None, Some("c")]));
+/// ```
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex or the values of the result do not
+/// fit in offsets of type `O`.
+pub fn regex_replace_scalar<O: Offset>(
+    values: &Utf8Array<O>,
+    pattern: &str,
+    replacement: &str,
+    all: bool,
+) -> Result<Utf8Array<O>> {
+    let regex = compile(pattern)?;
+
+    let mut offsets = MutableBuffer::<O>::with_capacity(values.len() + 1);
+    offsets.push(O::zero());
+    let mut new_values = String::with_capacity(values.values().len());
+    for haystack in values.iter() {
+        if let Some(haystack) = haystack {
+            push_replaced(&mut new_values, haystack, &regex, replacement, all);
+        }
+        offsets.push(O::from_usize(new_values.len()).ok_or(ArrowError::KeyOverflowError)?);
+    }
+
+    // Safety: `new_values` is a `String` and thus valid utf8 at every offset
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            offsets.into(),
+            new_values.into_bytes().into(),
+            values.validity().clone(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_per_row() {
+        let values = Utf8Array::<i32>::from(&[Some("aaa"), Some("aaa"), None, Some("abc")]);
+        let pattern = Utf8Array::<i32>::from(&[Some("a"), Some("^a"), Some("a"), None]);
+
+        let result = regex_replace(&values, &pattern, "x", false).unwrap();
+        let expected = Utf8Array::<i32>::from(&[Some("xaa"), Some("xaa"), None, None]);
+        assert_eq!(result, expected);
+
+        let result = regex_replace(&values, &pattern, "x", true).unwrap();
+        let expected = Utf8Array::<i32>::from(&[Some("xxx"), Some("xaa"), None, None]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn replace_scalar() {
+        let values = Utf8Array::<i64>::from_slice(&["2021-01-02", "no date"]);
+        let result = regex_replace_scalar(
+            &values,
+            r"(?P<y>\d{4})-(\d{2})-(\d{2})",
+            "$3/$2/${y}",
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            Utf8Array::<i64>::from_slice(&["02/01/2021", "no date"])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let values = Utf8Array::<i32>::from_slice(&["a"]);
+        assert!(regex_replace_scalar(&values, "(", "", true).is_err());
+
+        let pattern = Utf8Array::<i32>::from_slice(&["a", "b"]);
+        assert!(regex_replace(&values, &pattern, "", true).is_err());
+
+        let values = Utf8Array::<i32>::from(&[Some("a"), None]);
+        let pattern = Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]);
+        assert!(regex_replace(&values, &pattern, "", true).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    bitmap::{Bitmap, MutableBitmap},
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+use super::{null_sentinel, SortOptions};
+
+/// A [`NativeType`] that can be encoded into bytes whose lexicographical order is the order
+/// of the values.
+pub trait FixedLengthEncoding: NativeType {
+    /// The encoded bytes
+    type Encoded: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+    /// Encodes `self` into order-preserving bytes.
+    fn encode(self) -> Self::Encoded;
+
+    /// Decodes bytes produced by [`FixedLengthEncoding::encode`].
+    fn decode(encoded: Self::Encoded) -> Self;
+}
+
+macro_rules! unsigned {
+    ($ty:ty) => {
+        impl FixedLengthEncoding for $ty {
+            type Encoded = [u8; std::mem::size_of::<$ty>()];
+
+            #[inline]
+            fn encode(self) -> Self::Encoded {
+                self.to_be_bytes()
+            }
+
+            #[inline]
+            fn decode(encoded: Self::Encoded) -> Self {
+                Self::from_be_bytes(encoded)
+            }
+        }
+    };
+}
+
+macro_rules! signed {
+    ($ty:ty) => {
+        impl FixedLengthEncoding for $ty {
+            type Encoded = [u8; std::mem::size_of::<$ty>()];
+
+            #[inline]
+            fn encode(self) -> Self::Encoded {
+                let mut encoded = self.to_be_bytes();
+                // flip the sign bit so that negative numbers are ordered before positive ones
+                encoded[0] ^= 0x80;
+                encoded
+            }
+
+            #[inline]
+            fn decode(mut encoded: Self::Encoded) -> Self {
+                encoded[0] ^= 0x80;
+                Self::from_be_bytes(encoded)
+            }
+        }
+    };
+}
+
+macro_rules! float {
+    ($ty:ty, $signed:ty, $unsigned:ty) => {
+        impl FixedLengthEncoding for $ty {
+            type Encoded = [u8; std::mem::size_of::<$ty>()];
+
+            /// Encodes the float according to IEEE 754 total ordering, as in
+            /// [`crate::array::ord::total_cmp_f64`].
+            #[inline]
+            fn encode(self) -> Self::Encoded {
+                let bits = self.to_bits() as $signed;
+                let bits =
+                    bits ^ ((((bits >> (<$signed>::BITS - 1)) as $unsigned) >> 1) as $signed);
+                bits.encode()
+            }
+
+            #[inline]
+            fn decode(encoded: Self::Encoded) -> Self {
+                let bits = <$signed>::decode(encoded);
+                let bits =
+                    bits ^ ((((bits >> (<$signed>::BITS - 1)) as $unsigned) >> 1) as $signed);
+                Self::from_bits(bits as $unsigned)
+            }
+        }
+    };
+}
+
+unsigned!(u8);
+unsigned!(u16);
+unsigned!(u32);
+unsigned!(u64);
+signed!(i8);
+signed!(i16);
+signed!(i32);
+signed!(i64);
+signed!(i128);
+float!(f32, i32, u32);
+float!(f64, i64, u64);
+
+/// Encodes values of `width` bytes at `offsets` of `buffer`, advancing each offset.
+/// Every value is preceded by a byte denoting whether it is null.
+pub fn encode<B, I>(
+    iter: I,
+    width: usize,
+    options: &SortOptions,
+    buffer: &mut [u8],
+    offsets: &mut [usize],
+) where
+    B: AsRef<[u8]>,
+    I: Iterator<Item = Option<B>>,
+{
+    for (offset, value) in offsets.iter_mut().zip(iter) {
+        let end = *offset + 1 + width;
+        let out = &mut buffer[*offset..end];
+        match value {
+            Some(value) => {
+                out[0] = 1;
+                out[1..].copy_from_slice(value.as_ref());
+                if options.descending {
+                    out[1..].iter_mut().for_each(|x| *x = !*x);
+                }
+            }
+            // the value bytes are left zeroed
+            None => out[0] = null_sentinel(options),
+        }
+        *offset = end;
+    }
+}
+
+/// Decodes values of `width` bytes from the start of each row, advancing the rows past them.
+/// `op` is called with the (order-preserving) bytes of every value, including nulls.
+pub fn decode<T, F>(
+    rows: &mut [&[u8]],
+    width: usize,
+    options: &SortOptions,
+    mut op: F,
+) -> Result<(Vec<T>, Option<Bitmap>)>
+where
+    F: FnMut(&[u8]) -> T,
+{
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    let mut scratch = vec![0u8; width];
+    let values = rows
+        .iter_mut()
+        .map(|row| {
+            if row.len() < 1 + width {
+                return Err(ArrowError::InvalidArgumentError(
+                    "The row is too short to contain the encoded columns".to_string(),
+                ));
+            }
+            let (value, rest) = row.split_at(1 + width);
+            validity.push(value[0] == 1);
+            scratch.copy_from_slice(&value[1..]);
+            if options.descending {
+                scratch.iter_mut().for_each(|x| *x = !*x);
+            }
+            *row = rest;
+            Ok(op(&scratch))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((values, validity.into()))
+}
+
+/// Decodes a value of type `T` from its (order-preserving) bytes.
+#[inline]
+pub fn decode_value<T: FixedLengthEncoding>(bytes: &[u8]) -> T {
+    let mut encoded = T::Encoded::default();
+    encoded.as_mut().copy_from_slice(bytes);
+    T::decode(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// asserts that the encoding of `values`, sorted in total order, is sorted
+    fn assert_order<T: FixedLengthEncoding + PartialEq + std::fmt::Debug>(values: &[T]) {
+        for window in values.windows(2) {
+            assert!(window[0].encode().as_ref() < window[1].encode().as_ref());
+            assert_eq!(T::decode(window[0].encode()), window[0]);
+        }
+    }
+
+    #[test]
+    fn order() {
+        assert_order(&[i8::MIN, -1, 0, 1, i8::MAX]);
+        assert_order(&[i64::MIN, -1, 0, 1, i64::MAX]);
+        assert_order(&[i128::MIN, -1, 0, 1, i128::MAX]);
+        assert_order(&[0u32, 1, 256, u32::MAX]);
+        assert_order(&[f64::NEG_INFINITY, -1.5, -0.0, 0.0, 1.5, f64::INFINITY]);
+        assert_order(&[f32::NEG_INFINITY, -1.5, -0.0, 0.0, 1.5, f32::INFINITY]);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(1.0f64.encode() < f64::NAN.encode());
+        assert!(f64::decode(f64::NAN.encode()).is_nan());
+    }
+}
@@ -0,0 +1,585 @@
+//! Conversion of columns to and from an order-preserving row format.
+//!
+//! [`columns_to_rows`] encodes a set of columns into one byte row per slot, such that
+//! comparing two rows with `memcmp` (i.e. comparing `&[u8]`) is equivalent to comparing them
+//! lexicographically, column by column, according to each column's [`SortOptions`].
+//! This allows to sort, merge and group by many columns using plain byte slices, instead of
+//! dynamically dispatched comparators such as the ones from [`crate::array::ord`].
+//!
+//! Each value is prefixed by a null sentinel byte; values of fixed size are encoded in
+//! big-endian with the sign bit flipped, while variable-sized values are encoded in blocks.
+//! Descending columns are encoded with their value bytes inverted.
+//! [`rows_to_columns`] decodes rows back into arrays.
+use crate::{
+    array::*,
+    buffer::Buffer,
+    datatypes::{DataType, IntervalUnit},
+    error::{ArrowError, Result},
+};
+
+pub use super::sort::{SortColumn, SortOptions};
+
+mod fixed;
+mod variable;
+
+use fixed::{decode_value, FixedLengthEncoding};
+
+/// The sentinel denoting a null value: nulls are ordered before or after any valid value
+/// (whose sentinel is `1`) according to `options.nulls_first`.
+#[inline]
+fn null_sentinel(options: &SortOptions) -> u8 {
+    if options.nulls_first {
+        0
+    } else {
+        0xFF
+    }
+}
+
+/// A column's [`DataType`] and [`SortOptions`], required to decode rows.
+#[derive(Debug, Clone)]
+pub struct SortField {
+    /// The [`DataType`] of the column
+    pub data_type: DataType,
+    /// The [`SortOptions`] the column was encoded with
+    pub options: SortOptions,
+}
+
+/// A set of rows encoded by [`columns_to_rows`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rows {
+    values: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl Rows {
+    /// Returns the number of rows.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns whether there are no rows.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the row at position `i`.
+    /// # Panics
+    /// This function panics iff `i >= self.len()`.
+    #[inline]
+    pub fn row(&self, i: usize) -> &[u8] {
+        &self.values[self.offsets[i]..self.offsets[i + 1]]
+    }
+
+    /// Returns an iterator over the rows.
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.offsets
+            .windows(2)
+            .map(move |x| &self.values[x[0]..x[1]])
+    }
+}
+
+/// Returns the number of bytes of the encoding of a value of `data_type`, or `None` if
+/// values of `data_type` have a variable size.
+fn fixed_width(data_type: &DataType) -> Option<usize> {
+    use DataType::*;
+    Some(match data_type {
+        Boolean | Int8 | UInt8 => 1,
+        Int16 | UInt16 => 2,
+        Int32 | UInt32 | Float32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => 4,
+        Int64 | UInt64 | Float64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => 8,
+        Decimal(_, _) => 16,
+        FixedSizeBinary(size) => *size as usize,
+        _ => return None,
+    })
+}
+
+/// Checks if columns of type `data_type` can be encoded into rows.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::row::can_encode;
+/// use arrow2::datatypes::DataType;
+///
+/// assert_eq!(can_encode(&DataType::Utf8), true);
+/// assert_eq!(can_encode(&DataType::Null), false);
+/// ```
+pub fn can_encode(data_type: &DataType) -> bool {
+    fixed_width(data_type).is_some()
+        || matches!(
+            data_type,
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+        )
+}
+
+fn encode_primitive<T: FixedLengthEncoding>(
+    array: &dyn Array,
+    options: &SortOptions,
+    buffer: &mut [u8],
+    offsets: &mut [usize],
+) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let iter = array.iter().map(|x| x.map(|x| x.encode()));
+    fixed::encode(iter, std::mem::size_of::<T>(), options, buffer, offsets)
+}
+
+fn encode_binary<O: Offset>(
+    array: &dyn Array,
+    options: &SortOptions,
+    buffer: &mut [u8],
+    offsets: &mut [usize],
+) {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    variable::encode(array.iter(), options, buffer, offsets)
+}
+
+fn encode_utf8<O: Offset>(
+    array: &dyn Array,
+    options: &SortOptions,
+    buffer: &mut [u8],
+    offsets: &mut [usize],
+) {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let iter = array.iter().map(|x| x.map(|x| x.as_bytes()));
+    variable::encode(iter, options, buffer, offsets)
+}
+
+fn encode_column(
+    array: &dyn Array,
+    options: &SortOptions,
+    buffer: &mut [u8],
+    offsets: &mut [usize],
+) {
+    use DataType::*;
+    match array.data_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let iter = array.iter().map(|x| x.map(|x| [x as u8]));
+            fixed::encode(iter, 1, options, buffer, offsets)
+        }
+        Int8 => encode_primitive::<i8>(array, options, buffer, offsets),
+        Int16 => encode_primitive::<i16>(array, options, buffer, offsets),
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            encode_primitive::<i32>(array, options, buffer, offsets)
+        }
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            encode_primitive::<i64>(array, options, buffer, offsets)
+        }
+        UInt8 => encode_primitive::<u8>(array, options, buffer, offsets),
+        UInt16 => encode_primitive::<u16>(array, options, buffer, offsets),
+        UInt32 => encode_primitive::<u32>(array, options, buffer, offsets),
+        UInt64 => encode_primitive::<u64>(array, options, buffer, offsets),
+        Float32 => encode_primitive::<f32>(array, options, buffer, offsets),
+        Float64 => encode_primitive::<f64>(array, options, buffer, offsets),
+        Decimal(_, _) => encode_primitive::<i128>(array, options, buffer, offsets),
+        FixedSizeBinary(size) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            fixed::encode(array.iter(), *size as usize, options, buffer, offsets)
+        }
+        Binary => encode_binary::<i32>(array, options, buffer, offsets),
+        LargeBinary => encode_binary::<i64>(array, options, buffer, offsets),
+        Utf8 => encode_utf8::<i32>(array, options, buffer, offsets),
+        LargeUtf8 => encode_utf8::<i64>(array, options, buffer, offsets),
+        _ => unreachable!(),
+    }
+}
+
+/// Adds the encoded length of each slot of a variable-sized `array` to `lengths`.
+fn add_variable_lengths(array: &dyn Array, lengths: &mut [usize]) {
+    fn add<'a, I: Iterator<Item = Option<&'a [u8]>>>(iter: I, lengths: &mut [usize]) {
+        lengths
+            .iter_mut()
+            .zip(iter)
+            .for_each(|(length, value)| *length += variable::encoded_len(value))
+    }
+    match array.data_type() {
+        DataType::Binary => add(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .iter(),
+            lengths,
+        ),
+        DataType::LargeBinary => add(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .iter(),
+            lengths,
+        ),
+        DataType::Utf8 => add(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .iter()
+                .map(|x| x.map(|x| x.as_bytes())),
+            lengths,
+        ),
+        DataType::LargeUtf8 => add(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .iter()
+                .map(|x| x.map(|x| x.as_bytes())),
+            lengths,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// Encodes `columns` into [`Rows`] whose lexicographical order is the order of the columns
+/// sorted lexicographically according to each column's [`SortOptions`], as in
+/// [`crate::compute::sort::lexsort_to_indices`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::row::{columns_to_rows, SortColumn, SortOptions};
+///
+/// let a = Int32Array::from(&[Some(1), Some(1), None]);
+/// let b = Utf8Array::<i32>::from_slice(&["b", "a", "c"]);
+/// let columns = [
+///     SortColumn { values: &a, options: None },
+///     SortColumn {
+///         values: &b,
+///         options: Some(SortOptions { descending: true, nulls_first: true }),
+///     },
+/// ];
+/// let rows = columns_to_rows(&columns).unwrap();
+///
+/// let mut indices = (0..rows.len()).collect::<Vec<_>>();
+/// indices.sort_by_key(|i| rows.row(*i));
+/// assert_eq!(indices, vec![2, 0, 1]);
+/// ```
+/// # Errors
+/// This function errors iff the columns have different lengths or if any of them has a
+/// [`DataType`] that is not supported (see [`can_encode`]).
+pub fn columns_to_rows(columns: &[SortColumn]) -> Result<Rows> {
+    let length = columns.first().map(|x| x.values.len()).unwrap_or(0);
+    if columns.iter().any(|x| x.values.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Columns encoded into rows must have the same length".to_string(),
+        ));
+    }
+    if let Some(column) = columns.iter().find(|x| !can_encode(x.values.data_type())) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Encoding {:?} into rows is not supported",
+            column.values.data_type()
+        )));
+    }
+
+    let fixed = columns
+        .iter()
+        .filter_map(|x| fixed_width(x.values.data_type()))
+        .map(|x| x + 1)
+        .sum::<usize>();
+    let mut lengths = vec![fixed; length];
+    columns
+        .iter()
+        .filter(|x| fixed_width(x.values.data_type()).is_none())
+        .for_each(|x| add_variable_lengths(x.values, &mut lengths));
+
+    let mut offsets = Vec::with_capacity(length + 1);
+    offsets.push(0);
+    offsets.extend(lengths.iter().scan(0, |acc, x| {
+        *acc += x;
+        Some(*acc)
+    }));
+
+    let mut values = vec![0; *offsets.last().unwrap()];
+    // the position in `values` where the next column of each row is written
+    let mut write_offsets = offsets[..length].to_vec();
+    for column in columns {
+        let options = column.options.unwrap_or_default();
+        encode_column(column.values, &options, &mut values, &mut write_offsets);
+    }
+
+    Ok(Rows { values, offsets })
+}
+
+fn decode_primitive<T: FixedLengthEncoding>(
+    rows: &mut [&[u8]],
+    field: &SortField,
+) -> Result<Box<dyn Array>> {
+    let (values, validity) = fixed::decode(
+        rows,
+        std::mem::size_of::<T>(),
+        &field.options,
+        decode_value::<T>,
+    )?;
+    Ok(Box::new(PrimitiveArray::<T>::from_data(
+        field.data_type.clone(),
+        values.into(),
+        validity,
+    )))
+}
+
+fn decode_column(rows: &mut [&[u8]], field: &SortField) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    let options = &field.options;
+    Ok(match &field.data_type {
+        Boolean => {
+            let (values, validity) = fixed::decode(rows, 1, options, |x| x[0] == 1)?;
+            Box::new(BooleanArray::from_data(
+                values.into_iter().collect(),
+                validity,
+            ))
+        }
+        Int8 => decode_primitive::<i8>(rows, field)?,
+        Int16 => decode_primitive::<i16>(rows, field)?,
+        Int32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            decode_primitive::<i32>(rows, field)?
+        }
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            decode_primitive::<i64>(rows, field)?
+        }
+        UInt8 => decode_primitive::<u8>(rows, field)?,
+        UInt16 => decode_primitive::<u16>(rows, field)?,
+        UInt32 => decode_primitive::<u32>(rows, field)?,
+        UInt64 => decode_primitive::<u64>(rows, field)?,
+        Float32 => decode_primitive::<f32>(rows, field)?,
+        Float64 => decode_primitive::<f64>(rows, field)?,
+        Decimal(_, _) => decode_primitive::<i128>(rows, field)?,
+        FixedSizeBinary(size) => {
+            let (values, validity) = fixed::decode(rows, *size as usize, options, |x| x.to_vec())?;
+            let values = values.into_iter().flatten().collect::<Buffer<u8>>();
+            Box::new(FixedSizeBinaryArray::from_data(
+                field.data_type.clone(),
+                values,
+                validity,
+            ))
+        }
+        Binary => {
+            let (offsets, values, validity) = variable::decode::<i32>(rows, options)?;
+            Box::new(BinaryArray::<i32>::from_data(
+                offsets.into(),
+                values.into(),
+                validity,
+            ))
+        }
+        LargeBinary => {
+            let (offsets, values, validity) = variable::decode::<i64>(rows, options)?;
+            Box::new(BinaryArray::<i64>::from_data(
+                offsets.into(),
+                values.into(),
+                validity,
+            ))
+        }
+        Utf8 => decode_utf8::<i32>(rows, options)?,
+        LargeUtf8 => decode_utf8::<i64>(rows, options)?,
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Decoding {:?} from rows is not supported",
+                other
+            )))
+        }
+    })
+}
+
+fn decode_utf8<O: Offset>(rows: &mut [&[u8]], options: &SortOptions) -> Result<Box<dyn Array>> {
+    let (offsets, values, validity) = variable::decode::<O>(rows, options)?;
+    offsets.windows(2).try_for_each(|x| {
+        std::str::from_utf8(&values[x[0].to_usize()..x[1].to_usize()]).map(|_| ())
+    })?;
+    // Safety: each value was checked to be valid utf8 above
+    Ok(Box::new(unsafe {
+        Utf8Array::<O>::from_data_unchecked(offsets.into(), values.into(), validity)
+    }))
+}
+
+/// Decodes rows produced by [`columns_to_rows`] back into columns, one per field in `fields`.
+/// `rows` may be any subset (or permutation) of the encoded rows, e.g. after sorting them.
+/// # Errors
+/// This function errors iff the rows were not encoded with `fields`.
+pub fn rows_to_columns(rows: &[&[u8]], fields: &[SortField]) -> Result<Vec<Box<dyn Array>>> {
+    let mut rows = rows.to_vec();
+    let columns = fields
+        .iter()
+        .map(|field| decode_column(&mut rows, field))
+        .collect::<Result<Vec<_>>>()?;
+    if rows.iter().any(|row| !row.is_empty()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "The rows contain more columns than the fields".to_string(),
+        ));
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::sort::lexsort_to_indices;
+    use crate::compute::take::take;
+    use crate::datatypes::TimeUnit;
+
+    fn all_options() -> Vec<SortOptions> {
+        let mut options = vec![];
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                options.push(SortOptions {
+                    descending,
+                    nulls_first,
+                })
+            }
+        }
+        options
+    }
+
+    fn pseudo_random(length: usize, seed: u64) -> Vec<Option<i64>> {
+        let mut state = seed;
+        (0..length)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let value = (state >> 33) as i64 % 10 - 5;
+                if value == 0 {
+                    None
+                } else {
+                    Some(value)
+                }
+            })
+            .collect()
+    }
+
+    fn columns() -> Vec<Box<dyn Array>> {
+        let a =
+            Int64Array::from(pseudo_random(100, 1)).to(DataType::Timestamp(TimeUnit::Second, None));
+        let b = pseudo_random(100, 2)
+            .iter()
+            .map(|x| x.map(|x| "a".repeat(x.unsigned_abs() as usize * 10)))
+            .collect::<Utf8Array<i32>>();
+        let c = pseudo_random(100, 3)
+            .iter()
+            .map(|x| x.map(|x| x as f32 / 2.0))
+            .collect::<Float32Array>();
+        let d = pseudo_random(100, 4)
+            .iter()
+            .map(|x| x.map(|x| x > 0))
+            .collect::<BooleanArray>();
+        vec![Box::new(a), Box::new(b), Box::new(c), Box::new(d)]
+    }
+
+    #[test]
+    fn roundtrip() -> Result<()> {
+        let columns = columns();
+        for options in all_options() {
+            let sort_columns = columns
+                .iter()
+                .map(|x| SortColumn {
+                    values: x.as_ref(),
+                    options: Some(options),
+                })
+                .collect::<Vec<_>>();
+            let fields = columns
+                .iter()
+                .map(|x| SortField {
+                    data_type: x.data_type().clone(),
+                    options,
+                })
+                .collect::<Vec<_>>();
+            let rows = columns_to_rows(&sort_columns)?;
+            let decoded = rows_to_columns(&rows.iter().collect::<Vec<_>>(), &fields)?;
+            assert_eq!(decoded, columns);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn same_as_lexsort() -> Result<()> {
+        let columns = columns();
+        for options in all_options() {
+            let sort_columns = columns
+                .iter()
+                .enumerate()
+                .map(|(i, x)| SortColumn {
+                    values: x.as_ref(),
+                    // alternate the options between columns
+                    options: Some(SortOptions {
+                        descending: options.descending ^ (i % 2 == 0),
+                        nulls_first: options.nulls_first ^ (i % 3 == 0),
+                    }),
+                })
+                .collect::<Vec<_>>();
+            let rows = columns_to_rows(&sort_columns)?;
+
+            let mut indices = (0..rows.len() as u32).collect::<Vec<_>>();
+            indices.sort_by_key(|i| rows.row(*i as usize));
+            let indices = UInt32Array::from_slice(&indices);
+
+            let expected = lexsort_to_indices::<u32>(&sort_columns, None)?;
+            for column in &columns {
+                let result = take(column.as_ref(), &indices)?;
+                let expected = take(column.as_ref(), &expected)?;
+                assert_eq!(result.as_ref(), expected.as_ref());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_size_binary_and_decimal() -> Result<()> {
+        let a = FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(2),
+            vec![1, 2, 0, 0, 0, 1].into(),
+            Some([true, false, true].into()),
+        );
+        let b = Int128Array::from(&[Some(-1), Some(i128::MAX), None]).to(DataType::Decimal(38, 0));
+        let options = SortOptions::default();
+        let rows = columns_to_rows(&[
+            SortColumn {
+                values: &a,
+                options: None,
+            },
+            SortColumn {
+                values: &b,
+                options: None,
+            },
+        ])?;
+        // nulls first
+        assert!(rows.row(1) < rows.row(2));
+        assert!(rows.row(2) < rows.row(0));
+
+        let fields = [
+            SortField {
+                data_type: a.data_type().clone(),
+                options,
+            },
+            SortField {
+                data_type: b.data_type().clone(),
+                options,
+            },
+        ];
+        let decoded = rows_to_columns(&rows.iter().collect::<Vec<_>>(), &fields)?;
+        assert_eq!(decoded[0].as_ref(), &a as &dyn Array);
+        assert_eq!(decoded[1].as_ref(), &b as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let a = Int32Array::from_slice(&[1, 2]);
+        let b = Int32Array::from_slice(&[1]);
+        let column = |values| SortColumn {
+            values,
+            options: None,
+        };
+        assert!(columns_to_rows(&[column(&a), column(&b)]).is_err());
+        let null = NullArray::from_data(2);
+        assert!(columns_to_rows(&[column(&null)]).is_err());
+
+        let rows = columns_to_rows(&[column(&a)]).unwrap();
+        let field = SortField {
+            data_type: DataType::Int64,
+            options: SortOptions::default(),
+        };
+        assert!(rows_to_columns(&rows.iter().collect::<Vec<_>>(), &[field]).is_err());
+    }
+}
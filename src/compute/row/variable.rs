@@ -0,0 +1,200 @@
+//! Variable-length values are encoded as
+//! * a null sentinel byte (see [`super::null_sentinel`]), followed, for valid values, by
+//! * `EMPTY` for empty values, or `NON_EMPTY` followed by the value split in blocks of
+//!   `BLOCK_SIZE` bytes. Each block is padded with zeros and followed by a continuation byte:
+//!   `BLOCK_CONTINUATION` if more blocks follow, or the number of bytes used in the last block.
+//!
+//! This encoding is order-preserving and no encoded value is a prefix of another one.
+//! In descending order, all bytes but the null sentinel are inverted.
+use crate::{
+    array::Offset,
+    bitmap::{Bitmap, MutableBitmap},
+    error::{ArrowError, Result},
+};
+
+use super::{null_sentinel, SortOptions};
+
+const BLOCK_SIZE: usize = 32;
+const BLOCK_CONTINUATION: u8 = 0xFF;
+const EMPTY: u8 = 1;
+const NON_EMPTY: u8 = 2;
+
+/// Returns the number of bytes that `value` occupies when encoded.
+#[inline]
+pub fn encoded_len(value: Option<&[u8]>) -> usize {
+    match value {
+        None => 1,
+        Some([]) => 2,
+        Some(value) => 2 + ((value.len() - 1) / BLOCK_SIZE + 1) * (BLOCK_SIZE + 1),
+    }
+}
+
+/// Encodes `iter` at `offsets` of `buffer`, advancing each offset.
+pub fn encode<'a, I>(iter: I, options: &SortOptions, buffer: &mut [u8], offsets: &mut [usize])
+where
+    I: Iterator<Item = Option<&'a [u8]>>,
+{
+    for (offset, value) in offsets.iter_mut().zip(iter) {
+        let value = match value {
+            Some(value) => value,
+            None => {
+                buffer[*offset] = null_sentinel(options);
+                *offset += 1;
+                continue;
+            }
+        };
+        buffer[*offset] = 1;
+        let start = *offset + 1;
+        let mut end = start + 1;
+        if value.is_empty() {
+            buffer[start] = EMPTY;
+        } else {
+            buffer[start] = NON_EMPTY;
+            let mut blocks = value.chunks(BLOCK_SIZE).peekable();
+            while let Some(block) = blocks.next() {
+                buffer[end..end + block.len()].copy_from_slice(block);
+                end += BLOCK_SIZE;
+                buffer[end] = if blocks.peek().is_some() {
+                    BLOCK_CONTINUATION
+                } else {
+                    block.len() as u8
+                };
+                end += 1;
+            }
+        }
+        if options.descending {
+            buffer[start..end].iter_mut().for_each(|x| *x = !*x);
+        }
+        *offset = end;
+    }
+}
+
+fn malformed() -> ArrowError {
+    ArrowError::InvalidArgumentError(
+        "The row does not contain a valid variable-length encoding".to_string(),
+    )
+}
+
+/// Decodes a variable-length value from the start of each row, advancing the rows past it.
+/// Returns the offsets, values and validity of the decoded values.
+pub fn decode<O: Offset>(
+    rows: &mut [&[u8]],
+    options: &SortOptions,
+) -> Result<(Vec<O>, Vec<u8>, Option<Bitmap>)> {
+    let mut offsets = Vec::with_capacity(rows.len() + 1);
+    offsets.push(O::default());
+    let mut values = vec![];
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+
+    let byte = |x: u8| if options.descending { !x } else { x };
+
+    for row in rows.iter_mut() {
+        let is_valid = *row.first().ok_or_else(malformed)? == 1;
+        validity.push(is_valid);
+        if !is_valid {
+            *row = &row[1..];
+            offsets.push(O::from_usize(values.len()).ok_or_else(malformed)?);
+            continue;
+        }
+        let header = byte(*row.get(1).ok_or_else(malformed)?);
+        let mut position = 2;
+        match header {
+            EMPTY => {}
+            NON_EMPTY => loop {
+                let block = row
+                    .get(position..position + BLOCK_SIZE + 1)
+                    .ok_or_else(malformed)?;
+                position += BLOCK_SIZE + 1;
+                let continuation = byte(block[BLOCK_SIZE]);
+                let length = match continuation {
+                    BLOCK_CONTINUATION => BLOCK_SIZE,
+                    length if length as usize <= BLOCK_SIZE => length as usize,
+                    _ => return Err(malformed()),
+                };
+                values.extend(block[..length].iter().map(|x| byte(*x)));
+                if continuation != BLOCK_CONTINUATION {
+                    break;
+                }
+            },
+            _ => return Err(malformed()),
+        }
+        *row = &row[position..];
+        offsets.push(O::from_usize(values.len()).ok_or_else(malformed)?);
+    }
+    Ok((offsets, values, validity.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(values: &[Option<&[u8]>], options: &SortOptions) -> Vec<Vec<u8>> {
+        let mut offsets = vec![0];
+        offsets.extend(values.iter().scan(0, |acc, x| {
+            *acc += encoded_len(*x);
+            Some(*acc)
+        }));
+        let mut buffer = vec![0; *offsets.last().unwrap()];
+        let mut write_offsets = offsets[..values.len()].to_vec();
+        encode(
+            values.iter().copied(),
+            options,
+            &mut buffer,
+            &mut write_offsets,
+        );
+        assert_eq!(write_offsets, offsets[1..]);
+
+        let rows = offsets
+            .windows(2)
+            .map(|x| buffer[x[0]..x[1]].to_vec())
+            .collect::<Vec<_>>();
+
+        let mut slices = rows.iter().map(|x| x.as_slice()).collect::<Vec<_>>();
+        let (decoded_offsets, decoded, validity) = decode::<i32>(&mut slices, options).unwrap();
+        assert!(slices.iter().all(|x| x.is_empty()));
+        for (i, value) in values.iter().enumerate() {
+            let is_valid = validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+            let start = decoded_offsets[i] as usize;
+            let end = decoded_offsets[i + 1] as usize;
+            assert_eq!(is_valid.then(|| &decoded[start..end]), *value);
+        }
+        rows
+    }
+
+    #[test]
+    fn order() {
+        let long = [7u8; 70];
+        let values = [
+            Some(b"".as_ref()),
+            Some(b"\0"),
+            Some(&long[..32]),
+            Some(&long[..33]),
+            Some(&long[..]),
+            Some(b"a"),
+            Some(b"a\0"),
+            Some(b"b"),
+        ];
+        for descending in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_first: true,
+            };
+            let rows = roundtrip(&values, &options);
+            for window in rows.windows(2) {
+                assert_eq!(window[0] < window[1], !descending);
+            }
+        }
+    }
+
+    #[test]
+    fn nulls() {
+        let values = [None, Some(b"".as_ref()), Some(b"\xFF\xFF")];
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let rows = roundtrip(&values, &options);
+        assert!(rows[0] > rows[2]);
+        assert!(rows[1] < rows[2]);
+    }
+}
@@ -172,8 +172,7 @@ pub fn lexsort_to_indices<I: Index>(
         )
     };
 
-    if let Some(limit) = limit {
-        let limit = limit.min(row_count);
+    if let Some(limit) = limit.filter(|limit| *limit < row_count) {
         let (before, _, _) = values.select_nth_unstable_by(limit, lex_comparator);
         before.sort_unstable_by(lex_comparator);
         values.truncate(limit);
@@ -266,6 +265,30 @@ mod tests {
         test_lex_sort_arrays(input, expected);
     }
 
+    #[test]
+    fn test_lex_sort_binary_dictionary() {
+        let c1 = BinaryArray::<i32>::from_slice(&[b"b".as_ref(), b"a", b"b", b"a"]);
+        let mut c2 = MutableDictionaryArray::<i32, MutablePrimitiveArray<i64>>::new();
+        c2.try_extend(vec![Some(1), Some(5), Some(3), None])
+            .unwrap();
+        let c2: DictionaryArray<i32> = c2.into();
+        let input = vec![
+            SortColumn {
+                values: &c1,
+                options: None,
+            },
+            SortColumn {
+                values: &c2,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+        ];
+        let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+        assert_eq!(indices, Int32Array::from_slice(&[1, 3, 2, 0]));
+    }
+
     /*
         // test sort with nulls first
         let input = vec![
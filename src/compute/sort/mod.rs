@@ -6,7 +6,7 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
-    types::{days_ms, NativeType},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 use crate::buffer::MutableBuffer;
@@ -15,10 +15,12 @@ mod boolean;
 mod common;
 mod lex_sort;
 mod primitive;
+mod top_k;
 mod utf8;
 
 pub(crate) use lex_sort::{build_compare, Compare};
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use top_k::{lexsort_top_k, lexsort_top_k_to_indices, top_k, top_k_to_indices};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -66,6 +68,9 @@ pub fn sort(
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_sort!(days_ms, values, ord::total_cmp, options, limit)
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            dyn_sort!(months_days_ns, values, ord::total_cmp, options, limit)
+        }
         _ => {
             let indices = sort_to_indices::<u64>(values, options, limit)?;
             take::take(values, &indices)
@@ -137,6 +142,9 @@ pub fn sort_to_indices<I: Index>(
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_sort_indices!(I, days_ms, values, ord::total_cmp, options, limit)
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            dyn_sort_indices!(I, months_days_ns, values, ord::total_cmp, options, limit)
+        }
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
             values.as_any().downcast_ref().unwrap(),
             options,
@@ -147,6 +155,11 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
+        DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_)
+        | DataType::Decimal(_, _)
+        | DataType::Struct(_) => sort_by_comparator(values, options, limit),
         DataType::List(field) => {
             let (v, n) = partition_validity(values);
             match field.data_type() {
@@ -158,10 +171,7 @@ pub fn sort_to_indices<I: Index>(
                 DataType::UInt16 => Ok(sort_list::<I, i32, u16>(values, v, n, options, limit)),
                 DataType::UInt32 => Ok(sort_list::<I, i32, u32>(values, v, n, options, limit)),
                 DataType::UInt64 => Ok(sort_list::<I, i32, u64>(values, v, n, options, limit)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
+                _ => sort_by_comparator(values, options, limit),
             }
         }
         DataType::LargeList(field) => {
@@ -175,10 +185,7 @@ pub fn sort_to_indices<I: Index>(
                 DataType::UInt16 => Ok(sort_list::<I, i64, u16>(values, v, n, options, limit)),
                 DataType::UInt32 => Ok(sort_list::<I, i64, u32>(values, v, n, options, limit)),
                 DataType::UInt64 => Ok(sort_list::<I, i64, u64>(values, v, n, options, limit)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
+                _ => sort_by_comparator(values, options, limit),
             }
         }
         DataType::FixedSizeList(field, _) => {
@@ -192,19 +199,13 @@ pub fn sort_to_indices<I: Index>(
                 DataType::UInt16 => Ok(sort_list::<I, i32, u16>(values, v, n, options, limit)),
                 DataType::UInt32 => Ok(sort_list::<I, i32, u32>(values, v, n, options, limit)),
                 DataType::UInt64 => Ok(sort_list::<I, i32, u64>(values, v, n, options, limit)),
-                t => Err(ArrowError::NotYetImplemented(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                ))),
+                _ => sort_by_comparator(values, options, limit),
             }
         }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 => sort_dict::<I, i32>(values, key_type.as_ref(), options, limit),
             DataType::LargeUtf8 => sort_dict::<I, i64>(values, key_type.as_ref(), options, limit),
-            t => Err(ArrowError::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {:?}",
-                t
-            ))),
+            _ => sort_dict_by_rank(values, key_type.as_ref(), options, limit),
         },
         t => Err(ArrowError::NotYetImplemented(format!(
             "Sort not supported for data type {:?}",
@@ -267,6 +268,111 @@ fn sort_dict<I: Index, O: Offset>(
     }
 }
 
+/// Sorts `values` using the comparator from [`build_compare`]; used for types without a
+/// specialized implementation. The sort is stable: equal values keep their order.
+fn sort_by_comparator<I: Index>(
+    values: &dyn Array,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let compare = build_compare(values, *options)?;
+    // ties are broken by position so that the unstable sorts below are stable
+    let compare = |lhs: &I, rhs: &I| {
+        let (lhs, rhs) = (lhs.to_usize(), rhs.to_usize());
+        compare(lhs, rhs).then(lhs.cmp(&rhs))
+    };
+
+    let length = values.len();
+    // Safety: `0..length` is TrustedLen
+    let mut indices = unsafe {
+        MutableBuffer::from_trusted_len_iter_unchecked(
+            (0..length).map(|x| I::from_usize(x).unwrap()),
+        )
+    };
+
+    if let Some(limit) = limit.filter(|limit| *limit < length) {
+        let (before, _, _) = indices.select_nth_unstable_by(limit, compare);
+        before.sort_unstable_by(compare);
+        indices.truncate(limit);
+    } else {
+        indices.sort_unstable_by(compare);
+    }
+
+    Ok(PrimitiveArray::<I>::from_data(
+        I::DATA_TYPE,
+        indices.into(),
+        None,
+    ))
+}
+
+/// Sorts a dictionary by the rank of its values: the values are sorted once and each key
+/// is replaced by the (dense) rank of its value, so that the values are never materialized.
+/// Like in [`ord::build_compare`], null values are ordered before any other value.
+fn sort_dict_by_rank<I: Index>(
+    values: &dyn Array,
+    key_type: &DataType,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let ranks = match key_type {
+        DataType::Int8 => dict_ranks::<i8>(values)?,
+        DataType::Int16 => dict_ranks::<i16>(values)?,
+        DataType::Int32 => dict_ranks::<i32>(values)?,
+        DataType::Int64 => dict_ranks::<i64>(values)?,
+        DataType::UInt8 => dict_ranks::<u8>(values)?,
+        DataType::UInt16 => dict_ranks::<u16>(values)?,
+        DataType::UInt32 => dict_ranks::<u32>(values)?,
+        DataType::UInt64 => dict_ranks::<u64>(values)?,
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Sort not supported for dictionary key type {:?}",
+                t
+            )))
+        }
+    };
+    Ok(primitive::indices_sorted_unstable_by::<I, u64, _>(
+        &ranks,
+        ord::total_cmp,
+        options,
+        limit,
+    ))
+}
+
+/// Returns the rank of the value of each key of the dictionary `values`.
+fn dict_ranks<K: DictionaryKey>(values: &dyn Array) -> Result<PrimitiveArray<u64>> {
+    let array = values
+        .as_any()
+        .downcast_ref::<DictionaryArray<K>>()
+        .unwrap();
+    let dict_values = array.values().as_ref();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let order = sort_to_indices::<u64>(dict_values, &options, None)?;
+    let compare = build_compare(dict_values, options)?;
+
+    let mut ranks = vec![0u64; dict_values.len()];
+    let mut rank = 0;
+    let mut previous: Option<usize> = None;
+    for index in order.values().iter().map(|x| *x as usize) {
+        if let Some(previous) = previous {
+            if compare(previous, index) != Ordering::Equal {
+                rank += 1;
+            }
+        }
+        ranks[index] = rank;
+        previous = Some(index);
+    }
+
+    let ranks = array
+        .keys()
+        .iter()
+        .map(|key| key.map(|key| ranks[key.to_usize().unwrap()]));
+    Ok(PrimitiveArray::<u64>::from_trusted_len_iter(ranks))
+}
+
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
@@ -278,6 +384,9 @@ fn sort_dict<I: Index, O: Offset>(
 /// assert_eq!(can_sort(&data_type), true);
 ///
 /// let data_type = DataType::LargeBinary;
+/// assert_eq!(can_sort(&data_type), true);
+///
+/// let data_type = DataType::Null;
 /// assert_eq!(can_sort(&data_type), false)
 /// ```
 pub fn can_sort(data_type: &DataType) -> bool {
@@ -300,22 +409,17 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::UInt64
         | DataType::Float32
         | DataType::Float64
+        | DataType::Decimal(_, _)
         | DataType::Utf8
-        | DataType::LargeUtf8 => true,
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
-            matches!(
-                field.data_type(),
-                DataType::Int8
-                    | DataType::Int16
-                    | DataType::Int32
-                    | DataType::Int64
-                    | DataType::UInt8
-                    | DataType::UInt16
-                    | DataType::UInt32
-                    | DataType::UInt64
-            )
+            can_sort(field.data_type())
         }
-        DataType::Dictionary(key_type, value_type) if *value_type.as_ref() == DataType::Utf8 => {
+        DataType::Struct(fields) => fields.iter().all(|field| can_sort(field.data_type())),
+        DataType::Dictionary(key_type, value_type) => {
             matches!(
                 key_type.as_ref(),
                 DataType::Int8
@@ -326,7 +430,7 @@ pub fn can_sort(data_type: &DataType) -> bool {
                     | DataType::UInt16
                     | DataType::UInt32
                     | DataType::UInt64
-            )
+            ) && can_sort(value_type.as_ref())
         }
         _ => false,
     }
@@ -424,6 +528,7 @@ fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
 
     fn test_sort_to_indices_boolean_arrays(
         data: &[Option<bool>],
@@ -1016,6 +1121,8 @@ mod tests {
             LargeUtf8,
             Binary,
             LargeBinary,
+            FixedSizeBinary(2),
+            Decimal(10, 2),
             Duration(TimeUnit::Second),
             Duration(TimeUnit::Millisecond),
             Duration(TimeUnit::Microsecond),
@@ -1035,4 +1142,91 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_sort_binary() -> Result<()> {
+        let array = BinaryArray::<i32>::from(&[Some(b"b".as_ref()), None, Some(b"ab"), Some(b"a")]);
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let result = sort_to_indices::<i32>(&array, &options, None)?;
+        assert_eq!(result, Int32Array::from_slice(&[3, 2, 0, 1]));
+
+        let result = sort_to_indices::<i32>(&array, &options, Some(2))?;
+        assert_eq!(result, Int32Array::from_slice(&[3, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_struct() -> Result<()> {
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let array = StructArray::from_data(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(&[Some(2), Some(1), Some(1), None])),
+                Arc::new(Utf8Array::<i32>::from_slice(&["a", "c", "b", "d"])),
+            ],
+            Some([true, true, true, false].into()),
+        );
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let result = sort_to_indices::<i32>(&array, &options, None)?;
+        assert_eq!(result, Int32Array::from_slice(&[0, 1, 2, 3]));
+
+        // equal values keep their order
+        let array = StructArray::from_data(
+            vec![Field::new("a", DataType::Int32, true)],
+            vec![Arc::new(Int32Array::from_slice(&[1, 0, 1, 0, 1]))],
+            None,
+        );
+        let result = sort_to_indices::<i32>(&array, &options, None)?;
+        assert_eq!(result, Int32Array::from_slice(&[0, 2, 4, 1, 3]));
+        let result = sort_to_indices::<i32>(&array, &options, Some(2))?;
+        assert_eq!(result, Int32Array::from_slice(&[0, 2]));
+        let result = sort_to_indices::<i32>(&array, &SortOptions::default(), None)?;
+        assert_eq!(result, Int32Array::from_slice(&[1, 3, 0, 2, 4]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_list_utf8() -> Result<()> {
+        let data = vec![
+            Some(vec![Some("b")]),
+            Some(vec![Some("a"), Some("c")]),
+            None,
+            Some(vec![Some("a")]),
+        ];
+        let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+        array.try_extend(data)?;
+        let array: ListArray<i32> = array.into();
+
+        let result = sort_to_indices::<i32>(&array, &SortOptions::default(), None)?;
+        assert_eq!(result, Int32Array::from_slice(&[2, 3, 1, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_primitive_dict() -> Result<()> {
+        let data = vec![Some(3), None, Some(1), Some(3), Some(2)];
+        let mut array = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+        array.try_extend(data)?;
+        let array: DictionaryArray<u8> = array.into();
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let result = sort_to_indices::<i32>(&array, &options, None)?;
+        // ties are resolved arbitrarily
+        let result = take::take(&array, &result)?;
+        let expected = take::take(&array, &Int32Array::from_slice(&[2, 4, 0, 3, 1]))?;
+        assert_eq!(result.as_ref(), expected.as_ref());
+        Ok(())
+    }
 }
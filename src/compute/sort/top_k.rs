@@ -0,0 +1,310 @@
+use std::cmp::Ordering;
+
+use crate::compute::take;
+use crate::error::{ArrowError, Result};
+use crate::{
+    array::{Array, Index, PrimitiveArray},
+    buffer::MutableBuffer,
+};
+
+use super::lex_sort::{build_compare, Compare};
+use super::{SortColumn, SortOptions};
+
+/// A binary heap of at most `k` row indices whose root is the largest of them according
+/// to `cmp`; ties are broken by the index, so that the selection is stable.
+struct BoundedHeap<'a> {
+    k: usize,
+    cmp: Compare<'a>,
+    heap: Vec<usize>,
+}
+
+impl<'a> BoundedHeap<'a> {
+    fn new(k: usize, cmp: Compare<'a>) -> Self {
+        Self {
+            k,
+            cmp,
+            heap: Vec::with_capacity(k),
+        }
+    }
+
+    #[inline]
+    fn compare(&self, lhs: usize, rhs: usize) -> Ordering {
+        (self.cmp)(lhs, rhs).then(lhs.cmp(&rhs))
+    }
+
+    #[inline]
+    fn push(&mut self, index: usize) {
+        if self.heap.len() < self.k {
+            self.heap.push(index);
+            self.sift_up(self.heap.len() - 1);
+        } else if self.k > 0 && self.compare(index, self.heap[0]) == Ordering::Less {
+            self.heap[0] = index;
+            self.sift_down(0);
+        }
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if self.compare(self.heap[position], self.heap[parent]) != Ordering::Greater {
+                break;
+            }
+            self.heap.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        let length = self.heap.len();
+        loop {
+            let left = 2 * position + 1;
+            let right = left + 1;
+            let mut largest = position;
+            if left < length
+                && self.compare(self.heap[left], self.heap[largest]) == Ordering::Greater
+            {
+                largest = left;
+            }
+            if right < length
+                && self.compare(self.heap[right], self.heap[largest]) == Ordering::Greater
+            {
+                largest = right;
+            }
+            if largest == position {
+                break;
+            }
+            self.heap.swap(position, largest);
+            position = largest;
+        }
+    }
+
+    /// Returns the indices in the heap, sorted.
+    fn into_sorted<I: Index>(self) -> PrimitiveArray<I> {
+        let Self { cmp, mut heap, .. } = self;
+        heap.sort_unstable_by(|lhs, rhs| cmp(*lhs, *rhs).then(lhs.cmp(rhs)));
+        let values = heap
+            .into_iter()
+            .map(|x| I::from_usize(x).unwrap())
+            .collect::<MutableBuffer<I>>();
+        PrimitiveArray::<I>::from_data(I::DATA_TYPE, values.into(), None)
+    }
+}
+
+fn top_k_by<I: Index>(cmp: Compare, length: usize, k: usize) -> PrimitiveArray<I> {
+    let mut heap = BoundedHeap::new(k.min(length), cmp);
+    (0..length).for_each(|index| heap.push(index));
+    heap.into_sorted()
+}
+
+/// Returns the indices of the first `k` elements of `values` when sorted according to
+/// `options`, in sorted order. Ties are resolved by the position in `values`.
+///
+/// This is equivalent to [`super::sort_to_indices`] with `limit = Some(k)`, but runs in
+/// `O(n log k)` using a bounded heap. Nulls are ordered according to `options.nulls_first`.
+/// # Errors
+/// Errors if the [`crate::datatypes::DataType`] of `values` has no natural order.
+pub fn top_k_to_indices<I: Index>(
+    values: &dyn Array,
+    k: usize,
+    options: &SortOptions,
+) -> Result<PrimitiveArray<I>> {
+    let cmp = build_compare(values, *options)?;
+    Ok(top_k_by(cmp, values.len(), k))
+}
+
+/// Returns the first `k` elements of `values` when sorted according to `options`.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::sort::{top_k, SortOptions};
+///
+/// let array = Int32Array::from(&[Some(5), None, Some(3), Some(9), Some(1)]);
+/// let options = SortOptions {
+///     descending: true,
+///     nulls_first: false,
+/// };
+/// let result = top_k(&array, 2, &options).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from_slice(&[9, 5]) as &dyn arrow2::array::Array);
+/// ```
+/// # Errors
+/// Errors if the [`crate::datatypes::DataType`] of `values` has no natural order or is not
+/// supported by [`take`].
+pub fn top_k(values: &dyn Array, k: usize, options: &SortOptions) -> Result<Box<dyn Array>> {
+    let indices = top_k_to_indices::<u64>(values, k, options)?;
+    take::take(values, &indices)
+}
+
+/// Returns the indices of the first `k` rows of `columns` when sorted lexicographically,
+/// in sorted order. Ties are resolved by the row position.
+///
+/// This is equivalent to [`super::lexsort_to_indices`] with `limit = Some(k)`, but runs in
+/// `O(n log k)` using a bounded heap.
+/// # Errors
+/// Errors if `columns` is empty, if the columns have different lengths or if any of them
+/// has no natural order.
+pub fn lexsort_top_k_to_indices<I: Index>(
+    columns: &[SortColumn],
+    k: usize,
+) -> Result<PrimitiveArray<I>> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::InvalidArgumentError(
+            "lexical sort columns have different row counts".to_string(),
+        ));
+    };
+
+    let comparators = columns
+        .iter()
+        .map(|column| build_compare(column.values, column.options.unwrap_or_default()))
+        .collect::<Result<Vec<Compare>>>()?;
+
+    let cmp = Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
+        for comparator in comparators.iter() {
+            match comparator(a_idx, b_idx) {
+                Ordering::Equal => continue,
+                other => return other,
+            }
+        }
+        Ordering::Equal
+    });
+
+    Ok(top_k_by(cmp, row_count, k))
+}
+
+/// Returns the first `k` rows of `columns` when sorted lexicographically.
+/// # Errors
+/// Errors if [`lexsort_top_k_to_indices`] errors or if any of the columns is not supported
+/// by [`take`].
+pub fn lexsort_top_k(columns: &[SortColumn], k: usize) -> Result<Vec<Box<dyn Array>>> {
+    let indices = lexsort_top_k_to_indices::<u64>(columns, k)?;
+    columns
+        .iter()
+        .map(|c| take::take(c.values, &indices))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::compute::sort::{lexsort_to_indices, sort_to_indices};
+
+    fn all_options() -> Vec<SortOptions> {
+        let mut options = vec![];
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                options.push(SortOptions {
+                    descending,
+                    nulls_first,
+                })
+            }
+        }
+        options
+    }
+
+    fn pseudo_random(length: usize, seed: u64) -> Vec<Option<i32>> {
+        let mut state = seed;
+        (0..length)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let value = (state >> 33) as i32 % 20;
+                if value % 7 == 0 {
+                    None
+                } else {
+                    Some(value)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn same_as_sort() -> Result<()> {
+        let array = Int32Array::from(pseudo_random(200, 1));
+        for options in all_options() {
+            for k in [0, 1, 5, 50, 199, 200, 300] {
+                let result = top_k(&array, k, &options)?;
+                let indices = sort_to_indices::<u32>(&array, &options, Some(k))?;
+                let expected = take::take(&array, &indices)?;
+                assert_eq!(result.as_ref(), expected.as_ref(), "{:?} {}", options, k);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn stable() -> Result<()> {
+        let array = Int32Array::from(&[Some(1), None, Some(1), None, Some(0)]);
+        let options = SortOptions {
+            descending: false,
+            nulls_first: true,
+        };
+        let result = top_k_to_indices::<u32>(&array, 4, &options)?;
+        assert_eq!(result, UInt32Array::from_slice(&[1, 3, 4, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn utf8() -> Result<()> {
+        let array = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("c")]);
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let result = top_k(&array, 3, &options)?;
+        let expected = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        Ok(())
+    }
+
+    #[test]
+    fn lexical_same_as_lexsort() -> Result<()> {
+        let a = Int32Array::from(pseudo_random(300, 2));
+        let b = Int32Array::from(pseudo_random(300, 3));
+        for a_options in all_options() {
+            for b_options in all_options() {
+                let columns = vec![
+                    SortColumn {
+                        values: &a,
+                        options: Some(a_options),
+                    },
+                    SortColumn {
+                        values: &b,
+                        options: Some(b_options),
+                    },
+                ];
+                for k in [0, 3, 17, 300] {
+                    let result = lexsort_top_k(&columns, k)?;
+                    let indices = lexsort_to_indices::<u32>(&columns, Some(k))?;
+                    assert_eq!(result[0].as_ref(), take::take(&a, &indices)?.as_ref());
+                    assert_eq!(result[1].as_ref(), take::take(&b, &indices)?.as_ref());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert!(lexsort_top_k(&[], 1).is_err());
+        let a = Int32Array::from_slice(&[1, 2]);
+        let b = Int32Array::from_slice(&[1]);
+        let columns = vec![
+            SortColumn {
+                values: &a,
+                options: None,
+            },
+            SortColumn {
+                values: &b,
+                options: None,
+            },
+        ];
+        assert!(lexsort_top_k(&columns, 1).is_err());
+    }
+}
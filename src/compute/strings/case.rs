@@ -0,0 +1,92 @@
+use crate::array::{Array, Offset, Utf8Array};
+use crate::error::Result;
+
+use super::unary;
+
+/// Applies `op` to every byte of the values of an ASCII-only `array`, reusing its offsets.
+fn ascii_map<O: Offset, F: Fn(&u8) -> u8>(array: &Utf8Array<O>, op: F) -> Utf8Array<O> {
+    let values = array.values().iter().map(op).collect::<Vec<_>>();
+    // Safety: mapping ASCII characters to ASCII characters preserves utf8
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            array.offsets().clone(),
+            values.into(),
+            array.validity().clone(),
+        )
+    }
+}
+
+/// Returns a new [`Utf8Array`] with every value in lowercase.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::strings::lower;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("Arrow"), None, Some("ÀB")]);
+/// let result = lower(&array).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("arrow"), None, Some("àb")]));
+/// ```
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn lower<O: Offset>(array: &Utf8Array<O>) -> Result<Utf8Array<O>> {
+    if array.values().is_ascii() {
+        Ok(ascii_map(array, u8::to_ascii_lowercase))
+    } else {
+        unary(array, |value, result| {
+            result.push_str(&value.to_lowercase())
+        })
+    }
+}
+
+/// Returns a new [`Utf8Array`] with every value in uppercase.
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn upper<O: Offset>(array: &Utf8Array<O>) -> Result<Utf8Array<O>> {
+    if array.values().is_ascii() {
+        Ok(ascii_map(array, u8::to_ascii_uppercase))
+    } else {
+        unary(array, |value, result| {
+            result.push_str(&value.to_uppercase())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii() -> Result<()> {
+        let array = Utf8Array::<i64>::from(&[Some("aB"), None, Some("")]);
+        assert_eq!(
+            upper(&array)?,
+            Utf8Array::<i64>::from(&[Some("AB"), None, Some("")])
+        );
+        assert_eq!(
+            lower(&array)?,
+            Utf8Array::<i64>::from(&[Some("ab"), None, Some("")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn non_ascii() -> Result<()> {
+        let array = Utf8Array::<i32>::from_slice(&["straße", "ǅ"]);
+        assert_eq!(
+            upper(&array)?,
+            Utf8Array::<i32>::from_slice(&["STRASSE", "Ǆ"])
+        );
+        assert_eq!(
+            lower(&array)?,
+            Utf8Array::<i32>::from_slice(&["straße", "ǆ"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sliced() -> Result<()> {
+        let array = Utf8Array::<i32>::from_slice(&["A", "B", "C"]).slice(1, 2);
+        assert_eq!(lower(&array)?, Utf8Array::<i32>::from_slice(&["b", "c"]));
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    array::{Array, Offset, Utf8Array},
+    buffer::MutableBuffer,
+    error::{ArrowError, Result},
+};
+
+/// Concatenates the values of each row of `arrays`, separated by `separator`.
+/// Null values are skipped; the result has no nulls.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::strings::concat_ws;
+///
+/// let a = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+/// let b = Utf8Array::<i32>::from(&[Some("1"), None, None]);
+/// let result = concat_ws(&[&a, &b], "-").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from_slice(&["a-1", "b", ""]));
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or the
+/// values of the result do not fit in offsets of type `O`.
+pub fn concat_ws<O: Offset>(arrays: &[&Utf8Array<O>], separator: &str) -> Result<Utf8Array<O>> {
+    let length = arrays.first().map(|x| x.len()).ok_or_else(|| {
+        ArrowError::InvalidArgumentError("concat_ws requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.len() != length) {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat_ws requires arrays of the same length".to_string(),
+        ));
+    }
+
+    let mut offsets = MutableBuffer::<O>::with_capacity(length + 1);
+    offsets.push(O::zero());
+    let capacity = arrays.iter().map(|x| x.values().len()).sum::<usize>();
+    let mut values = String::with_capacity(capacity);
+    let mut iters = arrays.iter().map(|x| x.iter()).collect::<Vec<_>>();
+    for _ in 0..length {
+        let mut first = true;
+        for value in iters.iter_mut().filter_map(|x| x.next().unwrap()) {
+            if !first {
+                values.push_str(separator);
+            }
+            values.push_str(value);
+            first = false;
+        }
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::KeyOverflowError)?);
+    }
+
+    // Safety: `values` is a `String` and thus valid utf8 at every offset
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(offsets.into(), values.into_bytes().into(), None)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() -> Result<()> {
+        let a = Utf8Array::<i64>::from(&[Some("a"), None, Some("")]);
+        let b = Utf8Array::<i64>::from(&[Some("b"), Some("c"), None]);
+        let c = Utf8Array::<i64>::from(&[Some("d"), Some("e"), None]);
+        let result = concat_ws(&[&a, &b, &c], ", ")?;
+        assert_eq!(
+            result,
+            Utf8Array::<i64>::from_slice(&["a, b, d", "c, e", ""])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let a = Utf8Array::<i32>::from_slice(&["a"]);
+        let b = Utf8Array::<i32>::from_slice(&["a", "b"]);
+        assert!(concat_ws::<i32>(&[], "").is_err());
+        assert!(concat_ws(&[&a, &b], "").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::array::{BooleanArray, Offset, Utf8Array};
+use crate::compute::utils::unary_utf8_boolean;
+
+/// Returns whether each value of `array` starts with `pattern`.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::strings::starts_with;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow"), None, Some("parquet")]);
+/// let result = starts_with(&array, "arr");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+/// ```
+pub fn starts_with<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    unary_utf8_boolean(array, |value| value.starts_with(pattern))
+}
+
+/// Returns whether each value of `array` ends with `pattern`.
+pub fn ends_with<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    unary_utf8_boolean(array, |value| value.ends_with(pattern))
+}
+
+/// Returns whether each value of `array` contains `pattern`.
+pub fn contains_str<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    unary_utf8_boolean(array, |value| value.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let array = Utf8Array::<i64>::from(&[Some("arrow"), None, Some("row")]);
+        assert_eq!(
+            ends_with(&array, "row"),
+            BooleanArray::from(&[Some(true), None, Some(true)])
+        );
+        assert_eq!(
+            contains_str(&array, "rr"),
+            BooleanArray::from(&[Some(true), None, Some(false)])
+        );
+        assert_eq!(
+            starts_with(&array, ""),
+            BooleanArray::from(&[Some(true), None, Some(true)])
+        );
+    }
+}
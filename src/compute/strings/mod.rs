@@ -0,0 +1,55 @@
+//! Defines kernels over [`Utf8Array`]s, such as [`lower`], [`trim`], [`lpad`] and [`split`].
+//!
+//! All kernels preserve the validity of their inputs: null slots are not evaluated and are
+//! null in the result. Kernels that do not change the number of bytes of each value, such
+//! as [`reverse`], reuse the offsets of their input.
+use crate::{
+    array::{Array, Offset, Utf8Array},
+    buffer::MutableBuffer,
+    error::{ArrowError, Result},
+};
+
+mod case;
+pub use case::*;
+mod concat_ws;
+pub use concat_ws::*;
+mod matches;
+pub use matches::*;
+mod pad;
+pub use pad::*;
+mod split;
+pub use split::*;
+mod transform;
+pub use transform::*;
+mod trim;
+pub use trim::*;
+
+/// Applies `op` to every non-null value of `array`; `op` writes the new value to the
+/// `String` it receives. The result has the same validity as `array`.
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+fn unary<O, F>(array: &Utf8Array<O>, mut op: F) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    F: FnMut(&str, &mut String),
+{
+    let mut offsets = MutableBuffer::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    let mut values = String::with_capacity(array.values().len());
+
+    for value in array.iter() {
+        if let Some(value) = value {
+            op(value, &mut values);
+        }
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::KeyOverflowError)?);
+    }
+
+    // Safety: `values` is a `String` and thus valid utf8 at every offset
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            offsets.into(),
+            values.into_bytes().into(),
+            array.validity().clone(),
+        )
+    })
+}
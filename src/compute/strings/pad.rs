@@ -0,0 +1,75 @@
+use crate::array::{Offset, Utf8Array};
+use crate::error::Result;
+
+use super::unary;
+
+/// Pads or truncates `value` to `length` characters, writing the result to `result`.
+fn pad(value: &str, length: usize, fill: &str, left: bool, result: &mut String) {
+    let count = value.chars().count();
+    if count >= length || fill.is_empty() {
+        result.extend(value.chars().take(length));
+        return;
+    }
+    let padding = fill.chars().cycle().take(length - count);
+    if left {
+        result.extend(padding);
+        result.push_str(value);
+    } else {
+        result.push_str(value);
+        result.extend(padding);
+    }
+}
+
+/// Returns a new [`Utf8Array`] with every value left-padded with `fill` to `length`
+/// characters. Values longer than `length` characters are truncated to their first `length`
+/// characters; values are not padded when `fill` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::strings::lpad;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("1"), None, Some("12345")]);
+/// let result = lpad(&array, 3, "0").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("001"), None, Some("123")]));
+/// ```
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn lpad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: &str) -> Result<Utf8Array<O>> {
+    unary(array, |value, result| {
+        pad(value, length, fill, true, result)
+    })
+}
+
+/// Returns a new [`Utf8Array`] with every value right-padded with `fill` to `length`
+/// characters. Values longer than `length` characters are truncated to their first `length`
+/// characters; values are not padded when `fill` is empty.
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn rpad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: &str) -> Result<Utf8Array<O>> {
+    unary(array, |value, result| {
+        pad(value, length, fill, false, result)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() -> Result<()> {
+        let array = Utf8Array::<i32>::from(&[Some("ab"), None, Some(""), Some("åäöü")]);
+        assert_eq!(
+            lpad(&array, 3, "xy")?,
+            Utf8Array::<i32>::from(&[Some("xab"), None, Some("xyx"), Some("åäö")])
+        );
+        assert_eq!(
+            rpad(&array, 5, "é")?,
+            Utf8Array::<i32>::from(&[Some("abééé"), None, Some("ééééé"), Some("åäöüé")])
+        );
+        assert_eq!(
+            rpad(&array, 3, "")?,
+            Utf8Array::<i32>::from(&[Some("ab"), None, Some(""), Some("åäö")])
+        );
+        Ok(())
+    }
+}
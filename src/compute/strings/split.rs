@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, ListArray, Offset, Utf8Array},
+    buffer::MutableBuffer,
+    error::{ArrowError, Result},
+};
+
+use super::unary;
+
+fn check_delimiter(delimiter: &str) -> Result<()> {
+    if delimiter.is_empty() {
+        Err(ArrowError::InvalidArgumentError(
+            "The delimiter must not be empty".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Splits every value of `array` by `delimiter`, returning a [`ListArray`] whose items are
+/// the parts of each value. Null values result in null lists.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, Utf8Array};
+/// use arrow2::compute::strings::split;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a,b"), None, Some("")]);
+/// let result = split(&array, ",").unwrap();
+/// let values = result.values().as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+/// assert_eq!(values, &Utf8Array::<i32>::from_slice(&["a", "b", ""]));
+/// assert_eq!(result.offsets().as_slice(), &[0, 2, 2, 3]);
+/// ```
+/// # Errors
+/// This function errors iff `delimiter` is empty or the parts do not fit in offsets of type `O`.
+pub fn split<O: Offset>(array: &Utf8Array<O>, delimiter: &str) -> Result<ListArray<O>> {
+    check_delimiter(delimiter)?;
+
+    let mut list_offsets = MutableBuffer::<O>::with_capacity(array.len() + 1);
+    list_offsets.push(O::zero());
+    let mut offsets = MutableBuffer::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    let mut values = MutableBuffer::<u8>::with_capacity(array.values().len());
+
+    let mut length = 0;
+    for value in array.iter() {
+        if let Some(value) = value {
+            for part in value.split(delimiter) {
+                values.extend_from_slice(part.as_bytes());
+                offsets.push(O::from_usize(values.len()).ok_or(ArrowError::KeyOverflowError)?);
+                length += 1;
+            }
+        }
+        list_offsets.push(O::from_usize(length).ok_or(ArrowError::KeyOverflowError)?);
+    }
+
+    // Safety: every part of a utf8 value split by a utf8 delimiter is valid utf8
+    let values =
+        unsafe { Utf8Array::<O>::from_data_unchecked(offsets.into(), values.into(), None) };
+    let data_type = ListArray::<O>::default_datatype(values.data_type().clone());
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        list_offsets.into(),
+        Arc::new(values),
+        array.validity().clone(),
+    ))
+}
+
+/// Returns the part at position `index` of every value of `array` split by `delimiter`.
+/// `index` is 1-based and counts from the end when negative; values with fewer parts result
+/// in an empty string.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::strings::split_part;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a.b.c"), None, Some("d")]);
+/// let result = split_part(&array, ".", -1).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("c"), None, Some("d")]));
+/// ```
+/// # Errors
+/// This function errors iff `delimiter` is empty or `index` is 0.
+pub fn split_part<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    index: i64,
+) -> Result<Utf8Array<O>> {
+    check_delimiter(delimiter)?;
+    if index == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "The index of split_part is 1-based and must not be 0".to_string(),
+        ));
+    }
+
+    let position = index.unsigned_abs() as usize - 1;
+    unary(array, |value, result| {
+        let part = if index > 0 {
+            value.split(delimiter).nth(position)
+        } else {
+            value.rsplit(delimiter).nth(position)
+        };
+        result.push_str(part.unwrap_or(""))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::DataType;
+
+    #[test]
+    fn test_split() -> Result<()> {
+        let array = Utf8Array::<i64>::from(&[Some("a::b::"), None, Some("c")]);
+        let result = split(&array, "::")?;
+        assert_eq!(
+            result.data_type(),
+            &ListArray::<i64>::default_datatype(DataType::LargeUtf8)
+        );
+        assert_eq!(result.offsets().as_slice(), &[0, 3, 3, 4]);
+        assert!(result.is_null(1));
+        let values = result
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i64>>()
+            .unwrap();
+        assert_eq!(values, &Utf8Array::<i64>::from_slice(&["a", "b", "", "c"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_part() -> Result<()> {
+        let array = Utf8Array::<i32>::from(&[Some("a,b,c"), None, Some("")]);
+        let result = split_part(&array, ",", 2)?;
+        assert_eq!(result, Utf8Array::<i32>::from(&[Some("b"), None, Some("")]));
+        let result = split_part(&array, ",", 4)?;
+        assert_eq!(result, Utf8Array::<i32>::from(&[Some(""), None, Some("")]));
+        let result = split_part(&array, ",", -3)?;
+        assert_eq!(result, Utf8Array::<i32>::from(&[Some("a"), None, Some("")]));
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let array = Utf8Array::<i32>::from_slice(&["a"]);
+        assert!(split(&array, "").is_err());
+        assert!(split_part(&array, ",", 0).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::array::{Array, Offset, Utf8Array};
+use crate::error::Result;
+
+use super::unary;
+
+/// Returns a new [`Utf8Array`] with every occurrence of `from` replaced by `to`.
+/// Values are returned unchanged when `from` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::strings::replace;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a-b-c"), None]);
+/// let result = replace(&array, "-", "+").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("a+b+c"), None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn replace<O: Offset>(array: &Utf8Array<O>, from: &str, to: &str) -> Result<Utf8Array<O>> {
+    if from.is_empty() {
+        return Ok(array.clone());
+    }
+    unary(array, |value, result| {
+        let mut last = 0;
+        for (start, part) in value.match_indices(from) {
+            result.push_str(&value[last..start]);
+            result.push_str(to);
+            last = start + part.len();
+        }
+        result.push_str(&value[last..]);
+    })
+}
+
+/// Returns a new [`Utf8Array`] with the characters of every value in reverse order.
+/// Since this does not change the number of bytes of each value, the offsets are reused.
+pub fn reverse<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    let mut values = array.values().as_slice().to_vec();
+    array
+        .offsets()
+        .windows(2)
+        .enumerate()
+        .filter(|(i, _)| array.is_valid(*i))
+        .for_each(|(_, window)| {
+            let start = window[0].to_usize();
+            let end = window[1].to_usize();
+            let value = &array.values()[start..end];
+            // Safety: the values of a `Utf8Array` are valid utf8 between offsets
+            let value = unsafe { std::str::from_utf8_unchecked(value) };
+            let mut position = end;
+            value.chars().for_each(|c| {
+                let length = c.len_utf8();
+                c.encode_utf8(&mut values[position - length..position]);
+                position -= length;
+            });
+        });
+    // Safety: every value was replaced by a sequence of utf8 characters of the same length
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            array.offsets().clone(),
+            values.into(),
+            array.validity().clone(),
+        )
+    }
+}
+
+/// Returns a new [`Utf8Array`] with every value repeated `n` times.
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn repeat<O: Offset>(array: &Utf8Array<O>, n: usize) -> Result<Utf8Array<O>> {
+    unary(array, |value, result| {
+        (0..n).for_each(|_| result.push_str(value));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace() -> Result<()> {
+        let array = Utf8Array::<i32>::from(&[Some("aaa"), None, Some("")]);
+        assert_eq!(
+            replace(&array, "aa", "b")?,
+            Utf8Array::<i32>::from(&[Some("ba"), None, Some("")])
+        );
+        assert_eq!(replace(&array, "", "b")?, array);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse() {
+        let array = Utf8Array::<i32>::from(&[Some("abc"), None, Some("añb€")]).slice(0, 3);
+        assert_eq!(
+            reverse(&array),
+            Utf8Array::<i32>::from(&[Some("cba"), None, Some("€bña")])
+        );
+    }
+
+    #[test]
+    fn test_repeat() -> Result<()> {
+        let array = Utf8Array::<i64>::from(&[Some("ab"), None]);
+        assert_eq!(
+            repeat(&array, 3)?,
+            Utf8Array::<i64>::from(&[Some("ababab"), None])
+        );
+        assert_eq!(
+            repeat(&array, 0)?,
+            Utf8Array::<i64>::from(&[Some(""), None])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::array::{Offset, Utf8Array};
+use crate::error::Result;
+
+use super::unary;
+
+/// Returns a new [`Utf8Array`] with leading and trailing whitespace removed from every value.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::strings::trim;
+///
+/// let array = Utf8Array::<i32>::from(&[Some(" a b\t"), None]);
+/// assert_eq!(trim(&array).unwrap(), Utf8Array::<i32>::from(&[Some("a b"), None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn trim<O: Offset>(array: &Utf8Array<O>) -> Result<Utf8Array<O>> {
+    unary(array, |value, result| result.push_str(value.trim()))
+}
+
+/// Returns a new [`Utf8Array`] with leading whitespace removed from every value.
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn ltrim<O: Offset>(array: &Utf8Array<O>) -> Result<Utf8Array<O>> {
+    unary(array, |value, result| result.push_str(value.trim_start()))
+}
+
+/// Returns a new [`Utf8Array`] with trailing whitespace removed from every value.
+/// # Errors
+/// This function errors iff the values of the result do not fit in offsets of type `O`.
+pub fn rtrim<O: Offset>(array: &Utf8Array<O>) -> Result<Utf8Array<O>> {
+    unary(array, |value, result| result.push_str(value.trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() -> Result<()> {
+        let array = Utf8Array::<i32>::from(&[Some("  a "), None, Some(""), Some("\u{3000}b")]);
+        assert_eq!(
+            trim(&array)?,
+            Utf8Array::<i32>::from(&[Some("a"), None, Some(""), Some("b")])
+        );
+        assert_eq!(
+            ltrim(&array)?,
+            Utf8Array::<i32>::from(&[Some("a "), None, Some(""), Some("b")])
+        );
+        assert_eq!(
+            rtrim(&array)?,
+            Utf8Array::<i32>::from(&[Some("  a"), None, Some(""), Some("\u{3000}b")])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+//! Defines kernels to extract, project and build [`StructArray`]s.
+//!
+//! The validity of a [`StructArray`] is not part of the validity of its fields: kernels that
+//! extract fields combine it into the extracted arrays, so that a slot is null whenever one
+//! of its parents is null.
+use std::sync::Arc;
+
+use crate::{
+    array::{clone, Array, StructArray},
+    bitmap::Bitmap,
+    datatypes::{DataType, Field, Schema},
+    error::{ArrowError, Result},
+    record_batch::RecordBatch,
+};
+
+use super::utils::{build_from_slots, combine_validities};
+
+/// Returns the index of the field named `name` of `array`.
+fn field_index(array: &StructArray, name: &str) -> Result<usize> {
+    array
+        .fields()
+        .iter()
+        .position(|x| x.name() == name)
+        .ok_or_else(|| {
+            let names = array.fields().iter().map(|x| x.name()).collect::<Vec<_>>();
+            ArrowError::InvalidArgumentError(format!(
+                "Unable to get field named \"{}\". Valid fields: {:?}",
+                name, names
+            ))
+        })
+}
+
+/// Returns `array` with the slots that are null in `validity` set to null.
+fn mask(array: &dyn Array, validity: &Option<Bitmap>) -> Box<dyn Array> {
+    match validity {
+        Some(validity) if validity.null_count() > 0 => {
+            let sources = validity
+                .iter()
+                .enumerate()
+                .map(|(row, is_valid)| is_valid.then_some((0, row)));
+            build_from_slots(&[array], true, array.len(), sources)
+        }
+        _ => clone(array),
+    }
+}
+
+/// Returns the field of `array` at `path`, a sequence of field names separated by `.` that
+/// descends into nested structs. The validity of the result combines the validity of the
+/// field with the validities of all its parents.
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, StructArray};
+/// use arrow2::bitmap::Bitmap;
+/// use arrow2::compute::structure::struct_field;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let c = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let b = StructArray::from_data(
+///     vec![Field::new("c", DataType::Int32, true)],
+///     vec![Arc::new(c)],
+///     None,
+/// );
+/// let a = StructArray::from_data(
+///     vec![Field::new("b", b.data_type().clone(), true)],
+///     vec![Arc::new(b)],
+///     Some(Bitmap::from([true, true, false])),
+/// );
+///
+/// let result = struct_field(&a, "b.c").unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), None, None]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff a field of `path` does not exist or is not a struct when it is not
+/// the last one.
+pub fn struct_field(array: &StructArray, path: &str) -> Result<Box<dyn Array>> {
+    let mut names = path.split('.');
+    // `split` yields at least one item
+    let mut index = field_index(array, names.next().unwrap())?;
+    let mut array = array;
+    let mut validity = array.validity().clone();
+    for name in names {
+        let child = array.values()[index].as_ref();
+        array = child
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Unable to get field named \"{}\" of {:?}, as it is not a struct",
+                    name,
+                    child.data_type()
+                ))
+            })?;
+        index = field_index(array, name)?;
+        validity = combine_validities(&validity, array.validity());
+    }
+    Ok(mask(array.values()[index].as_ref(), &validity))
+}
+
+/// Returns a [`StructArray`] with the fields of `array` named `names`, in the order of
+/// `names`, and the validity of `array`.
+/// # Errors
+/// This function errors iff `names` is empty or a field of `names` does not exist.
+pub fn struct_project(array: &StructArray, names: &[&str]) -> Result<StructArray> {
+    if names.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Struct projection requires at least one field".to_string(),
+        ));
+    }
+    let indices = names
+        .iter()
+        .map(|name| field_index(array, name))
+        .collect::<Result<Vec<_>>>()?;
+
+    let fields = indices.iter().map(|x| array.fields()[*x].clone()).collect();
+    let values = indices.iter().map(|x| array.values()[*x].clone()).collect();
+    Ok(StructArray::from_data(
+        fields,
+        values,
+        array.validity().clone(),
+    ))
+}
+
+/// Returns a [`StructArray`] without nulls whose fields are `arrays`, named `names`.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::structure::{struct_field, struct_from_arrays};
+///
+/// let a = Int32Array::from_slice(&[1, 2]);
+/// let b = Utf8Array::<i32>::from(&[Some("x"), None]);
+/// let array = struct_from_arrays(&["a", "b"], &[&a, &b]).unwrap();
+///
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(&b as &dyn Array, struct_field(&array, "b").unwrap().as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty or `names` and `arrays` do not have the same
+/// length, or the arrays do not have the same length.
+pub fn struct_from_arrays(names: &[&str], arrays: &[&dyn Array]) -> Result<StructArray> {
+    if arrays.is_empty() || names.len() != arrays.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A struct requires at least one array and a name per array (got {} names and {} arrays)",
+            names.len(),
+            arrays.len()
+        )));
+    }
+    if arrays.iter().any(|x| x.len() != arrays[0].len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "All arrays of a struct must have the same length".to_string(),
+        ));
+    }
+
+    let fields = names
+        .iter()
+        .zip(arrays.iter())
+        .map(|(name, array)| Field::new(name, array.data_type().clone(), true))
+        .collect();
+    let values = arrays.iter().map(|x| clone(*x).into()).collect();
+    Ok(StructArray::from_data(fields, values, None))
+}
+
+/// Returns `batch` with the struct column named `column` replaced by its fields, in place.
+/// The validity of the struct is combined into its fields, which are nullable when the struct
+/// field is nullable, so that the schema of the result depends only on the schema of `batch`.
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::compute::structure::{struct_from_arrays, unnest_struct};
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::record_batch::RecordBatch;
+///
+/// let id = Int32Array::from_slice(&[1, 2]);
+/// let b = Utf8Array::<i32>::from_slice(&["x", "y"]);
+/// let c = Int32Array::from_slice(&[3, 4]);
+/// let array = struct_from_arrays(&["b", "c"], &[&b, &c]).unwrap();
+///
+/// let schema = Schema::new(vec![
+///     Field::new("id", DataType::Int32, false),
+///     Field::new("a", array.data_type().clone(), true),
+/// ]);
+/// let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(id), Arc::new(array)]).unwrap();
+///
+/// let batch = unnest_struct(&batch, "a").unwrap();
+/// let names = batch.schema().fields().iter().map(|x| x.name().as_str()).collect::<Vec<_>>();
+/// assert_eq!(names, vec!["id", "b", "c"]);
+/// ```
+/// # Errors
+/// This function errors iff `column` does not exist, is not a struct, or the resulting batch
+/// is invalid.
+pub fn unnest_struct(batch: &RecordBatch, column: &str) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let index = schema.index_of(column)?;
+    let parent = schema.field(index);
+    let array = batch
+        .column(index)
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Unable to unnest column \"{}\" of type {:?}, as it is not a struct",
+                column,
+                parent.data_type()
+            ))
+        })?;
+
+    let children = array
+        .fields()
+        .iter()
+        .zip(array.values().iter())
+        .map(|(field, values)| {
+            let mut field = field.clone();
+            field.nullable |= parent.is_nullable();
+            let values: Arc<dyn Array> = mask(values.as_ref(), array.validity()).into();
+            (field, values)
+        });
+
+    let mut fields = Vec::with_capacity(schema.fields().len() + array.fields().len());
+    let mut columns = Vec::with_capacity(fields.capacity());
+    fields.extend(schema.fields()[..index].iter().cloned());
+    columns.extend(batch.columns()[..index].iter().cloned());
+    for (field, values) in children {
+        fields.push(field);
+        columns.push(values);
+    }
+    fields.extend(schema.fields()[index + 1..].iter().cloned());
+    columns.extend(batch.columns()[index + 1..].iter().cloned());
+
+    let schema = Schema::new_from(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+/// Returns whether `data_type` is a struct with a field at `path`, as in [`struct_field`].
+pub fn can_struct_field(data_type: &DataType, path: &str) -> bool {
+    path.split('.')
+        .try_fold(data_type, |data_type, name| match data_type {
+            DataType::Struct(fields) => fields
+                .iter()
+                .find(|x| x.name() == name)
+                .map(|x| x.data_type()),
+            _ => None,
+        })
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    fn nested() -> StructArray {
+        let c = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+        let d = Utf8Array::<i32>::from_slice(&["a", "b", "c", "d"]);
+        let b = StructArray::from_data(
+            vec![
+                Field::new("c", DataType::Int32, true),
+                Field::new("d", DataType::Utf8, false),
+            ],
+            vec![Arc::new(c), Arc::new(d)],
+            Some(Bitmap::from([true, false, true, true])),
+        );
+        let e = BooleanArray::from_slice(&[true, false, true, false]);
+        StructArray::from_data(
+            vec![
+                Field::new("b", b.data_type().clone(), true),
+                Field::new("e", DataType::Boolean, false),
+            ],
+            vec![Arc::new(b), Arc::new(e)],
+            Some(Bitmap::from([true, true, true, false])),
+        )
+    }
+
+    #[test]
+    fn field() {
+        let array = nested();
+
+        let result = struct_field(&array, "b.c").unwrap();
+        let expected = Int32Array::from(&[Some(1), None, None, None]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        let result = struct_field(&array.slice(1, 3), "b.d").unwrap();
+        let expected = Utf8Array::<i32>::from(&[None, Some("c"), None]);
+        assert_eq!(&expected as &dyn Array, result.as_ref());
+
+        assert!(struct_field(&array, "b.x").is_err());
+        assert!(struct_field(&array, "e.c").is_err());
+        assert!(can_struct_field(array.data_type(), "b.d"));
+        assert!(!can_struct_field(array.data_type(), "e.c"));
+    }
+
+    #[test]
+    fn project() {
+        let array = nested();
+        let result = struct_project(&array, &["e", "b"]).unwrap();
+        assert_eq!(result.fields()[0].name(), "e");
+        assert_eq!(
+            result.values()[1].data_type(),
+            array.values()[0].data_type()
+        );
+        assert_eq!(result.validity(), array.validity());
+
+        assert!(struct_project(&array, &["x"]).is_err());
+        assert!(struct_project(&array, &[]).is_err());
+    }
+
+    #[test]
+    fn unnest() {
+        let array = nested();
+        let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)]).unwrap();
+
+        let result = unnest_struct(&batch, "a").unwrap();
+        assert_eq!(result.num_columns(), 2);
+        // the struct is nullable, which makes its non-nullable field "e" nullable
+        assert!(result.schema().field(1).is_nullable());
+        let expected = BooleanArray::from(&[Some(true), Some(false), Some(true), None]);
+        assert_eq!(&expected as &dyn Array, result.column(1).as_ref());
+
+        assert!(unnest_struct(&result, "e").is_err());
+
+        let array = StructArray::from_data(
+            vec![Field::new("e", DataType::Boolean, false)],
+            vec![Arc::new(BooleanArray::from_slice(&[true, false]))],
+            None,
+        );
+        let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), false)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array.clone())]).unwrap();
+        let result = unnest_struct(&batch, "a").unwrap();
+        assert!(!result.schema().field(0).is_nullable());
+
+        // the schema does not depend on whether there are nulls
+        let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)]).unwrap();
+        let result = unnest_struct(&batch, "a").unwrap();
+        assert!(result.schema().field(0).is_nullable());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{
+    array::{Array, FixedSizeBinaryArray, PrimitiveArray},
+    bitmap::MutableBitmap,
+    buffer::MutableBuffer,
+};
+
+use super::Index;
+
+/// `take` implementation for [`FixedSizeBinaryArray`]
+pub fn take<I: Index>(
+    values: &FixedSizeBinaryArray,
+    indices: &PrimitiveArray<I>,
+) -> FixedSizeBinaryArray {
+    let size = values.size();
+    let mut buffer = MutableBuffer::<u8>::with_capacity(indices.len() * size);
+
+    let validity = if values.null_count() > 0 || indices.null_count() > 0 {
+        let mut validity = MutableBitmap::with_capacity(indices.len());
+        indices.iter().for_each(|index| match index {
+            Some(index) if values.is_valid(index.to_usize()) => {
+                buffer.extend_from_slice(values.value(index.to_usize()));
+                validity.push(true);
+            }
+            _ => {
+                buffer.extend_constant(size, 0);
+                validity.push(false);
+            }
+        });
+        validity.into()
+    } else {
+        indices
+            .values()
+            .iter()
+            .for_each(|index| buffer.extend_from_slice(values.value(index.to_usize())));
+        None
+    };
+
+    FixedSizeBinaryArray::from_data(values.data_type().clone(), buffer.into(), validity)
+}
@@ -21,7 +21,7 @@ use crate::{
     array::{new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::{DataType, IntervalUnit},
     error::Result,
-    types::days_ms,
+    types::{days_ms, months_days_ns},
 };
 
 pub use crate::array::Index;
@@ -29,6 +29,7 @@ pub use crate::array::Index;
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_binary;
 mod generic_binary;
 mod list;
 mod primitive;
@@ -78,6 +79,9 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
         | DataType::Duration(_)
         | DataType::Timestamp(_, _) => downcast_take!(i64, values, indices),
         DataType::Interval(IntervalUnit::DayTime) => downcast_take!(days_ms, values, indices),
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            downcast_take!(months_days_ns, values, indices)
+        }
         DataType::UInt8 => downcast_take!(u8, values, indices),
         DataType::UInt16 => downcast_take!(u16, values, indices),
         DataType::UInt32 => downcast_take!(u32, values, indices),
@@ -102,6 +106,10 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let values = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(binary::take::<i64, _>(values, indices)))
         }
+        DataType::FixedSizeBinary(_) => {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_binary::take::<_>(values, indices)))
+        }
         DataType::Dictionary(key_type, _) => match key_type.as_ref() {
             DataType::Int8 => downcast_dict_take!(i8, values, indices),
             DataType::Int16 => downcast_dict_take!(i16, values, indices),
@@ -166,6 +174,7 @@ pub fn can_take(data_type: &DataType) -> bool {
         | DataType::LargeUtf8
         | DataType::Binary
         | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_)
         | DataType::Struct(_)
         | DataType::List(_)
         | DataType::LargeList(_) => true,
@@ -322,6 +331,7 @@ mod tests {
             Time64(TimeUnit::Nanosecond),
             Interval(IntervalUnit::DayTime),
             Interval(IntervalUnit::YearMonth),
+            Interval(IntervalUnit::MonthDayNano),
             Date32,
             Time32(TimeUnit::Second),
             Time32(TimeUnit::Millisecond),
@@ -330,6 +340,7 @@ mod tests {
             LargeUtf8,
             Binary,
             LargeBinary,
+            FixedSizeBinary(2),
             Duration(TimeUnit::Second),
             Duration(TimeUnit::Millisecond),
             Duration(TimeUnit::Microsecond),
@@ -362,4 +373,21 @@ mod tests {
         let a = take(&values, &indices).unwrap();
         assert_eq!(a.len(), 0)
     }
+
+    #[test]
+    fn fixed_size_binary() {
+        let values = FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(2),
+            vec![1, 2, 3, 4, 5, 6].into(),
+            Some([true, false, true].into()),
+        );
+        let indices = Int32Array::from(&[Some(2), None, Some(1), Some(0)]);
+        let result = take(&values, &indices).unwrap();
+        let expected = FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(2),
+            vec![5, 6, 0, 0, 0, 0, 1, 2].into(),
+            Some([true, false, false, true].into()),
+        );
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
 }
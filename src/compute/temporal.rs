@@ -16,103 +16,147 @@
 // under the License.
 
 //! Defines temporal kernels for time and date related functions.
+//!
+//! Date components, such as [`year`] or [`month`], are extracted from `Date32`, `Date64` and
+//! `Timestamp` arrays; time components, such as [`hour`] or [`second`], are additionally
+//! extracted from `Time32` and `Time64` arrays. Timestamps with a timezone are converted to
+//! their local time before extracting components. Timezones are either fixed offsets such
+//! as `+01:00` (see [`parse_offset`]) or, with feature `chrono-tz`, IANA timezones such as
+//! `America/New_York`, whose offsets account for daylight saving time.
 
-use chrono::{Datelike, Timelike};
+use std::convert::TryFrom;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
 
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::temporal_conversions::*;
+use crate::types::NativeType;
 
 use super::arity::unary;
 
-/// Extracts the hours of a given temporal array as an array of integers
-pub fn hour(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
-    let final_data_type = DataType::UInt32;
+#[inline]
+fn as_primitive<T: NativeType>(array: &dyn Array) -> &PrimitiveArray<T> {
+    array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap()
+}
+
+/// Applies `op` to the (local) datetime of every slot of a `Date32`, `Date64` or `Timestamp`
+/// array.
+fn extract_datetime<T, F>(
+    name: &str,
+    array: &dyn Array,
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(NaiveDateTime) -> T,
+{
     match array.data_type() {
-        DataType::Time32(TimeUnit::Second) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i32>>()
-                .unwrap();
-            Ok(unary(array, |x| time32s_to_time(x).hour(), final_data_type))
-        }
-        DataType::Time32(TimeUnit::Microsecond) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i32>>()
-                .unwrap();
-            Ok(unary(
-                array,
-                |x| time32ms_to_time(x).hour(),
-                final_data_type,
-            ))
-        }
-        DataType::Time64(TimeUnit::Microsecond) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            Ok(unary(
-                array,
-                |x| time64us_to_time(x).hour(),
-                final_data_type,
-            ))
-        }
-        DataType::Time64(TimeUnit::Nanosecond) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            Ok(unary(
-                array,
-                |x| time64ns_to_time(x).hour(),
-                final_data_type,
-            ))
-        }
-        DataType::Date32 => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i32>>()
-                .unwrap();
-            Ok(unary(
-                array,
-                |x| date32_to_datetime(x).hour(),
-                final_data_type,
-            ))
-        }
-        DataType::Date64 => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            Ok(unary(
+        DataType::Date32 => Ok(unary(
+            as_primitive::<i32>(array),
+            |x| op(date32_to_datetime(x)),
+            data_type,
+        )),
+        DataType::Date64 => Ok(unary(
+            as_primitive::<i64>(array),
+            |x| op(date64_to_datetime(x)),
+            data_type,
+        )),
+        DataType::Timestamp(time_unit, None) => Ok(unary(
+            as_primitive::<i64>(array),
+            |x| op(timestamp_to_naive_datetime(x, time_unit)),
+            data_type,
+        )),
+        DataType::Timestamp(time_unit, Some(timezone)) => {
+            let array = as_primitive::<i64>(array);
+            with_timezone!(timezone, |tz| Ok(unary(
                 array,
-                |x| date64_to_datetime(x).hour(),
-                final_data_type,
-            ))
-        }
-        DataType::Timestamp(time_unit, None) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            let op = match time_unit {
-                TimeUnit::Second => |x| timestamp_s_to_datetime(x).hour(),
-                TimeUnit::Millisecond => |x| timestamp_ms_to_datetime(x).hour(),
-                TimeUnit::Microsecond => |x| timestamp_us_to_datetime(x).hour(),
-                TimeUnit::Nanosecond => |x| timestamp_ns_to_datetime(x).hour(),
-            };
-            Ok(unary(array, op, final_data_type))
+                |x| op(timestamp_to_datetime(x, time_unit, &tz).naive_local()),
+                data_type,
+            )))
         }
         dt => Err(ArrowError::NotYetImplemented(format!(
-            "\"hour\" does not support type {:?}",
-            dt
+            "\"{}\" does not support type {:?}",
+            name, dt
         ))),
     }
 }
 
-/// Checks if an array of type `datatype` can perform hour operation
+/// Applies `op` to the date of every slot of a `Date32`, `Date64` or `Timestamp` array.
+fn extract_date<T, F>(
+    name: &str,
+    array: &dyn Array,
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(NaiveDate) -> T,
+{
+    extract_datetime(name, array, data_type, |x| op(x.date()))
+}
+
+/// Applies `op` to the time of every slot of a `Time32`, `Time64`, `Date32`, `Date64` or
+/// `Timestamp` array.
+fn extract_time<T, F>(
+    name: &str,
+    array: &dyn Array,
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(NaiveTime) -> T,
+{
+    match array.data_type() {
+        DataType::Time32(TimeUnit::Second) => Ok(unary(
+            as_primitive::<i32>(array),
+            |x| op(time32s_to_time(x)),
+            data_type,
+        )),
+        DataType::Time32(TimeUnit::Millisecond) => Ok(unary(
+            as_primitive::<i32>(array),
+            |x| op(time32ms_to_time(x)),
+            data_type,
+        )),
+        DataType::Time64(TimeUnit::Microsecond) => Ok(unary(
+            as_primitive::<i64>(array),
+            |x| op(time64us_to_time(x)),
+            data_type,
+        )),
+        DataType::Time64(TimeUnit::Nanosecond) => Ok(unary(
+            as_primitive::<i64>(array),
+            |x| op(time64ns_to_time(x)),
+            data_type,
+        )),
+        _ => extract_datetime(name, array, data_type, |x| op(x.time())),
+    }
+}
+
+/// Extracts the hours of a given temporal array as an array of integers
+pub fn hour(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_time("hour", array, DataType::UInt32, |x| x.hour())
+}
+
+/// Extracts the minutes of a given temporal array as an array of integers
+pub fn minute(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_time("minute", array, DataType::UInt32, |x| x.minute())
+}
+
+/// Extracts the seconds of a given temporal array as an array of integers
+pub fn second(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_time("second", array, DataType::UInt32, |x| x.second())
+}
+
+/// Extracts the nanoseconds of the second of a given temporal array as an array of integers
+pub fn nanosecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_time("nanosecond", array, DataType::UInt32, |x| x.nanosecond())
+}
+
+/// Checks if an array of type `datatype` can perform hour operation. The same types
+/// support [`minute`], [`second`], [`nanosecond`] and [`epoch`].
 ///
 /// # Examples
 /// ```
@@ -129,80 +173,364 @@ pub fn can_hour(data_type: &DataType) -> bool {
     matches!(
         data_type,
         DataType::Time32(TimeUnit::Second)
-            | DataType::Time32(TimeUnit::Microsecond)
+            | DataType::Time32(TimeUnit::Millisecond)
             | DataType::Time64(TimeUnit::Microsecond)
             | DataType::Time64(TimeUnit::Nanosecond)
-            | DataType::Date32
-            | DataType::Date64
-            | DataType::Timestamp(_, None)
-    )
+    ) || can_year(data_type)
 }
 
-/// Extracts the hours of a given temporal array as an array of integers
+/// Extracts the years of a given temporal array as an array of integers
 pub fn year(array: &dyn Array) -> Result<PrimitiveArray<i32>> {
-    let final_data_type = DataType::Int32;
+    extract_date("year", array, DataType::Int32, |x| x.year())
+}
+
+/// Extracts the months (from 1 to 12) of a given temporal array as an array of integers
+pub fn month(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_date("month", array, DataType::UInt32, |x| x.month())
+}
+
+/// Extracts the days of the month (from 1 to 31) of a given temporal array as an array of
+/// integers
+pub fn day(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_date("day", array, DataType::UInt32, |x| x.day())
+}
+
+/// Extracts the ISO days of the week (from 1 for Monday to 7 for Sunday) of a given temporal
+/// array as an array of integers
+pub fn day_of_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_date("day_of_week", array, DataType::UInt32, |x| {
+        x.weekday().number_from_monday()
+    })
+}
+
+/// Extracts the days of the year (from 1 to 366) of a given temporal array as an array of
+/// integers
+pub fn day_of_year(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_date("day_of_year", array, DataType::UInt32, |x| x.ordinal())
+}
+
+/// Extracts the ISO 8601 week numbers (from 1 to 53) of a given temporal array as an array of
+/// integers
+pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_date("iso_week", array, DataType::UInt32, |x| x.iso_week().week())
+}
+
+/// Extracts the quarters (from 1 to 4) of a given temporal array as an array of integers
+pub fn quarter(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    extract_date("quarter", array, DataType::UInt32, |x| {
+        (x.month() - 1) / 3 + 1
+    })
+}
+
+/// Checks if an array of type `datatype` can perform year operation. The same types
+/// support [`month`], [`day`], [`day_of_week`], [`day_of_year`], [`iso_week`] and [`quarter`].
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::temporal::can_year;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Date32;
+/// assert_eq!(can_year(&data_type), true);
+
+/// let data_type = DataType::Int8;
+/// assert_eq!(can_year(&data_type), false);
+/// ```
+pub fn can_year(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, None) => true,
+        DataType::Timestamp(_, Some(timezone)) => is_valid_timezone(timezone),
+        _ => false,
+    }
+}
+
+/// Returns the number of seconds, including fractions, since the Unix epoch of every slot of
+/// a `Date32`, `Date64` or `Timestamp` array, and since midnight of every slot of a `Time32` or
+/// `Time64` array. The timezone of timestamps does not change the result.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Int64Array};
+/// use arrow2::compute::temporal::epoch;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Int64Array::from(&[Some(1500), None])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+/// let result = epoch(&array).unwrap();
+/// assert_eq!(result, Float64Array::from(&[Some(1.5), None]));
+/// ```
+pub fn epoch(array: &dyn Array) -> Result<PrimitiveArray<f64>> {
+    let data_type = DataType::Float64;
+    let seconds = |time_unit: &TimeUnit| timeunit_scale(&TimeUnit::Second, time_unit);
     match array.data_type() {
-        DataType::Date32 => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i32>>()
-                .unwrap();
+        DataType::Date32 => Ok(unary(
+            as_primitive::<i32>(array),
+            |x| (x as i64 * SECONDS_IN_DAY) as f64,
+            data_type,
+        )),
+        DataType::Date64 => {
+            let scale = seconds(&TimeUnit::Millisecond);
             Ok(unary(
-                array,
-                |x| date32_to_datetime(x).year(),
-                final_data_type,
+                as_primitive::<i64>(array),
+                |x| x as f64 * scale,
+                data_type,
             ))
         }
-        DataType::Date64 => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
+        DataType::Time32(time_unit @ (TimeUnit::Second | TimeUnit::Millisecond)) => {
+            let scale = seconds(time_unit);
             Ok(unary(
-                array,
-                |x| date64_to_datetime(x).year(),
-                final_data_type,
+                as_primitive::<i32>(array),
+                |x| x as f64 * scale,
+                data_type,
             ))
         }
-        DataType::Timestamp(time_unit, None) => {
-            let array = array
-                .as_any()
-                .downcast_ref::<PrimitiveArray<i64>>()
-                .unwrap();
-            let op = match time_unit {
-                TimeUnit::Second => |x| timestamp_s_to_datetime(x).year(),
-                TimeUnit::Millisecond => |x| timestamp_ms_to_datetime(x).year(),
-                TimeUnit::Microsecond => |x| timestamp_us_to_datetime(x).year(),
-                TimeUnit::Nanosecond => |x| timestamp_ns_to_datetime(x).year(),
-            };
-            Ok(unary(array, op, final_data_type))
+        DataType::Time64(time_unit @ (TimeUnit::Microsecond | TimeUnit::Nanosecond))
+        | DataType::Timestamp(time_unit, _) => {
+            let scale = seconds(time_unit);
+            Ok(unary(
+                as_primitive::<i64>(array),
+                |x| x as f64 * scale,
+                data_type,
+            ))
         }
         dt => Err(ArrowError::NotYetImplemented(format!(
-            "\"year\" does not support type {:?}",
+            "\"epoch\" does not support type {:?}",
             dt
         ))),
     }
 }
 
-/// Checks if an array of type `datatype` can perform year operation
+fn localize<T: TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: &TimeUnit,
+    timezone: &T,
+) -> PrimitiveArray<i64> {
+    let iter = array.iter().map(|x| {
+        x.and_then(|x| {
+            let local = timestamp_to_naive_datetime(*x, time_unit);
+            let datetime = timezone.from_local_datetime(&local).earliest()?;
+            naive_datetime_to_timestamp(&datetime.naive_utc(), time_unit)
+        })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter)
+}
+
+/// Converts the timestamps of `array` to the timezone `to_tz`.
 ///
-/// # Examples
+/// Timestamps with a timezone represent instants, so their values are kept and only their
+/// timezone changes. Timestamps without a timezone are interpreted as local times in `to_tz`
+/// and converted to instants: local times that occur twice (when clocks are set back) resolve
+/// to the earliest instant, and local times that do not exist (when clocks are set forward)
+/// are null.
+/// # Example
 /// ```
-/// use arrow2::compute::temporal::can_year;
-/// use arrow2::datatypes::{DataType};
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::{convert_timezone, hour};
+/// use arrow2::datatypes::{DataType, TimeUnit};
 ///
-/// let data_type = DataType::Date32;
-/// assert_eq!(can_year(&data_type), true);
+/// let array = Int64Array::from(&[Some(7200)]).to(DataType::Timestamp(TimeUnit::Second, None));
+/// let result = convert_timezone(&array, "+02:00").unwrap();
+/// assert_eq!(result.value(0), 0);
+/// assert_eq!(hour(&result).unwrap().value(0), 2);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a `Timestamp` array or `to_tz` is not a valid
+/// timezone.
+pub fn convert_timezone(array: &PrimitiveArray<i64>, to_tz: &str) -> Result<PrimitiveArray<i64>> {
+    let (time_unit, from_tz) = match array.data_type() {
+        DataType::Timestamp(time_unit, timezone) => (time_unit, timezone),
+        dt => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "\"convert_timezone\" does not support type {:?}",
+                dt
+            )))
+        }
+    };
+    let data_type = DataType::Timestamp(time_unit.clone(), Some(to_tz.to_string()));
+    let result = match from_tz {
+        Some(_) => with_timezone!(to_tz, |_tz| array.clone()),
+        None => with_timezone!(to_tz, |tz| localize(array, time_unit, &tz)),
+    };
+    Ok(result.to(data_type))
+}
 
-/// let data_type = DataType::Int8;
-/// assert_eq!(can_year(&data_type), false);
+/// Applies `op` to the (local) datetime of every slot of a `Date32`, `Date64` or `Timestamp`
+/// array, returning an array of the same type. Slots for which `op` returns `None`, or whose
+/// result does not exist in the timezone of the array, are null.
+fn map_datetime<F>(name: &str, array: &dyn Array, op: F) -> Result<Box<dyn Array>>
+where
+    F: Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    let data_type = array.data_type().clone();
+    match array.data_type() {
+        DataType::Date32 => {
+            let iter = as_primitive::<i32>(array).iter().map(|x| {
+                let datetime = op(date32_to_datetime(*x?))?;
+                Some(datetime.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+            });
+            Ok(Box::new(
+                PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+            ))
+        }
+        DataType::Date64 => {
+            let iter = as_primitive::<i64>(array).iter().map(|x| {
+                let datetime = op(date64_to_datetime(*x?))?;
+                naive_datetime_to_timestamp(&datetime, &TimeUnit::Millisecond)
+            });
+            Ok(Box::new(
+                PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+            ))
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let iter = as_primitive::<i64>(array).iter().map(|x| {
+                let datetime = op(timestamp_to_naive_datetime(*x?, time_unit))?;
+                naive_datetime_to_timestamp(&datetime, time_unit)
+            });
+            Ok(Box::new(
+                PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+            ))
+        }
+        DataType::Timestamp(time_unit, Some(timezone)) => {
+            let array = as_primitive::<i64>(array);
+            with_timezone!(timezone, |tz| {
+                let iter = array.iter().map(|x| {
+                    let local = timestamp_to_datetime(*x?, time_unit, &tz).naive_local();
+                    let datetime = tz.from_local_datetime(&op(local)?).earliest()?;
+                    naive_datetime_to_timestamp(&datetime.naive_utc(), time_unit)
+                });
+                Ok(Box::new(
+                    PrimitiveArray::from_trusted_len_iter(iter).to(data_type),
+                ))
+            })
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"{}\" does not support type {:?}",
+            name, dt
+        ))),
+    }
+}
+
+/// The unit to which [`date_trunc`] truncates datetimes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncUnit {
+    /// The first day of the year
+    Year,
+    /// The first day of the quarter
+    Quarter,
+    /// The first day of the month
+    Month,
+    /// The Monday of the (ISO) week
+    Week,
+    /// Midnight
+    Day,
+    /// The start of the hour
+    Hour,
+    /// The start of the minute
+    Minute,
+    /// The start of the second
+    Second,
+}
+
+fn truncate(datetime: NaiveDateTime, unit: TruncUnit) -> Option<NaiveDateTime> {
+    let date = datetime.date();
+    let date = match unit {
+        TruncUnit::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1)?,
+        TruncUnit::Quarter => {
+            NaiveDate::from_ymd_opt(date.year(), (date.month() - 1) / 3 * 3 + 1, 1)?
+        }
+        TruncUnit::Month => date.with_day(1)?,
+        TruncUnit::Week => {
+            date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+        }
+        _ => date,
+    };
+    match unit {
+        TruncUnit::Hour => date.and_hms_opt(datetime.hour(), 0, 0),
+        TruncUnit::Minute => date.and_hms_opt(datetime.hour(), datetime.minute(), 0),
+        TruncUnit::Second => datetime.with_nanosecond(0),
+        _ => date.and_hms_opt(0, 0, 0),
+    }
+}
+
+/// Truncates every datetime of a `Date32`, `Date64` or `Timestamp` array to `unit`,
+/// returning an array of the same type.
+///
+/// Timestamps with a timezone are truncated in their local time. Truncated local times
+/// that occur twice resolve to the earliest instant, and the ones that do not exist are null.
+/// # Example
 /// ```
-pub fn can_year(data_type: &DataType) -> bool {
-    matches!(
-        data_type,
-        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, None)
-    )
+/// use arrow2::array::{Array, Int64Array};
+/// use arrow2::compute::temporal::{date_trunc, TruncUnit};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// // 2021-05-20T10:30:00
+/// let data_type = DataType::Timestamp(TimeUnit::Second, None);
+/// let array = Int64Array::from(&[Some(1621506600), None]).to(data_type.clone());
+///
+/// let result = date_trunc(&array, TruncUnit::Month).unwrap();
+/// // 2021-05-01T00:00:00
+/// let expected = Int64Array::from(&[Some(1619827200), None]).to(data_type);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff the type of `array` is not supported or its timezone is invalid.
+pub fn date_trunc(array: &dyn Array, unit: TruncUnit) -> Result<Box<dyn Array>> {
+    map_datetime("date_trunc", array, |x| truncate(x, unit))
+}
+
+/// Returns the number of nanoseconds of `duration`, as an `i128` to not overflow.
+#[inline]
+fn duration_nanoseconds(duration: chrono::Duration) -> i128 {
+    duration.num_seconds() as i128 * NANOSECONDS as i128 + duration.subsec_nanos() as i128
+}
+
+/// Bins every datetime of a `Date32`, `Date64` or `Timestamp` array into intervals of length
+/// `interval` aligned with `origin`, returning the start of each bin in an array of the same
+/// type. The start of each bin is `origin + n * interval` for the largest integer `n` such that
+/// it is not after the datetime.
+///
+/// Timestamps with a timezone, and `origin`, are binned in their local time, so that e.g.
+/// daily bins start at local midnight. Bins that start at local times that occur twice resolve
+/// to the earliest instant, and the ones that do not exist are null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int64Array};
+/// use arrow2::compute::temporal::date_bin;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+/// use chrono::{Duration, NaiveDate};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, None);
+/// let array = Int64Array::from(&[Some(0), Some(899), Some(900), Some(-1)]).to(data_type.clone());
+///
+/// let origin = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let result = date_bin(&array, Duration::minutes(15), origin).unwrap();
+/// let expected = Int64Array::from(&[Some(0), Some(0), Some(900), Some(-900)]).to(data_type);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff `interval` is not positive, or the type of `array` is not
+/// supported or its timezone is invalid.
+pub fn date_bin(
+    array: &dyn Array,
+    interval: chrono::Duration,
+    origin: NaiveDateTime,
+) -> Result<Box<dyn Array>> {
+    let stride = duration_nanoseconds(interval);
+    if stride <= 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "\"date_bin\" requires a positive interval".to_string(),
+        ));
+    }
+    map_datetime("date_bin", array, |x| {
+        let delta = duration_nanoseconds(x.signed_duration_since(origin));
+        let delta = delta.div_euclid(stride) * stride;
+        let seconds = delta.div_euclid(NANOSECONDS as i128);
+        let nanoseconds = delta.rem_euclid(NANOSECONDS as i128);
+        origin
+            .checked_add_signed(chrono::Duration::try_seconds(i64::try_from(seconds).ok()?)?)?
+            .checked_add_signed(chrono::Duration::nanoseconds(nanoseconds as i64))
+    })
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +611,258 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn time32_milli_minute_second() {
+        let array =
+            Int32Array::from(&[Some(37_845_123), None]).to(DataType::Time32(TimeUnit::Millisecond));
+
+        assert_eq!(hour(&array).unwrap(), UInt32Array::from(&[Some(10), None]));
+        assert_eq!(
+            minute(&array).unwrap(),
+            UInt32Array::from(&[Some(30), None])
+        );
+        assert_eq!(
+            second(&array).unwrap(),
+            UInt32Array::from(&[Some(45), None])
+        );
+        assert_eq!(
+            nanosecond(&array).unwrap(),
+            UInt32Array::from(&[Some(123_000_000), None])
+        );
+    }
+
+    #[test]
+    fn timestamp_date_components() {
+        // 2021-01-01T12:00:00 (a Friday) and 2020-12-31T00:00:00 (a Thursday)
+        let array = Int64Array::from(&[Some(1609502400), Some(1609372800), None])
+            .to(DataType::Timestamp(TimeUnit::Second, None));
+
+        assert_eq!(
+            month(&array).unwrap(),
+            UInt32Array::from(&[Some(1), Some(12), None])
+        );
+        assert_eq!(
+            day(&array).unwrap(),
+            UInt32Array::from(&[Some(1), Some(31), None])
+        );
+        assert_eq!(
+            day_of_week(&array).unwrap(),
+            UInt32Array::from(&[Some(5), Some(4), None])
+        );
+        assert_eq!(
+            day_of_year(&array).unwrap(),
+            UInt32Array::from(&[Some(1), Some(366), None])
+        );
+        assert_eq!(
+            iso_week(&array).unwrap(),
+            UInt32Array::from(&[Some(53), Some(53), None])
+        );
+        assert_eq!(
+            quarter(&array).unwrap(),
+            UInt32Array::from(&[Some(1), Some(4), None])
+        );
+    }
+
+    #[test]
+    fn timestamp_nano_before_epoch() {
+        // 1969-12-31T23:59:59.5
+        let array = Int64Array::from(&[Some(-500_000_000)])
+            .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+
+        assert_eq!(year(&array).unwrap(), Int32Array::from(&[Some(1969)]));
+        assert_eq!(second(&array).unwrap(), UInt32Array::from(&[Some(59)]));
+        assert_eq!(
+            nanosecond(&array).unwrap(),
+            UInt32Array::from(&[Some(500_000_000)])
+        );
+        assert_eq!(epoch(&array).unwrap(), Float64Array::from(&[Some(-0.5)]));
+    }
+
+    #[test]
+    fn timestamp_with_offset() {
+        // 2020-12-31T23:30:00 UTC
+        let values = Int64Array::from(&[Some(1609457400000)]);
+
+        let array = values.clone().to(DataType::Timestamp(
+            TimeUnit::Millisecond,
+            Some("+01:00".to_string()),
+        ));
+        assert_eq!(year(&array).unwrap(), Int32Array::from(&[Some(2021)]));
+        assert_eq!(hour(&array).unwrap(), UInt32Array::from(&[Some(0)]));
+        assert_eq!(
+            epoch(&array).unwrap(),
+            Float64Array::from(&[Some(1609457400.0)])
+        );
+
+        let array = values.clone().to(DataType::Timestamp(
+            TimeUnit::Millisecond,
+            Some("-0530".to_string()),
+        ));
+        assert_eq!(day(&array).unwrap(), UInt32Array::from(&[Some(31)]));
+        assert_eq!(hour(&array).unwrap(), UInt32Array::from(&[Some(18)]));
+        assert_eq!(minute(&array).unwrap(), UInt32Array::from(&[Some(0)]));
+
+        let array = values.clone().to(DataType::Timestamp(
+            TimeUnit::Millisecond,
+            Some("Mars/Olympus_Mons".to_string()),
+        ));
+        assert!(!can_year(array.data_type()));
+        assert!(hour(&array).is_err());
+    }
+
+    #[test]
+    fn convert_timezone_offset() {
+        let array = Int64Array::from(&[Some(0), None]).to(DataType::Timestamp(
+            TimeUnit::Second,
+            Some("UTC".to_string()),
+        ));
+        let result = convert_timezone(&array, "-05:00").unwrap();
+        assert_eq!(
+            result.data_type(),
+            &DataType::Timestamp(TimeUnit::Second, Some("-05:00".to_string()))
+        );
+        assert_eq!(result.values(), array.values());
+        assert_eq!(hour(&result).unwrap(), UInt32Array::from(&[Some(19), None]));
+
+        assert!(convert_timezone(&array, "Mars/Olympus_Mons").is_err());
+        assert!(convert_timezone(&Int64Array::from_slice(&[1]), "UTC").is_err());
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn timestamp_iana_timezone() {
+        // 2021-03-14T06:30:00 and 07:30:00 UTC, around the start of daylight saving time
+        let array = Int64Array::from(&[Some(1615703400000), Some(1615707000000), None]).to(
+            DataType::Timestamp(TimeUnit::Millisecond, Some("America/New_York".to_string())),
+        );
+        assert!(can_hour(array.data_type()));
+        assert_eq!(
+            hour(&array).unwrap(),
+            UInt32Array::from(&[Some(1), Some(3), None])
+        );
+        assert_eq!(
+            day(&array).unwrap(),
+            UInt32Array::from(&[Some(14), Some(14), None])
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn convert_timezone_localizes_naive() {
+        // 2021-03-14T02:30:00, that does not exist in New York, and 2021-11-07T01:30:00,
+        // that occurs twice
+        let array = Int64Array::from(&[Some(1615689000), Some(1636248600), Some(0)])
+            .to(DataType::Timestamp(TimeUnit::Second, None));
+        let result = convert_timezone(&array, "America/New_York").unwrap();
+        let expected = Int64Array::from(&[None, Some(1636263000), Some(18000)]).to(
+            DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string())),
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn date_trunc_timestamp() {
+        // 2021-05-20T10:30:45.123, a Thursday
+        let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+        let array = Int64Array::from(&[Some(1621506645123), None]).to(data_type.clone());
+
+        let cases = [
+            (TruncUnit::Year, 1609459200000),
+            (TruncUnit::Quarter, 1617235200000),
+            (TruncUnit::Month, 1619827200000),
+            (TruncUnit::Week, 1621209600000),
+            (TruncUnit::Day, 1621468800000),
+            (TruncUnit::Hour, 1621504800000),
+            (TruncUnit::Minute, 1621506600000),
+            (TruncUnit::Second, 1621506645000),
+        ];
+        for (unit, expected) in cases.iter() {
+            let result = date_trunc(&array, *unit).unwrap();
+            let expected = Int64Array::from(&[Some(*expected), None]).to(data_type.clone());
+            assert_eq!(result.as_ref(), &expected as &dyn Array, "{:?}", unit);
+        }
+    }
+
+    #[test]
+    fn date_trunc_date32() {
+        // 2021-05-20
+        let array = Int32Array::from(&[Some(18767), None]).to(DataType::Date32);
+
+        let result = date_trunc(&array, TruncUnit::Week).unwrap();
+        let expected = Int32Array::from(&[Some(18764), None]).to(DataType::Date32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let result = date_trunc(&array, TruncUnit::Hour).unwrap();
+        assert_eq!(result.as_ref(), &array as &dyn Array);
+
+        assert!(date_trunc(&Int32Array::from_slice(&[1]), TruncUnit::Day).is_err());
+    }
+
+    #[test]
+    fn date_trunc_and_bin_with_offset() {
+        // 2021-05-20T12:30:45.123+02:00
+        let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+02:00".to_string()));
+        let array = Int64Array::from(&[Some(1621506645123)]).to(data_type.clone());
+        // 2021-05-20T00:00:00+02:00
+        let expected = Int64Array::from(&[Some(1621461600000)]).to(data_type);
+
+        let result = date_trunc(&array, TruncUnit::Day).unwrap();
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        let origin = NaiveDate::from_ymd_opt(1970, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let result = date_bin(&array, chrono::Duration::days(1), origin).unwrap();
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn date_bin_date32() {
+        // 2021-05-20, 2021-05-24 and 2021-05-16
+        let array =
+            Int32Array::from(&[Some(18767), Some(18771), Some(18763), None]).to(DataType::Date32);
+        // a Monday, 2021-05-17
+        let origin = NaiveDate::from_ymd_opt(2021, 5, 17)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        let result = date_bin(&array, chrono::Duration::weeks(1), origin).unwrap();
+        let expected =
+            Int32Array::from(&[Some(18764), Some(18771), Some(18757), None]).to(DataType::Date32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+        assert!(date_bin(&array, chrono::Duration::zero(), origin).is_err());
+        assert!(date_bin(&array, chrono::Duration::days(-1), origin).is_err());
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn date_trunc_iana_timezone() {
+        // 2021-03-14T12:00:00Z, the day daylight saving time starts in New York
+        let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+        let array = Int64Array::from(&[Some(1615723200)]).to(data_type.clone());
+
+        let result = date_trunc(&array, TruncUnit::Day).unwrap();
+        // 2021-03-14T00:00:00-05:00
+        let expected = Int64Array::from(&[Some(1615698000)]).to(data_type);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn epoch_date_and_time() {
+        let array = Int32Array::from(&[Some(1), None]).to(DataType::Date32);
+        assert_eq!(
+            epoch(&array).unwrap(),
+            Float64Array::from(&[Some(86400.0), None])
+        );
+
+        let array =
+            Int64Array::from(&[Some(1_500_000)]).to(DataType::Time64(TimeUnit::Microsecond));
+        assert_eq!(epoch(&array).unwrap(), Float64Array::from(&[Some(1.5)]));
+    }
+
     #[test]
     fn consistency_hour() {
         use crate::array::new_null_array;
@@ -324,10 +904,19 @@ mod tests {
 
         datatypes.into_iter().for_each(|d1| {
             let array = new_null_array(d1.clone(), 10);
+            let array = array.as_ref();
             if can_hour(&d1) {
-                assert!(hour(array.as_ref()).is_ok());
+                assert!(hour(array).is_ok());
+                assert!(minute(array).is_ok());
+                assert!(second(array).is_ok());
+                assert!(nanosecond(array).is_ok());
+                assert!(epoch(array).is_ok());
             } else {
-                assert!(hour(array.as_ref()).is_err());
+                assert!(hour(array).is_err());
+                assert!(minute(array).is_err());
+                assert!(second(array).is_err());
+                assert!(nanosecond(array).is_err());
+                assert!(epoch(array).is_err());
             }
         });
     }
@@ -373,10 +962,14 @@ mod tests {
 
         datatypes.into_iter().for_each(|d1| {
             let array = new_null_array(d1.clone(), 10);
+            let array = array.as_ref();
+            let kernels = [month, day, day_of_week, day_of_year, iso_week, quarter];
             if can_year(&d1) {
-                assert!(year(array.as_ref()).is_ok());
+                assert!(year(array).is_ok());
+                assert!(kernels.iter().all(|kernel| kernel(array).is_ok()));
             } else {
-                assert!(year(array.as_ref()).is_err());
+                assert!(year(array).is_err());
+                assert!(kernels.iter().all(|kernel| kernel(array).is_err()));
             }
         });
     }
@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::{
-    array::{Array, BooleanArray, Offset, Utf8Array},
+    array::{growable::make_growable, Array, BooleanArray, Offset, Utf8Array},
     bitmap::Bitmap,
 };
 
@@ -44,3 +44,50 @@ pub fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
     let values = Bitmap::from_trusted_len_iter(iterator);
     BooleanArray::from_data(values, validity)
 }
+
+/// Builds a new array out of `arrays`, where every slot is either taken from the slot
+/// `(array, row)` of `arrays` or null. Consecutive slots of the same array are extended at once.
+pub fn build_from_slots<I: Iterator<Item = Option<(usize, usize)>>>(
+    arrays: &[&dyn Array],
+    use_validity: bool,
+    capacity: usize,
+    sources: I,
+) -> Box<dyn Array> {
+    let mut growable = make_growable(arrays, use_validity, capacity);
+
+    // the current run of slots: either `(array, start, len)` or `len` nulls
+    let mut run: Option<(usize, usize, usize)> = None;
+    let mut nulls = 0;
+    for source in sources {
+        match (source, run.as_mut()) {
+            (Some((index, row)), Some((run_index, start, len)))
+                if *run_index == index && *start + *len == row =>
+            {
+                *len += 1
+            }
+            (Some((index, row)), _) => {
+                if let Some((index, start, len)) = run.take() {
+                    growable.extend(index, start, len)
+                }
+                if nulls > 0 {
+                    growable.extend_validity(nulls);
+                    nulls = 0;
+                }
+                run = Some((index, row, 1));
+            }
+            (None, _) => {
+                if let Some((index, start, len)) = run.take() {
+                    growable.extend(index, start, len)
+                }
+                nulls += 1;
+            }
+        }
+    }
+    if let Some((index, start, len)) = run {
+        growable.extend(index, start, len)
+    }
+    if nulls > 0 {
+        growable.extend_validity(nulls);
+    }
+    growable.as_box()
+}
@@ -0,0 +1,157 @@
+//! Cumulative aggregations over [`PrimitiveArray`]s.
+use crate::{
+    array::{Array, PrimitiveArray},
+    buffer::Buffer,
+    datatypes::DataType,
+    types::NativeType,
+};
+
+use super::WindowNumeric;
+
+/// Applies `op` cumulatively over the non-null values of `array`.
+/// Null slots remain null and do not affect the accumulated value.
+fn cumulative<T, F>(array: &PrimitiveArray<T>, initial: T, mut op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: FnMut(T, T) -> T,
+{
+    let mut acc = initial;
+    let values = array.iter().map(|x| {
+        if let Some(x) = x {
+            acc = op(acc, *x);
+        }
+        acc
+    });
+    let values = Buffer::from_trusted_len_iter(values);
+
+    PrimitiveArray::<T>::from_data(array.data_type().clone(), values, array.validity().clone())
+}
+
+/// Returns the cumulative sum of `array`. Nulls are preserved and skipped.
+/// Integers wrap around on overflow.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::cumsum;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let result = cumsum(&array);
+/// assert_eq!(result, Int32Array::from(&[Some(1), None, Some(4)]));
+/// ```
+pub fn cumsum<T: WindowNumeric>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, T::zero(), |acc, x| acc.window_add(x))
+}
+
+/// Returns the cumulative product of `array`. Nulls are preserved and skipped.
+/// Integers wrap around on overflow.
+pub fn cumprod<T: WindowNumeric>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, T::one(), |acc, x| acc.window_mul(x))
+}
+
+/// Returns the cumulative minimum of `array`. Nulls are preserved and skipped.
+pub fn cummin<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    let mut first = true;
+    cumulative(array, T::default(), |acc, x| {
+        if first || x < acc {
+            first = false;
+            x
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the cumulative maximum of `array`. Nulls are preserved and skipped.
+pub fn cummax<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    let mut first = true;
+    cumulative(array, T::default(), |acc, x| {
+        if first || x > acc {
+            first = false;
+            x
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the cumulative number of non-null values of `array`. The result has no nulls.
+pub fn cumcount(array: &dyn Array) -> PrimitiveArray<u64> {
+    let values = match array.validity() {
+        Some(validity) => {
+            let mut acc = 0;
+            validity
+                .iter()
+                .map(|is_valid| {
+                    acc += is_valid as u64;
+                    acc
+                })
+                .collect()
+        }
+        None => (1..=array.len() as u64).collect(),
+    };
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    #[test]
+    fn sum() {
+        let array = Int64Array::from(&[None, Some(1), Some(2), None, Some(3)]);
+        let result = cumsum(&array);
+        let expected = Int64Array::from(&[None, Some(1), Some(3), None, Some(6)]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn prod() {
+        let array = Float64Array::from(&[Some(2.0), None, Some(0.5), Some(3.0)]);
+        let result = cumprod(&array);
+        let expected = Float64Array::from(&[Some(2.0), None, Some(1.0), Some(3.0)]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn overflow() {
+        let array = Int8Array::from_slice(&[100, 100, -100]);
+        assert_eq!(cumsum(&array), Int8Array::from_slice(&[100, -56, 100]));
+        let array = UInt8Array::from_slice(&[16, 16, 2]);
+        assert_eq!(cumprod(&array), UInt8Array::from_slice(&[16, 0, 0]));
+    }
+
+    #[test]
+    fn min_max() {
+        let array = Int32Array::from(&[None, Some(3), Some(5), Some(1), None, Some(2)]);
+        assert_eq!(
+            cummin(&array),
+            Int32Array::from(&[None, Some(3), Some(3), Some(1), None, Some(1)])
+        );
+        assert_eq!(
+            cummax(&array),
+            Int32Array::from(&[None, Some(3), Some(5), Some(5), None, Some(5)])
+        );
+    }
+
+    #[test]
+    fn count() {
+        let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("b")]);
+        assert_eq!(cumcount(&array), UInt64Array::from_slice(&[1, 1, 2]));
+
+        let array = Int32Array::from_slice(&[1, 2, 3]);
+        assert_eq!(cumcount(&array), UInt64Array::from_slice(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn keeps_data_type() {
+        let array = Int32Array::from_slice(&[1, 2]).to(DataType::Date32);
+        assert_eq!(cummax(&array).data_type(), &DataType::Date32);
+    }
+}
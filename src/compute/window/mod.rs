@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing, cumulative and rolling aggregations
+//! and ranking of sorted partitions.
 
 use crate::compute::concat;
 use num::{abs, clamp};
@@ -23,8 +24,89 @@ use num::{abs, clamp};
 use crate::{
     array::{new_null_array, Array},
     error::{ArrowError, Result},
+    types::NativeType,
 };
 
+mod cumulative;
+pub use cumulative::*;
+
+mod rolling;
+pub use rolling::*;
+
+mod rank;
+pub use rank::*;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A numeric type of running sums and products, such as [`cumsum`] and [`rolling_sum`].
+/// Integers wrap around on overflow, like [`i32::wrapping_add`], and floats follow IEEE 754.
+pub trait WindowNumeric: NativeType + num::Zero + num::One + private::Sealed {
+    /// `self + rhs`, wrapping around on overflow.
+    fn window_add(self, rhs: Self) -> Self;
+    /// `self - rhs`, wrapping around on overflow.
+    fn window_sub(self, rhs: Self) -> Self;
+    /// `self * rhs`, wrapping around on overflow.
+    fn window_mul(self, rhs: Self) -> Self;
+    /// Whether `self` is neither infinite nor NaN. Always true for integers.
+    fn is_finite_value(self) -> bool;
+}
+
+macro_rules! window_integer {
+    ($($ty:ty),*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl WindowNumeric for $ty {
+            #[inline]
+            fn window_add(self, rhs: Self) -> Self {
+                self.wrapping_add(rhs)
+            }
+            #[inline]
+            fn window_sub(self, rhs: Self) -> Self {
+                self.wrapping_sub(rhs)
+            }
+            #[inline]
+            fn window_mul(self, rhs: Self) -> Self {
+                self.wrapping_mul(rhs)
+            }
+            #[inline]
+            fn is_finite_value(self) -> bool {
+                true
+            }
+        }
+    )*};
+}
+
+window_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+macro_rules! window_float {
+    ($($ty:ty),*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl WindowNumeric for $ty {
+            #[inline]
+            fn window_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+            #[inline]
+            fn window_sub(self, rhs: Self) -> Self {
+                self - rhs
+            }
+            #[inline]
+            fn window_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+            #[inline]
+            fn is_finite_value(self) -> bool {
+                self.is_finite()
+            }
+        }
+    )*};
+}
+
+window_float!(f32, f64);
+
 /// Shifts array by defined number of items (to left or right)
 /// A positive value for `offset` shifts the array to the right
 /// a negative value shifts the array to the left.
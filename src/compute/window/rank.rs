@@ -0,0 +1,224 @@
+//! Ranking functions over sorted partitions, such as the ones returned by
+//! [`crate::compute::partition::lexicographical_partition_ranges`].
+use std::ops::Range;
+
+use crate::{
+    array::PrimitiveArray,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+/// Checks that `ranges` are consecutive and start at 0, returning their total length.
+fn check_ranges(ranges: &[Range<usize>], name: &str) -> Result<usize> {
+    let mut end = 0;
+    for range in ranges {
+        if range.start != end || range.end <= range.start {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The {} must be non-empty, consecutive ranges starting at 0",
+                name
+            )));
+        }
+        end = range.end;
+    }
+    Ok(end)
+}
+
+/// Calls `op(partition, peer_index, peer)` for every group of peers `peer`, where `partition`
+/// is the partition containing it and `peer_index` its position within the partition.
+fn for_each_peer<F: FnMut(&Range<usize>, usize, &Range<usize>)>(
+    partitions: &[Range<usize>],
+    peers: &[Range<usize>],
+    mut op: F,
+) -> Result<()> {
+    let length = check_ranges(partitions, "partitions")?;
+    if check_ranges(peers, "peers")? != length {
+        return Err(ArrowError::InvalidArgumentError(
+            "The partitions and the peers must cover the same number of rows".to_string(),
+        ));
+    }
+
+    let mut partitions = partitions.iter();
+    let mut partition = match partitions.next() {
+        Some(partition) => partition,
+        None => return Ok(()),
+    };
+    let mut peer_index = 0;
+    for peer in peers {
+        if peer.start == partition.end {
+            partition = partitions.next().unwrap();
+            peer_index = 0;
+        }
+        if peer.end > partition.end {
+            return Err(ArrowError::InvalidArgumentError(
+                "Every group of peers must be contained in a single partition".to_string(),
+            ));
+        }
+        op(partition, peer_index, peer);
+        peer_index += 1;
+    }
+    Ok(())
+}
+
+/// Returns the 1-based position of each row within its partition.
+/// `partitions` must be consecutive ranges starting at 0, such as the ones returned by
+/// [`crate::compute::partition::lexicographical_partition_ranges`].
+/// # Example
+/// ```
+/// use arrow2::array::UInt64Array;
+/// use arrow2::compute::window::row_number;
+///
+/// let result = row_number(&[0..2, 2..5]).unwrap();
+/// assert_eq!(result, UInt64Array::from_slice(&[1, 2, 1, 2, 3]));
+/// ```
+/// # Errors
+/// This function errors iff `partitions` are not consecutive ranges starting at 0.
+pub fn row_number(partitions: &[Range<usize>]) -> Result<PrimitiveArray<u64>> {
+    check_ranges(partitions, "partitions")?;
+    let values = partitions
+        .iter()
+        .flat_map(|partition| 1..=partition.len() as u64)
+        .collect();
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values,
+        None,
+    ))
+}
+
+/// Returns the rank of each row within its partition, with gaps: peers (rows that compare
+/// equal) have the same rank, equal to the 1-based position of the first of them.
+///
+/// `partitions` are the ranges of rows sharing the same partition key and `peers` the ranges
+/// of rows sharing the same partition and order keys, e.g. the results of
+/// [`crate::compute::partition::lexicographical_partition_ranges`] over the partition
+/// columns and over the partition and order columns respectively.
+/// # Example
+/// ```
+/// use arrow2::array::UInt64Array;
+/// use arrow2::compute::window::rank;
+///
+/// let result = rank(&[0..4, 4..5], &[0..1, 1..3, 3..4, 4..5]).unwrap();
+/// assert_eq!(result, UInt64Array::from_slice(&[1, 2, 2, 4, 1]));
+/// ```
+/// # Errors
+/// This function errors iff the ranges are not consecutive ranges starting at 0 or if
+/// `peers` are not a refinement of `partitions`.
+pub fn rank(partitions: &[Range<usize>], peers: &[Range<usize>]) -> Result<PrimitiveArray<u64>> {
+    let mut values = Vec::with_capacity(peers.last().map(|x| x.end).unwrap_or(0));
+    for_each_peer(partitions, peers, |partition, _, peer| {
+        let rank = (peer.start - partition.start + 1) as u64;
+        values.resize(values.len() + peer.len(), rank)
+    })?;
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values.into(),
+        None,
+    ))
+}
+
+/// Returns the rank of each row within its partition, without gaps: peers have the same
+/// rank, equal to the 1-based position of their group of peers.
+/// See [`rank`] for the meaning of `partitions` and `peers`.
+/// # Errors
+/// This function errors iff the ranges are not consecutive ranges starting at 0 or if
+/// `peers` are not a refinement of `partitions`.
+pub fn dense_rank(
+    partitions: &[Range<usize>],
+    peers: &[Range<usize>],
+) -> Result<PrimitiveArray<u64>> {
+    let mut values = Vec::with_capacity(peers.last().map(|x| x.end).unwrap_or(0));
+    for_each_peer(partitions, peers, |_, peer_index, peer| {
+        values.resize(values.len() + peer.len(), peer_index as u64 + 1)
+    })?;
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values.into(),
+        None,
+    ))
+}
+
+/// Returns the relative rank of each row within its partition, `(rank - 1) / (rows - 1)`,
+/// where `rows` is the number of rows in the partition (0 for single-row partitions).
+/// See [`rank`] for the meaning of `partitions` and `peers`.
+/// # Errors
+/// This function errors iff the ranges are not consecutive ranges starting at 0 or if
+/// `peers` are not a refinement of `partitions`.
+pub fn percent_rank(
+    partitions: &[Range<usize>],
+    peers: &[Range<usize>],
+) -> Result<PrimitiveArray<f64>> {
+    let mut values = Vec::with_capacity(peers.last().map(|x| x.end).unwrap_or(0));
+    for_each_peer(partitions, peers, |partition, _, peer| {
+        let value = if partition.len() == 1 {
+            0.0
+        } else {
+            (peer.start - partition.start) as f64 / (partition.len() - 1) as f64
+        };
+        values.resize(values.len() + peer.len(), value)
+    })?;
+    Ok(PrimitiveArray::<f64>::from_data(
+        DataType::Float64,
+        values.into(),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::compute::partition::lexicographical_partition_ranges;
+    use crate::compute::sort::SortColumn;
+
+    #[test]
+    fn from_partition_ranges() -> Result<()> {
+        // sorted by (a, b)
+        let a = Utf8Array::<i32>::from_slice(&["x", "x", "x", "x", "y", "y"]);
+        let b = Int32Array::from(&[None, Some(1), Some(1), Some(2), Some(5), Some(5)]);
+        let column = |values| SortColumn {
+            values,
+            options: None,
+        };
+        let partitions = lexicographical_partition_ranges(&[column(&a)])?.collect::<Vec<_>>();
+        let peers =
+            lexicographical_partition_ranges(&[column(&a), column(&b)])?.collect::<Vec<_>>();
+
+        assert_eq!(
+            row_number(&partitions)?,
+            UInt64Array::from_slice(&[1, 2, 3, 4, 1, 2])
+        );
+        assert_eq!(
+            rank(&partitions, &peers)?,
+            UInt64Array::from_slice(&[1, 2, 2, 4, 1, 1])
+        );
+        assert_eq!(
+            dense_rank(&partitions, &peers)?,
+            UInt64Array::from_slice(&[1, 2, 2, 3, 1, 1])
+        );
+        assert_eq!(
+            percent_rank(&partitions, &peers)?,
+            Float64Array::from_slice(&[0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0, 0.0, 0.0])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn single_row_partition() -> Result<()> {
+        let result = percent_rank(&[0..1], &[0..1])?;
+        assert_eq!(result, Float64Array::from_slice(&[0.0]));
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> Result<()> {
+        assert_eq!(rank(&[], &[])?.len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(row_number(&[1..2]).is_err());
+        assert!(rank(&[0..2], &[0..3]).is_err());
+        assert!(rank(&[0..2, 2..4], &[0..1, 1..3, 3..4]).is_err());
+    }
+}
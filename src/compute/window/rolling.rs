@@ -0,0 +1,453 @@
+//! Fixed-size rolling (trailing) window aggregations over [`PrimitiveArray`]s.
+use std::collections::VecDeque;
+
+use num::ToPrimitive;
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    bitmap::MutableBitmap,
+    buffer::MutableBuffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+use super::WindowNumeric;
+
+fn check_window(window: usize, min_periods: usize) -> Result<()> {
+    if window == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Rolling windows must have a positive size".to_string(),
+        ));
+    }
+    if min_periods > window {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The minimum number of periods ({}) must be smaller or equal to the window size ({})",
+            min_periods, window
+        )));
+    }
+    Ok(())
+}
+
+/// A running state over the non-null values of a trailing window.
+trait RollingState<T, O> {
+    fn push(&mut self, index: usize, value: T);
+    fn pop(&mut self, index: usize, value: T);
+    /// the current result, given the number of non-null values in the window.
+    fn get(&self, count: usize) -> Option<O>;
+}
+
+/// Drives `state` over each trailing window of size `window` of `array`. A slot is null
+/// whenever its window has fewer than `min_periods` non-null values (or `get` returns `None`).
+fn rolling<T, O, S>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+    mut state: S,
+    data_type: DataType,
+) -> Result<PrimitiveArray<O>>
+where
+    T: NativeType,
+    O: NativeType,
+    S: RollingState<T, O>,
+{
+    check_window(window, min_periods)?;
+
+    let mut values = MutableBuffer::<O>::with_capacity(array.len());
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    let mut count = 0;
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            state.push(i, array.value(i));
+            count += 1;
+        }
+        if i >= window {
+            let leaving = i - window;
+            if array.is_valid(leaving) {
+                state.pop(leaving, array.value(leaving));
+                count -= 1;
+            }
+        }
+        match (count >= min_periods.max(1))
+            .then(|| state.get(count))
+            .flatten()
+        {
+            Some(value) => {
+                values.push(value);
+                validity.push(true);
+            }
+            None => {
+                values.push(O::default());
+                validity.push(false);
+            }
+        }
+    }
+    Ok(PrimitiveArray::<O>::from_data(
+        data_type,
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Running sum of the finite values of the window. Infinite and NaN values are kept apart,
+/// in order, so that they do not affect the sum once they leave the window
+/// (`inf - inf` is NaN).
+struct SumState<T> {
+    sum: T,
+    non_finite: VecDeque<T>,
+}
+
+impl<T: WindowNumeric> RollingState<T, T> for SumState<T> {
+    fn push(&mut self, _: usize, value: T) {
+        if value.is_finite_value() {
+            self.sum = self.sum.window_add(value);
+        } else {
+            self.non_finite.push_back(value);
+        }
+    }
+
+    fn pop(&mut self, _: usize, value: T) {
+        if value.is_finite_value() {
+            self.sum = self.sum.window_sub(value);
+        } else {
+            self.non_finite.pop_front();
+        }
+    }
+
+    fn get(&self, _: usize) -> Option<T> {
+        Some(
+            self.non_finite
+                .iter()
+                .fold(self.sum, |acc, x| acc.window_add(*x)),
+        )
+    }
+}
+
+/// Running mean and sum of squared deviations (Welford's algorithm) of the finite values of
+/// the window, used for the mean and the standard deviation. Infinite and NaN values are kept
+/// apart, as in [`SumState`].
+struct MomentsState {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    non_finite: VecDeque<f64>,
+    sample_std: bool,
+}
+
+impl MomentsState {
+    fn new(sample_std: bool) -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            non_finite: VecDeque::new(),
+            sample_std,
+        }
+    }
+}
+
+impl<T: NativeType + ToPrimitive> RollingState<T, f64> for MomentsState {
+    fn push(&mut self, _: usize, value: T) {
+        let value = value.to_f64().unwrap();
+        if value.is_finite() {
+            self.count += 1;
+            let delta = value - self.mean;
+            self.mean += delta / self.count as f64;
+            self.m2 += delta * (value - self.mean);
+        } else {
+            self.non_finite.push_back(value);
+        }
+    }
+
+    fn pop(&mut self, _: usize, value: T) {
+        let value = value.to_f64().unwrap();
+        if !value.is_finite() {
+            self.non_finite.pop_front();
+        } else if self.count == 1 {
+            self.count = 0;
+            self.mean = 0.0;
+            self.m2 = 0.0;
+        } else {
+            self.count -= 1;
+            let delta = value - self.mean;
+            self.mean -= delta / self.count as f64;
+            self.m2 -= delta * (value - self.mean);
+        }
+    }
+
+    fn get(&self, count: usize) -> Option<f64> {
+        if !self.sample_std {
+            if self.non_finite.is_empty() {
+                return Some(self.mean);
+            }
+            let sum = self.mean * self.count as f64;
+            let sum = self.non_finite.iter().fold(sum, |acc, x| acc + x);
+            return Some(sum / count as f64);
+        }
+        if count < 2 {
+            return None;
+        }
+        if !self.non_finite.is_empty() {
+            return Some(f64::NAN);
+        }
+        // rounding may leave a tiny negative sum of squares
+        let variance = self.m2.max(0.0) / (count - 1) as f64;
+        Some(variance.sqrt())
+    }
+}
+
+/// Monotonic queue of `(index, value)` whose front is the extreme of the window.
+struct ExtremeState<T, F: Fn(&T, &T) -> bool> {
+    queue: VecDeque<(usize, T)>,
+    /// whether the first argument supersedes the second one
+    supersedes: F,
+}
+
+impl<T: NativeType, F: Fn(&T, &T) -> bool> RollingState<T, T> for ExtremeState<T, F> {
+    fn push(&mut self, index: usize, value: T) {
+        while let Some((_, back)) = self.queue.back() {
+            if (self.supersedes)(&value, back) {
+                self.queue.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.queue.push_back((index, value));
+    }
+
+    fn pop(&mut self, index: usize, _: T) {
+        if matches!(self.queue.front(), Some((front, _)) if *front == index) {
+            self.queue.pop_front();
+        }
+    }
+
+    fn get(&self, _: usize) -> Option<T> {
+        self.queue.front().map(|(_, value)| *value)
+    }
+}
+
+/// Returns the sum of each trailing window of size `window`, ignoring nulls.
+/// A slot is null when its window has fewer than `min_periods` non-null values.
+/// Integers wrap around on overflow.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::rolling_sum;
+///
+/// let array = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+/// let result = rolling_sum(&array, 2, 1).unwrap();
+/// assert_eq!(result, Int32Array::from(&[Some(1), Some(3), Some(2), Some(4)]));
+/// ```
+/// # Errors
+/// This function errors iff `window == 0` or `min_periods > window`.
+pub fn rolling_sum<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: WindowNumeric,
+{
+    let state = SumState {
+        sum: T::zero(),
+        non_finite: VecDeque::new(),
+    };
+    rolling(array, window, min_periods, state, array.data_type().clone())
+}
+
+/// Returns the mean of each trailing window of size `window`, ignoring nulls.
+/// A slot is null when its window has fewer than `min_periods` non-null values.
+/// # Errors
+/// This function errors iff `window == 0` or `min_periods > window`.
+pub fn rolling_mean<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + ToPrimitive,
+{
+    let state = MomentsState::new(false);
+    rolling(array, window, min_periods, state, DataType::Float64)
+}
+
+/// Returns the sample standard deviation of each trailing window of size `window`,
+/// ignoring nulls. A slot is null when its window has fewer than `min_periods` (or 2)
+/// non-null values.
+/// # Errors
+/// This function errors iff `window == 0` or `min_periods > window`.
+pub fn rolling_std<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + ToPrimitive,
+{
+    let state = MomentsState::new(true);
+    rolling(array, window, min_periods, state, DataType::Float64)
+}
+
+/// Returns the minimum of each trailing window of size `window`, ignoring nulls.
+/// A slot is null when its window has fewer than `min_periods` non-null values.
+/// # Errors
+/// This function errors iff `window == 0` or `min_periods > window`.
+pub fn rolling_min<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    let state = ExtremeState {
+        queue: VecDeque::with_capacity(window),
+        supersedes: |new: &T, old: &T| new <= old,
+    };
+    rolling(array, window, min_periods, state, array.data_type().clone())
+}
+
+/// Returns the maximum of each trailing window of size `window`, ignoring nulls.
+/// A slot is null when its window has fewer than `min_periods` non-null values.
+/// # Errors
+/// This function errors iff `window == 0` or `min_periods > window`.
+pub fn rolling_max<T>(
+    array: &PrimitiveArray<T>,
+    window: usize,
+    min_periods: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    let state = ExtremeState {
+        queue: VecDeque::with_capacity(window),
+        supersedes: |new: &T, old: &T| new >= old,
+    };
+    rolling(array, window, min_periods, state, array.data_type().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+
+    #[test]
+    fn sum() -> Result<()> {
+        let array = Int64Array::from(&[Some(1), Some(2), None, Some(4), Some(5)]);
+        let result = rolling_sum(&array, 3, 2)?;
+        let expected = Int64Array::from(&[None, Some(3), Some(3), Some(6), Some(9)]);
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn mean() -> Result<()> {
+        let array = Int32Array::from(&[Some(1), Some(3), None, Some(5)]);
+        let result = rolling_mean(&array, 2, 1)?;
+        let expected = Float64Array::from(&[Some(1.0), Some(2.0), Some(3.0), Some(5.0)]);
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn std() -> Result<()> {
+        let array = Float64Array::from_slice(&[2.0, 4.0, 4.0, 4.0, 5.0]);
+        let result = rolling_std(&array, 3, 0)?;
+        assert!(result.is_null(0));
+        assert!((result.value(1) - 2f64.sqrt()).abs() < 1e-12);
+        assert!((result.value(2) - (4.0f64 / 3.0).sqrt()).abs() < 1e-12);
+        assert!(result.value(3).abs() < 1e-12);
+        assert!((result.value(4) - (1.0f64 / 3.0).sqrt()).abs() < 1e-12);
+
+        // a large offset must not cancel the deviations
+        let array = Float64Array::from_slice(&[1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0, 1e9 + 4.0]);
+        let result = rolling_std(&array, 3, 0)?;
+        assert!((result.value(2) - 1.0).abs() < 1e-9);
+        assert!((result.value(3) - 1.0).abs() < 1e-9);
+        let result = rolling_mean(&array, 3, 0)?;
+        assert_eq!(result.value(3), 1e9 + 3.0);
+        Ok(())
+    }
+
+    #[test]
+    fn non_finite() -> Result<()> {
+        let array = Float64Array::from_slice(&[1.0, f64::INFINITY, 2.0, f64::NAN, 3.0, 4.0, 5.0]);
+
+        let result = rolling_sum(&array, 2, 1)?;
+        assert_eq!(result.value(1), f64::INFINITY);
+        assert_eq!(result.value(2), f64::INFINITY);
+        assert!(result.value(3).is_nan());
+        assert!(result.value(4).is_nan());
+        assert_eq!(result.values().as_slice()[5..], [7.0, 9.0]);
+
+        let result = rolling_mean(&array, 2, 1)?;
+        assert_eq!(result.value(2), f64::INFINITY);
+        assert!(result.value(4).is_nan());
+        assert_eq!(result.values().as_slice()[5..], [3.5, 4.5]);
+
+        let result = rolling_std(&array, 2, 1)?;
+        assert!(result.value(1).is_nan());
+        assert!(result.value(4).is_nan());
+        assert!((result.value(5) - 0.5f64.sqrt()).abs() < 1e-12);
+
+        let array = Float32Array::from_slice(&[f32::INFINITY, f32::NEG_INFINITY, 1.0, 2.0]);
+        let result = rolling_sum(&array, 2, 1)?;
+        assert!(result.value(1).is_nan());
+        assert_eq!(result.value(2), f32::NEG_INFINITY);
+        assert_eq!(result.value(3), 3.0);
+        Ok(())
+    }
+
+    #[test]
+    fn sum_overflow() -> Result<()> {
+        let array = Int8Array::from_slice(&[100, 100, -100, 10]);
+        let result = rolling_sum(&array, 2, 1)?;
+        assert_eq!(result, Int8Array::from_slice(&[100, -56, 0, -90]));
+        Ok(())
+    }
+
+    #[test]
+    fn min_max() -> Result<()> {
+        let array = Int32Array::from(&[Some(3), Some(1), None, Some(4), Some(1), Some(5), Some(9)]);
+        let result = rolling_min(&array, 3, 1)?;
+        let expected = Int32Array::from(&[
+            Some(3),
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+        ]);
+        assert_eq!(result, expected);
+
+        let result = rolling_max(&array, 3, 1)?;
+        let expected = Int32Array::from(&[
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(4),
+            Some(4),
+            Some(5),
+            Some(9),
+        ]);
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn all_nulls_window() -> Result<()> {
+        let array = Int32Array::from(&[Some(1), None, None, Some(2)]);
+        let result = rolling_max(&array, 2, 0)?;
+        let expected = Int32Array::from(&[Some(1), Some(1), None, Some(2)]);
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let array = Int32Array::from_slice(&[1, 2]);
+        assert!(rolling_sum(&array, 0, 0).is_err());
+        assert!(rolling_sum(&array, 2, 3).is_err());
+    }
+}
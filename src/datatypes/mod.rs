@@ -141,7 +141,7 @@ pub enum TimeUnit {
     Nanosecond,
 }
 
-/// YEAR_MONTH or DAY_TIME interval in SQL style.
+/// YEAR_MONTH, DAY_TIME or MONTH_DAY_NANO interval in SQL style.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum IntervalUnit {
     /// Indicates the number of elapsed whole months, stored as 4-byte integers.
@@ -149,6 +149,9 @@ pub enum IntervalUnit {
     /// Indicates the number of elapsed days and milliseconds,
     /// stored as 2 contiguous 32-bit integers (8-bytes in total).
     DayTime,
+    /// Indicates the number of elapsed months, days and nanoseconds,
+    /// stored as 2 contiguous 32-bit integers followed by a 64-bit integer (16-bytes in total).
+    MonthDayNano,
 }
 
 impl DataType {
@@ -20,7 +20,7 @@
 use super::ffi::ArrowArrayRef;
 use crate::array::{BooleanArray, FromFfi};
 use crate::error::{ArrowError, Result};
-use crate::types::days_ms;
+use crate::types::{days_ms, months_days_ns};
 use crate::{
     array::{Array, BinaryArray, ListArray, PrimitiveArray, StructArray, Utf8Array},
     datatypes::{DataType, IntervalUnit},
@@ -46,6 +46,9 @@ pub fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         DataType::Interval(IntervalUnit::DayTime) => {
             Box::new(PrimitiveArray::<days_ms>::try_from_ffi(array)?)
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            Box::new(PrimitiveArray::<months_days_ns>::try_from_ffi(array)?)
+        }
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
@@ -156,6 +159,16 @@ mod tests {
         test_round_trip(data)
     }
 
+    #[test]
+    fn test_interval_months_days_ns() -> Result<()> {
+        let data = MonthsDaysNsArray::from(&[
+            Some(crate::types::months_days_ns::new(1, -2, 3)),
+            None,
+            Some(crate::types::months_days_ns::new(0, 0, i64::MAX)),
+        ]);
+        test_round_trip(data)
+    }
+
     #[test]
     fn test_large_binary() -> Result<()> {
         let data =
@@ -206,6 +206,7 @@ fn to_field(schema: &Ffi_ArrowSchema) -> Result<Field> {
         "tDn" => DataType::Duration(TimeUnit::Nanosecond),
         "tiM" => DataType::Interval(IntervalUnit::YearMonth),
         "tiD" => DataType::Interval(IntervalUnit::DayTime),
+        "tin" => DataType::Interval(IntervalUnit::MonthDayNano),
         "+l" => {
             let child = schema.child(0);
             DataType::List(Box::new(to_field(child)?))
@@ -295,6 +296,7 @@ fn to_format(data_type: &DataType) -> Result<String> {
         DataType::Duration(TimeUnit::Nanosecond) => "tDn",
         DataType::Interval(IntervalUnit::YearMonth) => "tiM",
         DataType::Interval(IntervalUnit::DayTime) => "tiD",
+        DataType::Interval(IntervalUnit::MonthDayNano) => "tin",
         DataType::Timestamp(unit, tz) => {
             let unit = match unit {
                 TimeUnit::Second => "s",
@@ -35,7 +35,7 @@ use crate::{
     array::*,
     bitmap::Bitmap,
     buffer::MutableBuffer,
-    types::{days_ms, NativeType},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 use super::super::compression;
@@ -730,6 +730,16 @@ pub fn read<R: Read + Seek>(
             compression,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
+        DataType::Interval(IntervalUnit::MonthDayNano) => read_primitive::<months_days_ns, _>(
+            field_nodes,
+            data_type,
+            buffers,
+            reader,
+            block_offset,
+            is_little_endian,
+            compression,
+        )
+        .map(|x| Arc::new(x) as Arc<dyn Array>),
         DataType::UInt8 => read_primitive::<u8, _>(
             field_nodes,
             data_type,
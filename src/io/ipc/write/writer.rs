@@ -340,6 +340,20 @@ mod tests {
         test_round_trip(batch)
     }
 
+    #[test]
+    fn write_interval_months_days_ns() -> Result<()> {
+        use crate::array::{Array, MonthsDaysNsArray};
+        use crate::types::months_days_ns;
+        use std::sync::Arc;
+        let array = Arc::new(MonthsDaysNsArray::from(&[
+            Some(months_days_ns::new(1, -2, 3)),
+            None,
+            Some(months_days_ns::new(-1, 2, i64::MIN)),
+        ])) as Arc<dyn Array>;
+        let batch = RecordBatch::try_from_iter(vec![("a", array)]).unwrap();
+        test_round_trip(batch)
+    }
+
     #[test]
     fn write_sliced_list() -> Result<()> {
         use crate::array::{MutableListArray, MutablePrimitiveArray, TryExtend};
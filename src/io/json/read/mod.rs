@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-mod deserialize;
+pub(crate) mod deserialize;
 mod infer_schema;
 mod reader;
 mod util;
@@ -27,7 +27,7 @@ use crate::{
     datatypes::{DataType, Field, IntervalUnit, Schema},
     error::{ArrowError, Result},
     record_batch::RecordBatch,
-    types::{days_ms, NativeType},
+    types::{days_ms, months_days_ns, NativeType},
 };
 
 use super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
@@ -87,6 +87,44 @@ fn to_primitive_interval(
     PrimitiveArray::<days_ms>::from_data(data_type, values, validity)
 }
 
+fn to_months_days_ns(value: &Value) -> months_days_ns {
+    if let Value::Object(v) = value {
+        let months = v.get("months").unwrap();
+        let days = v.get("days").unwrap();
+        let nanoseconds = v.get("nanoseconds").unwrap();
+        let nanoseconds = match nanoseconds {
+            Value::String(s) => s.parse::<i64>().unwrap(),
+            Value::Number(n) => n.as_i64().unwrap(),
+            _ => panic!(),
+        };
+        match (months, days) {
+            (Value::Number(months), Value::Number(days)) => {
+                let months = months.as_i64().unwrap() as i32;
+                let days = days.as_i64().unwrap() as i32;
+                months_days_ns::new(months, days, nanoseconds)
+            }
+            (_, _) => panic!(),
+        }
+    } else {
+        panic!()
+    }
+}
+
+fn to_primitive_months_days_ns(
+    json_col: &ArrowJsonColumn,
+    data_type: DataType,
+) -> PrimitiveArray<months_days_ns> {
+    let validity = to_validity(&json_col.validity);
+    let values = json_col
+        .data
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(to_months_days_ns)
+        .collect();
+    PrimitiveArray::<months_days_ns>::from_data(data_type, values, validity)
+}
+
 fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<i128> {
     let validity = to_validity(&json_col.validity);
     let values = json_col
@@ -254,6 +292,9 @@ pub fn to_array(
         DataType::Interval(IntervalUnit::DayTime) => {
             Ok(Arc::new(to_primitive_interval(json_col, data_type.clone())))
         }
+        DataType::Interval(IntervalUnit::MonthDayNano) => Ok(Arc::new(
+            to_primitive_months_days_ns(json_col, data_type.clone()),
+        )),
         DataType::Decimal(_, _) => Ok(Arc::new(to_decimal(json_col, data_type.clone()))),
         DataType::UInt8 => Ok(Arc::new(to_primitive::<u8>(json_col, data_type.clone()))),
         DataType::UInt16 => Ok(Arc::new(to_primitive::<u16>(json_col, data_type.clone()))),
@@ -1,7 +1,9 @@
 //! Conversion methods for dates and times.
 
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
 use crate::datatypes::TimeUnit;
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::error::{ArrowError, Result};
 
 /// Number of seconds in a day
 pub const SECONDS_IN_DAY: i64 = 86_400;
@@ -33,9 +35,9 @@ pub fn date32_to_date(days: i32) -> NaiveDate {
 pub fn date64_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from milliseconds
-        v / MILLISECONDS,
+        v.div_euclid(MILLISECONDS),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
+        (v.rem_euclid(MILLISECONDS) * MICROSECONDS) as u32,
     )
 }
 
@@ -98,9 +100,9 @@ pub fn timestamp_s_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from milliseconds
-        v / MILLISECONDS,
+        v.div_euclid(MILLISECONDS),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
+        (v.rem_euclid(MILLISECONDS) * MICROSECONDS) as u32,
     )
 }
 
@@ -109,9 +111,9 @@ pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from microseconds
-        v / MICROSECONDS,
+        v.div_euclid(MICROSECONDS),
         // discard extracted seconds and convert microseconds to nanoseconds
-        (v % MICROSECONDS * MILLISECONDS) as u32,
+        (v.rem_euclid(MICROSECONDS) * MILLISECONDS) as u32,
     )
 }
 
@@ -120,12 +122,161 @@ pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_ns_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from nanoseconds
-        v / NANOSECONDS,
+        v.div_euclid(NANOSECONDS),
         // discard extracted seconds
-        (v % NANOSECONDS) as u32,
+        v.rem_euclid(NANOSECONDS) as u32,
     )
 }
 
+/// converts a `i64` representing a `timestamp` in `time_unit` to [`NaiveDateTime`]
+#[inline]
+pub fn timestamp_to_naive_datetime(value: i64, time_unit: &TimeUnit) -> NaiveDateTime {
+    match time_unit {
+        TimeUnit::Second => timestamp_s_to_datetime(value),
+        TimeUnit::Millisecond => timestamp_ms_to_datetime(value),
+        TimeUnit::Microsecond => timestamp_us_to_datetime(value),
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime(value),
+    }
+}
+
+/// converts a `i64` representing a `timestamp` in `time_unit` to [`NaiveDateTime`], or `None`
+/// when it is out of the range of [`NaiveDateTime`]
+#[inline]
+pub fn timestamp_to_naive_datetime_opt(value: i64, time_unit: &TimeUnit) -> Option<NaiveDateTime> {
+    let (seconds, nanoseconds) = match time_unit {
+        TimeUnit::Second => (value, 0),
+        TimeUnit::Millisecond => (
+            value.div_euclid(MILLISECONDS),
+            value.rem_euclid(MILLISECONDS) * MICROSECONDS,
+        ),
+        TimeUnit::Microsecond => (
+            value.div_euclid(MICROSECONDS),
+            value.rem_euclid(MICROSECONDS) * MILLISECONDS,
+        ),
+        TimeUnit::Nanosecond => (value.div_euclid(NANOSECONDS), value.rem_euclid(NANOSECONDS)),
+    };
+    DateTime::from_timestamp(seconds, nanoseconds as u32).map(|x| x.naive_utc())
+}
+
+/// converts a `i64` representing a `timestamp` in `time_unit` to a [`DateTime`] in `timezone`
+#[inline]
+pub fn timestamp_to_datetime<T: TimeZone>(
+    value: i64,
+    time_unit: &TimeUnit,
+    timezone: &T,
+) -> DateTime<T> {
+    timezone.from_utc_datetime(&timestamp_to_naive_datetime(value, time_unit))
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp` in `time_unit`,
+/// or `None` if it does not fit in it
+#[inline]
+pub fn naive_datetime_to_timestamp(datetime: &NaiveDateTime, time_unit: &TimeUnit) -> Option<i64> {
+    let datetime = datetime.and_utc();
+    match time_unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    }
+}
+
+/// converts a [`NaiveTime`] to a `i64` representing the time since midnight in `time_unit`
+#[inline]
+pub fn naive_time_to_value(time: &NaiveTime, time_unit: &TimeUnit) -> i64 {
+    let seconds = time.num_seconds_from_midnight() as i64;
+    let nanoseconds = time.nanosecond() as i64;
+    match time_unit {
+        TimeUnit::Second => seconds,
+        TimeUnit::Millisecond => seconds * MILLISECONDS + nanoseconds / MICROSECONDS,
+        TimeUnit::Microsecond => seconds * MICROSECONDS + nanoseconds / MILLISECONDS,
+        TimeUnit::Nanosecond => seconds * NANOSECONDS + nanoseconds,
+    }
+}
+
+/// Parses a fixed offset timezone, such as `+01:00`, `-0530`, `+02`, `UTC` or `Z`,
+/// into a [`FixedOffset`].
+/// # Errors
+/// This function errors iff `timezone` is not a valid fixed offset.
+pub fn parse_offset(timezone: &str) -> Result<FixedOffset> {
+    let error = || {
+        ArrowError::InvalidArgumentError(format!(
+            "Unable to parse timezone \"{}\" as a fixed offset",
+            timezone
+        ))
+    };
+    if timezone == "UTC" || timezone == "Z" {
+        return Ok(FixedOffset::east_opt(0).unwrap());
+    }
+    let sign = match timezone.as_bytes().first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(error()),
+    };
+    let digits = timezone[1..].replace(':', "");
+    if !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return Err(error());
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (&digits[..2], "0"),
+        4 => (&digits[..2], &digits[2..]),
+        _ => return Err(error()),
+    };
+    let hours: i32 = hours.parse().map_err(|_| error())?;
+    let minutes: i32 = minutes.parse().map_err(|_| error())?;
+    if minutes >= 60 {
+        return Err(error());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(error)
+}
+
+/// Parses an IANA timezone, such as `America/New_York`, into a [`chrono_tz::Tz`].
+/// # Errors
+/// This function errors iff `timezone` is not in the IANA timezone database.
+#[cfg(feature = "chrono-tz")]
+pub fn parse_offset_tz(timezone: &str) -> Result<chrono_tz::Tz> {
+    timezone.parse::<chrono_tz::Tz>().map_err(|_| {
+        ArrowError::InvalidArgumentError(format!(
+            "Unable to parse timezone \"{}\" as a fixed offset or IANA timezone",
+            timezone
+        ))
+    })
+}
+
+/// Returns whether `timezone` is a fixed offset or, with feature `chrono-tz`, an IANA timezone.
+pub fn is_valid_timezone(timezone: &str) -> bool {
+    #[cfg(feature = "chrono-tz")]
+    let is_valid = parse_offset(timezone).is_ok() || parse_offset_tz(timezone).is_ok();
+    #[cfg(not(feature = "chrono-tz"))]
+    let is_valid = parse_offset(timezone).is_ok();
+    is_valid
+}
+
+/// Evaluates `$body` with `$tz` bound to the timezone parsed from `$timezone`: a
+/// [`FixedOffset`] (see [`parse_offset`]) or, with feature `chrono-tz`, a [`chrono_tz::Tz`].
+/// `$body` is evaluated once per timezone type, so that it is monomorphized for each of them.
+/// Returns early with an error when `$timezone` cannot be parsed.
+macro_rules! with_timezone {
+    ($timezone:expr, |$tz:ident| $body:expr) => {{
+        match $crate::temporal_conversions::parse_offset($timezone) {
+            Ok($tz) => $body,
+            #[cfg(feature = "chrono-tz")]
+            Err(_) => {
+                let $tz = $crate::temporal_conversions::parse_offset_tz($timezone)?;
+                $body
+            }
+            #[cfg(not(feature = "chrono-tz"))]
+            Err(_) => {
+                return Err($crate::error::ArrowError::InvalidArgumentError(format!(
+                    "Unable to parse timezone \"{}\" as a fixed offset; IANA timezones require feature \"chrono-tz\"",
+                    $timezone
+                )))
+            }
+        }
+    }};
+}
+pub(crate) use with_timezone;
+
 /// Calculates the scale factor between two TimeUnits. The function returns the
 /// scale that should multiply the TimeUnit "b" to have the same time scale as
 /// the TimeUnit "a".
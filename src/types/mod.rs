@@ -119,6 +119,10 @@ natural_type!(i64, DataType::Int64);
 natural_type!(f32, DataType::Float32);
 natural_type!(f64, DataType::Float64);
 natural_type!(days_ms, DataType::Interval(IntervalUnit::DayTime));
+natural_type!(
+    months_days_ns,
+    DataType::Interval(IntervalUnit::MonthDayNano)
+);
 natural_type!(i128, DataType::Decimal(32, 32)); // users should set the decimal when creating an array
 
 create_relation!(u8, &DataType::UInt8);
@@ -244,3 +248,94 @@ impl PartialOrd for days_ms {
         Some(self.cmp(other))
     }
 }
+
+/// The in-memory representation of the MonthDayNano variant of arrow's "Interval" logical type.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct months_days_ns(i32, i32, i64);
+
+impl std::fmt::Display for months_days_ns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}m{}d{}ns", self.months(), self.days(), self.ns())
+    }
+}
+
+unsafe impl NativeType for months_days_ns {
+    type Bytes = [u8; 16];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 16];
+        result[..4].copy_from_slice(&self.0.to_le_bytes());
+        result[4..8].copy_from_slice(&self.1.to_le_bytes());
+        result[8..].copy_from_slice(&self.2.to_le_bytes());
+        result
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 16];
+        result[..4].copy_from_slice(&self.0.to_be_bytes());
+        result[4..8].copy_from_slice(&self.1.to_be_bytes());
+        result[8..].copy_from_slice(&self.2.to_be_bytes());
+        result
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        let mut months = [0; 4];
+        months.copy_from_slice(&bytes[..4]);
+        let mut days = [0; 4];
+        days.copy_from_slice(&bytes[4..8]);
+        let mut ns = [0; 8];
+        ns.copy_from_slice(&bytes[8..]);
+        Self(
+            i32::from_be_bytes(months),
+            i32::from_be_bytes(days),
+            i64::from_be_bytes(ns),
+        )
+    }
+}
+
+create_relation!(
+    months_days_ns,
+    &DataType::Interval(IntervalUnit::MonthDayNano)
+);
+
+impl months_days_ns {
+    /// Returns a new [`months_days_ns`] with the given number of months, days and nanoseconds.
+    #[inline]
+    pub fn new(months: i32, days: i32, nanoseconds: i64) -> Self {
+        Self(months, days, nanoseconds)
+    }
+
+    /// Returns the number of months.
+    #[inline]
+    pub fn months(&self) -> i32 {
+        self.0
+    }
+
+    /// Returns the number of days.
+    #[inline]
+    pub fn days(&self) -> i32 {
+        self.1
+    }
+
+    /// Returns the number of nanoseconds.
+    #[inline]
+    pub fn ns(&self) -> i64 {
+        self.2
+    }
+}
+
+impl Ord for months_days_ns {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.months(), self.days(), self.ns()).cmp(&(other.months(), other.days(), other.ns()))
+    }
+}
+
+impl PartialOrd for months_days_ns {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}